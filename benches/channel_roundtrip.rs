@@ -0,0 +1,78 @@
+//! ViewerChannels 교체 전후 비교용 채널 왕복 마이크로벤치마크
+//! (`cargo bench --bench channel_roundtrip`)
+
+use std::{
+    hint::black_box,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// 왕복 횟수
+const ROUND_TRIPS: u32 = 100_000;
+
+/// 기존 방식 - std mpsc 수신자를 Mutex로 감싸 공유
+fn std_mpsc_mutex() -> Duration {
+    let (tx_request, rx_request) = mpsc::channel::<u32>();
+    let (tx_reply, rx_reply) = mpsc::channel::<u32>();
+    let rx_request = Arc::new(Mutex::new(rx_request));
+
+    let worker = thread::spawn(move || {
+        while let Ok(value) = rx_request.lock().unwrap().recv() {
+            if tx_reply.send(value).is_err() {
+                break;
+            }
+        }
+    });
+
+    let rx_reply = Arc::new(Mutex::new(rx_reply));
+    let started = Instant::now();
+    for i in 0..ROUND_TRIPS {
+        tx_request.send(i).unwrap();
+        black_box(rx_reply.lock().unwrap().recv().unwrap());
+    }
+    let elapsed = started.elapsed();
+
+    drop(tx_request);
+    worker.join().unwrap();
+    elapsed
+}
+
+/// crossbeam-channel - 수신자를 그대로 복제해서 공유
+fn crossbeam_unbounded() -> Duration {
+    let (tx_request, rx_request) = crossbeam_channel::unbounded::<u32>();
+    let (tx_reply, rx_reply) = crossbeam_channel::unbounded::<u32>();
+
+    let worker = thread::spawn(move || {
+        while let Ok(value) = rx_request.recv() {
+            if tx_reply.send(value).is_err() {
+                break;
+            }
+        }
+    });
+
+    let started = Instant::now();
+    for i in 0..ROUND_TRIPS {
+        tx_request.send(i).unwrap();
+        black_box(rx_reply.recv().unwrap());
+    }
+    let elapsed = started.elapsed();
+
+    drop(tx_request);
+    worker.join().unwrap();
+    elapsed
+}
+
+fn report(name: &str, elapsed: Duration) {
+    println!(
+        "{:<20} {:>8.1} ms total, {:>6.0} ns/round trip",
+        name,
+        elapsed.as_secs_f64() * 1000.0,
+        elapsed.as_nanos() as f64 / ROUND_TRIPS as f64
+    );
+}
+
+fn main() {
+    report("std mpsc + Mutex", std_mpsc_mutex());
+    report("crossbeam unbounded", crossbeam_unbounded());
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+/// VRChat 사용자를 위한 터미널 시스템 모니터
+#[derive(Debug, Default, Parser)]
+#[command(version, about)]
+pub struct Args {
+    /// 시작 시 표시할 뷰 이름 (예: system_monitor)
+    #[arg(long, value_name = "NAME")]
+    pub view: Option<String>,
+
+    /// 기본 경로 대신 사용할 설정 파일
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// 시작 tick 간격 (ms)
+    #[arg(long, value_name = "MS")]
+    pub tick_rate: Option<u64>,
+
+    /// NVML/AMD GPU 초기화를 건너뜀
+    #[arg(long)]
+    pub no_gpu: bool,
+
+    /// 수집한 스냅샷을 파일에 녹화
+    #[arg(long, value_name = "PATH", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// 실시간 수집 대신 녹화 파일 재생 (Space: 재생/일시정지)
+    #[arg(long, value_name = "PATH")]
+    pub replay: Option<PathBuf>,
+}
@@ -1,3 +0,0 @@
-pub struct Config {
-    pub some_setting: bool,
-}
\ No newline at end of file
@@ -0,0 +1,145 @@
+use std::{fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table, Value};
+
+use super::{read_document, ConfigError};
+
+/// 저장된 화면 배치 방식 (뷰는 인덱스 대신 이름으로 따로 저장)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutMode {
+    #[default]
+    Single,
+    SideBySide,
+}
+
+/// 이름을 붙여 저장한 뷰 배치 (설정 파일의 `[[layouts]]`)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutPreset {
+    pub name: String,
+    #[serde(default)]
+    pub layout_mode: LayoutMode,
+    /// 키 입력을 받는 뷰의 등록 이름
+    pub primary_view: String,
+    /// 나란히 보기일 때 오른쪽 뷰의 등록 이름
+    #[serde(default)]
+    pub secondary_view: Option<String>,
+}
+
+/// 프리셋을 `[[layouts]]` 한 항목에 해당하는 표로 변환
+fn preset_table(preset: &LayoutPreset) -> Result<Table, ConfigError> {
+    let content = toml::to_string(preset).map_err(io::Error::other)?;
+    let document: DocumentMut = content.parse()?;
+    Ok(document.as_table().clone())
+}
+
+/// 설정 파일의 `layouts`에 프리셋 추가 (`[[layouts]]`와 인라인 배열 모두 지원, 나머지 내용과 주석은 그대로 둠)
+pub fn append_preset(path: &Path, preset: &LayoutPreset) -> Result<(), ConfigError> {
+    let mut document = read_document(path)?;
+    let table = preset_table(preset)?;
+    match document.get_mut("layouts") {
+        None => {
+            let mut layouts = ArrayOfTables::new();
+            layouts.push(table);
+            document.insert("layouts", Item::ArrayOfTables(layouts));
+        }
+        Some(Item::ArrayOfTables(layouts)) => layouts.push(table),
+        Some(Item::Value(Value::Array(layouts))) => layouts.push(table.into_inline_table()),
+        Some(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "`layouts` is not an array of tables",
+            )
+            .into());
+        }
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+/// 설정 파일에서 이름이 같은 `layouts` 항목 삭제 (나머지 내용과 주석은 그대로 둠)
+pub fn remove_preset(path: &Path, name: &str) -> Result<(), ConfigError> {
+    let mut document = read_document(path)?;
+    match document.get_mut("layouts") {
+        Some(Item::ArrayOfTables(layouts)) => {
+            layouts.retain(|layout| layout.get("name").and_then(Item::as_str) != Some(name));
+        }
+        Some(Item::Value(Value::Array(layouts))) => {
+            layouts.retain(|layout| {
+                layout
+                    .as_inline_table()
+                    .and_then(|layout| layout.get("name"))
+                    .and_then(Value::as_str)
+                    != Some(name)
+            });
+        }
+        _ => return Ok(()),
+    }
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn preset(name: &str) -> LayoutPreset {
+        LayoutPreset {
+            name: name.to_string(),
+            layout_mode: LayoutMode::SideBySide,
+            primary_view: "system_monitor".to_string(),
+            secondary_view: Some("cpu_cores".to_string()),
+        }
+    }
+
+    fn temp_config(name: &str, content: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "l_vrc_console_{}_{}.toml",
+            name,
+            std::process::id()
+        ));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn removing_a_preset_keeps_comments_and_other_presets() {
+        let path = temp_config("layout_comments", "# 내 설정\nhistory_size = 90 # 샘플 수\n");
+        append_preset(&path, &preset("work")).unwrap();
+        append_preset(&path, &preset("play")).unwrap();
+
+        remove_preset(&path, "work").unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        let saved = Config::load(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert!(content.contains("# 내 설정"));
+        assert!(content.contains("# 샘플 수"));
+        assert_eq!(saved.history_size, 90);
+        assert_eq!(saved.layouts, vec![preset("play")]);
+    }
+
+    #[test]
+    fn appending_to_an_inline_layouts_array_stays_parseable() {
+        let path = temp_config(
+            "layout_inline",
+            "layouts = [{ name = \"old\", primary_view = \"status\" }]\n",
+        );
+        append_preset(&path, &preset("work")).unwrap();
+        let saved = Config::load(&path).unwrap();
+
+        remove_preset(&path, "old").unwrap();
+        let after_remove = Config::load(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        let names: Vec<&str> = saved.layouts.iter().map(|layout| layout.name.as_str()).collect();
+        assert_eq!(names, ["old", "work"]);
+        assert_eq!(after_remove.layouts, vec![preset("work")]);
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use toml_edit::DocumentMut;
+
+pub mod layout;
+mod osc;
+mod theme;
+
+pub use layout::LayoutPreset;
+pub use osc::{OscBinding, OscBindingValue};
+pub use theme::Theme;
+
+use crate::cli::Args;
+use crate::integration::prometheus::DEFAULT_PROMETHEUS_PORT;
+use crate::integration::vrc_osc::{DEFAULT_OSC_RECEIVE_PORT, DEFAULT_OSC_SEND_PORT};
+
+/// 설정 파일 로드 오류
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    /// 구조를 유지한 채 편집하려고 읽을 때의 형식 오류
+    Edit(toml_edit::TomlError),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "설정 파일을 읽을 수 없음: {}", e),
+            ConfigError::Parse(e) => write!(f, "설정 파일 형식 오류: {}", e),
+            ConfigError::Edit(e) => write!(f, "설정 파일 형식 오류: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        ConfigError::Parse(e)
+    }
+}
+
+impl From<toml_edit::TomlError> for ConfigError {
+    fn from(e: toml_edit::TomlError) -> Self {
+        ConfigError::Edit(e)
+    }
+}
+
+/// 앱 설정 (`~/.config/l_vrc_console/config.toml`, 없는 항목은 기본값 사용)
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub some_setting: bool,
+    /// 시스템 모니터에서 표시할 네트워크 인터페이스 (None이면 자동 감지)
+    pub network_interface: Option<String>,
+    /// 시스템 모니터에 CPU 온도 패널 표시 여부
+    pub show_cpu_temp: bool,
+    /// 시스템 모니터의 네트워크 패널 표시 여부 (W 키로 바꾸면 설정 파일에 저장)
+    pub show_network: bool,
+    /// 그래프 히스토리 샘플 수
+    pub history_size: usize,
+    /// 목표 프레임레이트 (동적 tick rate 조절 기준)
+    pub target_fps: u32,
+    pub min_tick_ms: u64,
+    pub max_tick_ms: u64,
+    /// 시작 tick 간격 (이후 min/max 범위에서 자동 조절)
+    pub tick_rate_ms: u64,
+    /// 이 값 이하이면 초록색 (%)
+    pub low_threshold: u8,
+    /// 이 값 초과이면 빨간색 (%)
+    pub high_threshold: u8,
+    /// 시작 시 표시할 뷰 이름 (비어 있으면 첫 번째 뷰)
+    pub initial_view: String,
+    /// 이 값을 넘으면 CPU 사용량 알림 (%)
+    pub cpu_alert_threshold: f64,
+    /// 이 값을 넘으면 메모리 사용량 알림 (%)
+    pub memory_alert_threshold: f64,
+    /// 물리 메모리 사용량이 이 샘플 수만큼 줄지 않고 늘면 누수 경고 (0이면 사용 안 함)
+    pub memory_leak_window: usize,
+    /// VRChat OSC 수신 포트
+    pub osc_receive_port: u16,
+    /// OSC를 보낼 VRChat 호스트와 포트
+    pub osc_send_host: String,
+    pub osc_send_port: u16,
+    /// VRChat 페이지에서 키를 누르면 보낼 OSC 메시지
+    pub osc_bindings: Vec<OscBinding>,
+    /// 메트릭 CSV 내보내기 경로 (E 키로 켜고 끔)
+    pub export_path: PathBuf,
+    /// GPU(NVML/AMD) 사용량 수집 여부
+    pub gpu_enabled: bool,
+    /// Prometheus `/metrics` 엔드포인트 사용 여부 (기본 꺼짐 - 켜면 `prometheus_port`에서 대기)
+    pub prometheus_enabled: bool,
+    pub prometheus_port: u16,
+    /// 색상 테마 ("dark", "light", "high_contrast")
+    pub theme: Theme,
+    /// 지연 시간을 측정할 호스트 이름 또는 IP 주소
+    pub ping_targets: Vec<String>,
+    /// CPU 전력 그래프의 최댓값 (W)
+    pub cpu_tdp_watts: f64,
+    /// 사용률 히스토그램 집계 구간 (초, 0이면 전체)
+    pub histogram_window_secs: u64,
+    /// 종료 시 세션 요약 Markdown 파일 저장 여부
+    pub export_summary: bool,
+    /// 디스크 사용량 트리 뷰의 시작 경로 (`~`는 홈 디렉터리)
+    pub disk_tree_root: PathBuf,
+    /// 사용자 정의 메트릭을 받을 Unix 소켓 경로 (None이면 사용 안 함)
+    pub custom_metrics_socket: Option<PathBuf>,
+    /// L 키로 불러올 저장된 뷰 배치
+    pub layouts: Vec<LayoutPreset>,
+    /// 레이아웃을 저장할 설정 파일 경로 (파일에서 읽지 않음)
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            some_setting: false,
+            network_interface: None,
+            show_cpu_temp: true,
+            show_network: true,
+            history_size: 60,
+            target_fps: 30,
+            min_tick_ms: 16,
+            max_tick_ms: 200,
+            tick_rate_ms: 50,
+            low_threshold: 50,
+            high_threshold: 75,
+            initial_view: String::new(),
+            cpu_alert_threshold: 90.0,
+            memory_alert_threshold: 90.0,
+            memory_leak_window: 60,
+            osc_receive_port: DEFAULT_OSC_RECEIVE_PORT,
+            osc_send_host: "127.0.0.1".to_string(),
+            osc_send_port: DEFAULT_OSC_SEND_PORT,
+            osc_bindings: Vec::new(),
+            export_path: PathBuf::from("l_vrc_console_metrics.csv"),
+            gpu_enabled: true,
+            prometheus_enabled: false,
+            prometheus_port: DEFAULT_PROMETHEUS_PORT,
+            theme: Theme::default(),
+            ping_targets: Vec::new(),
+            cpu_tdp_watts: 125.0,
+            histogram_window_secs: 300,
+            export_summary: false,
+            disk_tree_root: PathBuf::from("~"),
+            custom_metrics_socket: None,
+            layouts: Vec::new(),
+            path: None,
+        }
+    }
+}
+
+/// 설정 파일을 주석과 서식을 유지한 채 편집할 수 있게 읽기 (파일이 없으면 빈 문서)
+fn read_document(path: &Path) -> Result<DocumentMut, ConfigError> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content.parse()?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(DocumentMut::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 설정 파일의 최상위 키 하나만 바꿔 저장 (나머지 내용과 주석은 그대로 둠)
+pub fn save_value(
+    path: &Path,
+    key: &str,
+    value: impl Into<toml_edit::Value>,
+) -> Result<(), ConfigError> {
+    let mut document = read_document(path)?;
+    document[key] = toml_edit::value(value);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, document.to_string())?;
+    Ok(())
+}
+
+impl Config {
+    /// 기본 설정 파일 경로
+    pub fn default_path() -> Option<PathBuf> {
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))?;
+        Some(config_dir.join("l_vrc_console").join("config.toml"))
+    }
+
+    /// 지정한 경로의 설정 파일 로드
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let content = fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    /// 기본 경로의 설정 파일 로드 - 파일이 없거나 잘못되었으면 기본값 사용
+    pub fn load_or_default() -> Self {
+        let Some(path) = Self::default_path() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::load_or_report(&path)
+    }
+
+    /// 명령줄 인자를 반영하여 로드 (--config로 지정한 파일은 없어도 오류 출력)
+    pub fn load_or_default_with_args(args: &Args) -> Self {
+        let mut config = match args.config {
+            Some(ref path) => Self::load_or_report(path),
+            None => Self::load_or_default(),
+        };
+        // 파일이 아직 없어도 레이아웃을 저장하면 그 경로에 새로 만듦
+        config.path = args.config.clone().or_else(Self::default_path);
+
+        if let Some(ref view) = args.view {
+            config.initial_view = view.clone();
+        }
+        if let Some(tick_rate) = args.tick_rate {
+            // 지정한 간격이 자동 조절 범위를 벗어나지 않도록 범위 확장
+            config.tick_rate_ms = tick_rate;
+            config.min_tick_ms = config.min_tick_ms.min(tick_rate);
+            config.max_tick_ms = config.max_tick_ms.max(tick_rate);
+        }
+        if args.no_gpu {
+            config.gpu_enabled = false;
+        }
+        config
+    }
+
+    fn load_or_report(path: &Path) -> Self {
+        Self::load(path).unwrap_or_else(|e| {
+            eprintln!("{} ({})", e, path.display());
+            Self::default()
+        })
+    }
+
+    /// 목표 프레임 시간 (ms)
+    pub fn target_frame_ms(&self) -> u64 {
+        1000 / self.target_fps.max(1) as u64
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+/// 단축키로 보낼 OSC 값 (설정 파일에서는 `true`, `1`, `0.5`처럼 값 그대로)
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum OscBindingValue {
+    Bool(bool),
+    Int(i32),
+    Float(f32),
+}
+
+/// VRChat 페이지에서 키를 누르면 보낼 OSC 메시지 (설정 파일의 `[[osc_bindings]]`)
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct OscBinding {
+    pub key: char,
+    /// 예: "/avatar/parameters/MenuOpen"
+    pub address: String,
+    pub value: OscBindingValue,
+    /// Bool 값이면 `value` 대신 마지막으로 받은 값의 반대를 보냄
+    #[serde(default)]
+    pub toggle: bool,
+}
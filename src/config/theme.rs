@@ -0,0 +1,118 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::{de, Deserialize, Deserializer};
+
+/// UI 색상/스타일 설정 (설정 파일에서는 `theme = "dark"` 처럼 이름으로 지정)
+#[derive(Clone, Debug)]
+pub struct Theme {
+    /// 낮은 사용량 (기준값 이하)
+    pub low: Color,
+    /// 중간 사용량
+    pub mid: Color,
+    /// 높은 사용량 (기준값 초과)
+    pub high: Color,
+    pub border: Color,
+    pub title: Color,
+    pub background: Color,
+    pub text: Color,
+    /// 축 레이블 기본 스타일 (중간값 레이블)
+    pub axis_label_style: Style,
+    /// 축 양 끝(최소/최대) 레이블 스타일
+    pub axis_label_bold_style: Style,
+}
+
+impl Theme {
+    /// 기본 테마 - 터미널 기본 배경/글자색 유지
+    pub fn dark() -> Self {
+        Self {
+            low: Color::Green,
+            mid: Color::Yellow,
+            high: Color::Red,
+            border: Color::Reset,
+            title: Color::Cyan,
+            background: Color::Reset,
+            text: Color::Reset,
+            axis_label_style: Style::default(),
+            axis_label_bold_style: Style::default().add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// 밝은 배경용 테마 (노란색 대신 어두운 황토색)
+    pub fn light() -> Self {
+        Self {
+            low: Color::Green,
+            mid: Color::Rgb(184, 134, 11),
+            high: Color::Red,
+            border: Color::DarkGray,
+            title: Color::Blue,
+            background: Color::White,
+            text: Color::Black,
+            axis_label_style: Style::default().fg(Color::Black),
+            axis_label_bold_style: Style::default()
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// 검은 배경에 밝은 색만 사용하는 고대비 테마
+    pub fn high_contrast() -> Self {
+        Self {
+            low: Color::LightGreen,
+            mid: Color::LightYellow,
+            high: Color::LightRed,
+            border: Color::White,
+            title: Color::LightCyan,
+            background: Color::Black,
+            text: Color::White,
+            axis_label_style: Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+            axis_label_bold_style: Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// 이름으로 내장 테마 조회 ("dark", "light", "high_contrast")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().replace('-', "_").as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high_contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// 값이 기준값 이하/사이/초과인지에 따른 색상
+    pub fn level_color(&self, value: f64, low_threshold: f64, high_threshold: f64) -> Color {
+        if value <= low_threshold {
+            self.low
+        } else if value <= high_threshold {
+            self.mid
+        } else {
+            self.high
+        }
+    }
+
+    /// 화면 전체 배경/글자 스타일
+    pub fn base_style(&self) -> Style {
+        Style::default().fg(self.text).bg(self.background)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Self::from_name(&name).ok_or_else(|| {
+            de::Error::custom(format!(
+                "unknown theme '{}' (expected dark, light or high_contrast)",
+                name
+            ))
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::VecDeque;
+
+/// 보관할 최대 알림 수
+const MAX_ALERTS: usize = 10;
+
+/// 알림 중요도
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlertLevel {
+    Warning,
+    Critical,
+}
+
+/// 알림 한 건
+#[derive(Clone, Debug)]
+pub struct Alert {
+    pub message: String,
+    pub level: AlertLevel,
+    /// 사용자가 확인(Space)했는지 여부
+    pub acknowledged: bool,
+}
+
+impl Alert {
+    pub fn new(message: impl Into<String>, level: AlertLevel) -> Self {
+        Self {
+            message: message.into(),
+            level,
+            acknowledged: false,
+        }
+    }
+}
+
+/// 최근 알림 목록 관리 (최대 10개)
+pub struct AlertManager {
+    alerts: VecDeque<Alert>,
+}
+
+impl AlertManager {
+    pub fn new() -> Self {
+        Self {
+            alerts: VecDeque::with_capacity(MAX_ALERTS),
+        }
+    }
+
+    pub fn push(&mut self, alert: Alert) {
+        if self.alerts.len() >= MAX_ALERTS {
+            self.alerts.pop_front();
+        }
+        self.alerts.push_back(alert);
+    }
+
+    /// 확인하지 않은 가장 최근 알림
+    pub fn latest_unacknowledged(&self) -> Option<&Alert> {
+        self.alerts.iter().rev().find(|alert| !alert.acknowledged)
+    }
+
+    /// 확인하지 않은 가장 최근 알림을 확인 처리 - 남은 미확인 알림이 있으면 true
+    pub fn acknowledge_latest(&mut self) -> bool {
+        if let Some(alert) = self.alerts.iter_mut().rev().find(|alert| !alert.acknowledged) {
+            alert.acknowledged = true;
+        }
+        self.latest_unacknowledged().is_some()
+    }
+}
+
+impl Default for AlertManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::controllers::metrics_collector::SystemSnapshot;
+
+/// 이 행 수마다 디스크로 flush (크래시 시 손실 최소화)
+const FLUSH_EVERY_ROWS: usize = 10;
+const CSV_HEADER: &str = "timestamp,cpu_pct,mem_used_gb,mem_total_gb,gpu_pct,vram_pct";
+
+/// 메트릭을 CSV 파일로 내보내기
+pub struct ExportManager {
+    path: PathBuf,
+    writer: Option<BufWriter<File>>,
+    rows_since_flush: usize,
+}
+
+impl ExportManager {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            writer: None,
+            rows_since_flush: 0,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.writer.is_some()
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// 내보내기 켜기/끄기 - 켜진 상태가 되면 true
+    pub fn toggle(&mut self) -> io::Result<bool> {
+        if self.is_enabled() {
+            self.stop()?;
+            Ok(false)
+        } else {
+            self.start()?;
+            Ok(true)
+        }
+    }
+
+    /// 파일을 열고 (새 파일이면) 헤더 기록
+    pub fn start(&mut self) -> io::Result<()> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let is_new = file.metadata()?.len() == 0;
+
+        let mut writer = BufWriter::new(file);
+        if is_new {
+            writeln!(writer, "{}", CSV_HEADER)?;
+        }
+        self.writer = Some(writer);
+        self.rows_since_flush = 0;
+        Ok(())
+    }
+
+    pub fn stop(&mut self) -> io::Result<()> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// 스냅샷 한 건을 한 행으로 기록 (꺼져 있으면 무시)
+    pub fn write_row(&mut self, snapshot: &SystemSnapshot) -> io::Result<()> {
+        let Some(ref mut writer) = self.writer else {
+            return Ok(());
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs_f64())
+            .unwrap_or_default();
+        let gb = |bytes: u64| bytes as f64 / 1024.0 / 1024.0 / 1024.0;
+        let optional = |value: Option<f64>| value.map(|v| format!("{:.1}", v)).unwrap_or_default();
+
+        writeln!(
+            writer,
+            "{:.3},{:.1},{:.2},{:.2},{},{}",
+            timestamp,
+            snapshot.cpu_usage,
+            gb(snapshot.used_memory),
+            gb(snapshot.total_memory),
+            optional(snapshot.gpu_usage),
+            optional(snapshot.vram_percent),
+        )?;
+
+        self.rows_since_flush += 1;
+        if self.rows_since_flush >= FLUSH_EVERY_ROWS {
+            writer.flush()?;
+            self.rows_since_flush = 0;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for ExportManager {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
@@ -0,0 +1,43 @@
+use std::{
+    collections::BTreeSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+/// 네트워크 모니터에서 숨길 인터페이스 목록
+#[derive(Serialize, Deserialize)]
+struct InterfaceFilter {
+    hidden: BTreeSet<String>,
+}
+
+/// 기본 필터 경로 (설정 파일과 같은 디렉터리의 `network_filter.json`)
+pub fn default_filter_path() -> Option<PathBuf> {
+    Config::default_path()?
+        .parent()
+        .map(|dir| dir.join("network_filter.json"))
+}
+
+/// 숨긴 인터페이스 저장 (디렉터리가 없으면 생성)
+pub fn save_hidden_interfaces(path: &Path, hidden: &BTreeSet<String>) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let filter = InterfaceFilter {
+        hidden: hidden.clone(),
+    };
+    let json = serde_json::to_string_pretty(&filter).map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// 저장된 숨김 목록 로드 (파일이 없거나 형식이 맞지 않으면 빈 목록)
+pub fn load_hidden_interfaces(path: &Path) -> BTreeSet<String> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<InterfaceFilter>(&content).ok())
+        .map(|filter| filter.hidden)
+        .unwrap_or_default()
+}
@@ -0,0 +1,134 @@
+use std::{
+    cell::RefCell,
+    fmt::Write as _,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::controllers::metrics_collector::SystemSnapshot;
+use crate::integration::vrc_log::{VrcLogEvent, VrcLogEventKind};
+
+/// 요약에 남길 VRChat 로그 이벤트 (VrchatPageView가 기록)
+#[derive(Default)]
+pub struct SessionEvents {
+    /// 로그 감시가 한 번이라도 실행되었는지
+    pub watcher_active: bool,
+    /// 월드 입장, 플레이어 입장/퇴장 (오래된 것부터)
+    pub events: Vec<VrcLogEvent>,
+}
+
+impl SessionEvents {
+    /// 요약 대상 이벤트만 기록
+    pub fn record(&mut self, event: VrcLogEvent) {
+        if matches!(
+            event.kind,
+            VrcLogEventKind::WorldJoin | VrcLogEventKind::PlayerJoin | VrcLogEventKind::PlayerLeave
+        ) {
+            self.events.push(event);
+        }
+    }
+}
+
+pub type SharedSessionEvents = Rc<RefCell<SessionEvents>>;
+
+/// 현재 디렉터리의 `l_vrc_console_summary_<unix 초>.md`
+pub fn default_summary_path() -> PathBuf {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    PathBuf::from(format!("l_vrc_console_summary_{}.md", secs))
+}
+
+/// 최댓값과 평균 (값이 없으면 None)
+fn peak_and_average(values: impl Iterator<Item = f64>) -> Option<(f64, f64)> {
+    let (peak, sum, count) = values.fold((f64::MIN, 0.0, 0usize), |(peak, sum, count), v| {
+        (peak.max(v), sum + v, count + 1)
+    });
+    (count > 0).then(|| (peak, sum / count as f64))
+}
+
+/// 경과 시간을 "1h 2m 3s" 형식으로 변환
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{}h {}m {}s", secs / 3600, (secs % 3600) / 60, secs % 60)
+}
+
+/// 세션 요약 Markdown 생성
+fn render_session_summary(
+    snapshot_history: &[SystemSnapshot],
+    duration: Duration,
+    vrchat_events: Option<&[VrcLogEvent]>,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# l_vrc_console Session Summary\n");
+    let _ = writeln!(out, "## Session\n");
+    let _ = writeln!(out, "- Duration: {}", format_duration(duration));
+    let _ = writeln!(out, "- Samples: {}\n", snapshot_history.len());
+
+    let metrics = [
+        (
+            "CPU",
+            peak_and_average(snapshot_history.iter().map(|s| s.cpu_usage)),
+        ),
+        (
+            "GPU",
+            peak_and_average(snapshot_history.iter().filter_map(|s| s.gpu_usage)),
+        ),
+        (
+            "Memory",
+            peak_and_average(snapshot_history.iter().map(SystemSnapshot::memory_percent)),
+        ),
+    ];
+    let _ = writeln!(out, "## Usage\n");
+    let _ = writeln!(out, "| Metric | Peak | Average |");
+    let _ = writeln!(out, "|---|---|---|");
+    for (name, values) in metrics {
+        match values {
+            Some((peak, average)) => {
+                let _ = writeln!(out, "| {} | {:.1}% | {:.1}% |", name, peak, average);
+            }
+            None => {
+                let _ = writeln!(out, "| {} | - | - |", name);
+            }
+        }
+    }
+
+    if let Some(events) = vrchat_events {
+        let _ = writeln!(out, "\n## VRChat\n");
+        if events.is_empty() {
+            let _ = writeln!(out, "No world or player events.");
+        }
+        for event in events {
+            let _ = writeln!(
+                out,
+                "- `{}` **{}** {}",
+                event.time,
+                event.kind.label(),
+                event.detail
+            );
+        }
+    }
+    out
+}
+
+/// 세션 요약을 Markdown 파일로 저장 (VRChat 로그 감시를 안 했으면 이벤트 절 생략)
+pub fn write_session_summary(
+    path: &Path,
+    snapshot_history: &[SystemSnapshot],
+    duration: Duration,
+    vrchat_events: Option<&[VrcLogEvent]>,
+) -> io::Result<()> {
+    if let Some(dir) = path.parent()
+        && !dir.as_os_str().is_empty()
+    {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(
+        path,
+        render_session_summary(snapshot_history, duration, vrchat_events),
+    )
+}
@@ -0,0 +1,480 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use sysinfo::{
+    Components, Networks, ProcessRefreshKind, ProcessesToUpdate, System, ThreadKind, UpdateKind,
+    Users,
+};
+
+use nvml_wrapper::Nvml;
+
+use crate::integration::gpu::{self, GpuProcessInfo, GpuProvider};
+#[cfg(target_os = "linux")]
+use crate::integration::meminfo::MemInfoReader;
+use crate::integration::proc_stat::ProcStatReader;
+use crate::integration::rapl::RaplSensor;
+#[cfg(target_os = "windows")]
+use crate::integration::windows_memory::WindowsMemoryInfo;
+
+/// 기본 수집 간격 (UsageGraph의 기본 기대 간격과 동일)
+pub const DEFAULT_COLLECT_INTERVAL: Duration = Duration::from_millis(200);
+/// 프로세스 목록 전체를 읽으므로 수집 간격과 별도로 이 간격마다만 갱신
+const PROCESS_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 코어 한 개의 상태
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CoreSnapshot {
+    pub usage: f64,
+    pub frequency_mhz: u64,
+}
+
+/// 네트워크 인터페이스 한 개의 상태
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct NetworkSnapshot {
+    pub name: String,
+    /// 직전 수집 이후 초당 수신 바이트
+    pub rx_bytes_per_sec: f64,
+    /// 직전 수집 이후 초당 송신 바이트
+    pub tx_bytes_per_sec: f64,
+    pub total_received: u64,
+    pub total_transmitted: u64,
+}
+
+/// GPU 장치 한 개의 상태
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GpuSnapshot {
+    /// 그래프 제목에 표시할 장치 이름
+    pub name: String,
+    pub utilization: Option<f64>,
+    pub vram_percent: Option<f64>,
+    /// GPU 코어 온도 (°C)
+    pub temperature: Option<f64>,
+    /// 현재/최대 그래픽 클럭 (MHz)
+    pub graphics_clock_mhz: Option<f64>,
+    pub max_graphics_clock_mhz: Option<f64>,
+}
+
+/// CPU 소비 전력 (Linux RAPL)
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct CpuPowerSnapshot {
+    pub package_watts: f64,
+    /// 코어 도메인이 없는 CPU(대부분의 AMD)는 0
+    pub core_watts: f64,
+}
+
+/// 초당 컨텍스트 스위치/인터럽트 수 (Linux `/proc/stat`)
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct SchedulerSnapshot {
+    pub context_switches_per_sec: f64,
+    pub interrupts_per_sec: f64,
+}
+
+/// 프로세스 한 개의 상태
+#[derive(Clone, Debug)]
+pub struct ProcessSnapshot {
+    pub pid: u32,
+    pub parent: Option<u32>,
+    pub name: String,
+    pub cpu_usage: f32,
+    pub memory: u64,
+    /// 사용자 이름 (찾지 못하면 "-")
+    pub user: String,
+    /// 프로세스에 속한 스레드 수 (알 수 없으면 1)
+    pub task_count: usize,
+    /// 스레드면 Some (Linux에서는 스레드도 목록에 포함됨)
+    pub thread_kind: Option<ThreadKind>,
+}
+
+/// 한 번의 수집 결과
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SystemSnapshot {
+    /// 수집할 때마다 1씩 증가 (새 데이터 여부 판별용)
+    pub sequence: u64,
+    pub cpu_usage: f64,
+    pub cores: Vec<CoreSnapshot>,
+    pub total_memory: u64,
+    pub used_memory: u64,
+    pub available_memory: u64,
+    pub total_swap: u64,
+    pub used_swap: u64,
+    /// 버퍼 + 페이지 캐시 + 회수 가능한 slab (Linux `/proc/meminfo`, 바이트)
+    #[serde(default)]
+    pub cache_memory: Option<u64>,
+    /// Windows 커밋 사용량 (녹화 파일에는 저장하지 않음)
+    #[cfg(target_os = "windows")]
+    #[serde(skip)]
+    pub commit: Option<WindowsMemoryInfo>,
+    /// 이름순 정렬
+    pub networks: Vec<NetworkSnapshot>,
+    /// 첫 번째 GPU의 사용률 (GPU를 감지하지 못하면 None)
+    pub gpu_usage: Option<f64>,
+    pub vram_percent: Option<f64>,
+    /// 감지된 모든 GPU (NVML → amdgpu → i915 순으로 처음 발견된 종류)
+    #[serde(default)]
+    pub gpus: Vec<GpuSnapshot>,
+    /// CPU 패키지 온도 (°C, 센서를 찾지 못하면 None)
+    #[serde(default)]
+    pub cpu_temperature: Option<f64>,
+    /// RAPL 센서를 읽을 수 없으면 None
+    #[serde(default)]
+    pub cpu_power: Option<CpuPowerSnapshot>,
+    /// `/proc/stat`을 읽을 수 없으면 None
+    #[serde(default)]
+    pub scheduler: Option<SchedulerSnapshot>,
+    /// 프로세스 목록 (`PROCESS_REFRESH_INTERVAL`마다 갱신, 녹화 파일에는 저장하지 않음)
+    #[serde(skip)]
+    pub processes: Arc<[ProcessSnapshot]>,
+    /// 프로세스별 GPU 메모리 (`processes`와 함께 갱신, NVML을 쓸 수 없으면 None)
+    #[serde(skip)]
+    pub gpu_processes: Option<Arc<[GpuProcessInfo]>>,
+}
+
+impl SystemSnapshot {
+    /// 메모리 사용률 (%)
+    pub fn memory_percent(&self) -> f64 {
+        if self.total_memory == 0 {
+            return 0.0;
+        }
+        self.used_memory as f64 / self.total_memory as f64 * 100.0
+    }
+
+    pub fn network(&self, name: &str) -> Option<&NetworkSnapshot> {
+        self.networks.iter().find(|n| n.name == name)
+    }
+}
+
+pub type SharedSnapshot = Arc<Mutex<SystemSnapshot>>;
+
+/// 공유 스냅샷에서 새로 수집된 데이터만 꺼내는 뷰용 읽기 핸들
+pub struct SnapshotReader {
+    shared: SharedSnapshot,
+    last_sequence: u64,
+}
+
+impl SnapshotReader {
+    pub fn new(shared: SharedSnapshot) -> Self {
+        Self {
+            shared,
+            last_sequence: 0,
+        }
+    }
+
+    /// 마지막으로 읽은 뒤 새 스냅샷이 있으면 복사본 반환
+    pub fn poll(&mut self) -> Option<SystemSnapshot> {
+        let snapshot = self.shared.lock().ok()?;
+        if snapshot.sequence == self.last_sequence {
+            return None;
+        }
+        self.last_sequence = snapshot.sequence;
+        Some(snapshot.clone())
+    }
+
+    /// 새 데이터 여부와 관계없이 현재 스냅샷 복사본
+    pub fn current(&self) -> SystemSnapshot {
+        self.shared
+            .lock()
+            .map(|snapshot| snapshot.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// 백그라운드 스레드에서 sysinfo를 갱신해 공유 스냅샷으로 발행
+pub struct MetricsCollector {
+    snapshot: SharedSnapshot,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MetricsCollector {
+    /// 첫 수집을 마친 뒤 수집 스레드 시작 (gpu_enabled가 false면 GPU 감지 생략)
+    pub fn start(interval: Duration, gpu_enabled: bool) -> Self {
+        let snapshot = SharedSnapshot::default();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let mut state = CollectorState::new(gpu_enabled);
+        state.collect(&snapshot);
+
+        let handle = {
+            let snapshot = snapshot.clone();
+            let stop = stop.clone();
+            thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    thread::sleep(interval);
+                    state.collect(&snapshot);
+                }
+            })
+        };
+
+        Self {
+            snapshot,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// 뷰에 전달할 공유 스냅샷
+    pub fn snapshot(&self) -> SharedSnapshot {
+        self.snapshot.clone()
+    }
+
+    pub fn reader(&self) -> SnapshotReader {
+        SnapshotReader::new(self.snapshot())
+    }
+
+    /// 수집 스레드 종료 (공유 스냅샷은 그대로 남아 재생 등에 사용 가능)
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for MetricsCollector {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// 수집 스레드가 소유하는 GPU 장치 (이름과 최대 클럭은 감지할 때 한 번만 읽음)
+struct CollectedGpu {
+    provider: Box<dyn GpuProvider>,
+    name: String,
+    max_graphics_clock_mhz: Option<f64>,
+}
+
+impl CollectedGpu {
+    fn new(provider: Box<dyn GpuProvider>) -> Self {
+        Self {
+            name: provider.name(),
+            max_graphics_clock_mhz: provider.max_graphics_clock_mhz(),
+            provider,
+        }
+    }
+
+    fn snapshot(&self) -> GpuSnapshot {
+        GpuSnapshot {
+            name: self.name.clone(),
+            utilization: self.provider.utilization(),
+            vram_percent: self.provider.vram_percent(),
+            temperature: self.provider.temperature(),
+            graphics_clock_mhz: self.provider.graphics_clock_mhz(),
+            max_graphics_clock_mhz: self.max_graphics_clock_mhz,
+        }
+    }
+}
+
+/// 수집 스레드가 소유하는 sysinfo 상태
+struct CollectorState {
+    system: System,
+    networks: Networks,
+    users: Users,
+    components: Components,
+    rapl: Option<RaplSensor>,
+    proc_stat: Option<ProcStatReader>,
+    /// GPU 장치 열거와 프로세스별 GPU 메모리 조회가 함께 쓰는 NVML 핸들
+    nvml: Option<Arc<Nvml>>,
+    gpus: Vec<CollectedGpu>,
+    last_collect: Instant,
+    /// 마지막 프로세스 목록과 갱신 시각 (갱신 사이에는 그대로 재사용)
+    processes: Arc<[ProcessSnapshot]>,
+    gpu_processes: Option<Arc<[GpuProcessInfo]>>,
+    last_process_refresh: Option<Instant>,
+    sequence: u64,
+}
+
+impl CollectorState {
+    fn new(gpu_enabled: bool) -> Self {
+        let mut system = System::new();
+        system.refresh_cpu_all();
+        system.refresh_memory();
+        let nvml = gpu_enabled.then(gpu::init_nvml).flatten();
+        Self {
+            system,
+            networks: Networks::new_with_refreshed_list(),
+            users: Users::new_with_refreshed_list(),
+            components: Components::new_with_refreshed_list(),
+            rapl: RaplSensor::detect(),
+            proc_stat: ProcStatReader::detect(),
+            gpus: gpu::enumerate_gpus(nvml.as_ref())
+                .into_iter()
+                .map(CollectedGpu::new)
+                .collect(),
+            nvml,
+            last_collect: Instant::now(),
+            processes: Arc::default(),
+            gpu_processes: None,
+            last_process_refresh: None,
+            sequence: 0,
+        }
+    }
+
+    /// CPU 패키지 온도 조회 (센서 이름은 플랫폼/제조사마다 다름)
+    fn cpu_package_temperature(&self) -> Option<f64> {
+        const CPU_SENSOR_LABELS: [&str; 5] = ["Package id 0", "Tctl", "Tdie", "CPU", "coretemp"];
+
+        CPU_SENSOR_LABELS.iter().find_map(|pattern| {
+            self.components
+                .list()
+                .iter()
+                .find(|c| c.label().contains(pattern))
+                .and_then(|c| c.temperature())
+                .map(|t| t as f64)
+        })
+    }
+
+    /// 버퍼/캐시 메모리 (`free` 명령의 buff/cache와 같은 기준)
+    #[cfg(target_os = "linux")]
+    fn cache_memory() -> Option<u64> {
+        let meminfo = MemInfoReader::read().ok()?;
+        let cache_kb = meminfo.buffers_kb().unwrap_or(0)
+            + meminfo.cached_kb().unwrap_or(0)
+            + meminfo.s_reclaimable_kb().unwrap_or(0);
+        Some(cache_kb * 1024)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn cache_memory() -> Option<u64> {
+        None
+    }
+
+    /// 간격이 지났으면 프로세스 목록을 다시 읽음
+    fn refresh_processes(&mut self) {
+        if self
+            .last_process_refresh
+            .is_some_and(|t| t.elapsed() < PROCESS_REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.last_process_refresh = Some(Instant::now());
+
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_memory()
+                .with_tasks()
+                .with_user(UpdateKind::OnlyIfNotSet)
+                .with_environ(UpdateKind::OnlyIfNotSet),
+        );
+        self.processes = self
+            .system
+            .processes()
+            .iter()
+            .map(|(pid, process)| ProcessSnapshot {
+                pid: pid.as_u32(),
+                parent: process.parent().map(|parent| parent.as_u32()),
+                name: process.name().to_string_lossy().into_owned(),
+                cpu_usage: process.cpu_usage(),
+                memory: process.memory(),
+                // uid로 사용자를 찾지 못하면 환경 변수 USER로 대체
+                user: process
+                    .user_id()
+                    .and_then(|uid| self.users.get_user_by_id(uid))
+                    .map(|user| user.name().to_string())
+                    .or_else(|| {
+                        process.environ().iter().find_map(|var| {
+                            var.to_str()?.strip_prefix("USER=").map(str::to_string)
+                        })
+                    })
+                    .unwrap_or_else(|| "-".to_string()),
+                task_count: process.tasks().map_or(1, |tasks| tasks.len().max(1)),
+                thread_kind: process.thread_kind(),
+            })
+            .collect();
+        self.gpu_processes = self
+            .nvml
+            .as_ref()
+            .map(|nvml| gpu::gpu_processes(nvml, &self.system).into());
+    }
+
+    fn collect(&mut self, shared: &SharedSnapshot) {
+        self.system.refresh_cpu_all();
+        self.system.refresh_memory();
+        self.networks.refresh(true);
+        self.components.refresh(false);
+        if let Some(ref mut rapl) = self.rapl {
+            rapl.refresh();
+        }
+        if let Some(ref mut proc_stat) = self.proc_stat {
+            proc_stat.refresh();
+        }
+        self.refresh_processes();
+
+        let elapsed = self.last_collect.elapsed().as_secs_f64();
+        self.last_collect = Instant::now();
+        let rate = |bytes: u64| {
+            if elapsed > 0.0 {
+                bytes as f64 / elapsed
+            } else {
+                0.0
+            }
+        };
+
+        let mut networks: Vec<NetworkSnapshot> = self
+            .networks
+            .list()
+            .iter()
+            .map(|(name, data)| NetworkSnapshot {
+                name: name.clone(),
+                rx_bytes_per_sec: rate(data.received()),
+                tx_bytes_per_sec: rate(data.transmitted()),
+                total_received: data.total_received(),
+                total_transmitted: data.total_transmitted(),
+            })
+            .collect();
+        networks.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let gpus: Vec<GpuSnapshot> = self.gpus.iter().map(CollectedGpu::snapshot).collect();
+
+        self.sequence += 1;
+        let snapshot = SystemSnapshot {
+            sequence: self.sequence,
+            cpu_usage: self.system.global_cpu_usage() as f64,
+            cores: self
+                .system
+                .cpus()
+                .iter()
+                .map(|cpu| CoreSnapshot {
+                    usage: cpu.cpu_usage() as f64,
+                    frequency_mhz: cpu.frequency(),
+                })
+                .collect(),
+            total_memory: self.system.total_memory(),
+            used_memory: self.system.used_memory(),
+            available_memory: self.system.available_memory(),
+            total_swap: self.system.total_swap(),
+            used_swap: self.system.used_swap(),
+            cache_memory: Self::cache_memory(),
+            #[cfg(target_os = "windows")]
+            commit: WindowsMemoryInfo::read(),
+            networks,
+            gpu_usage: gpus.first().and_then(|gpu| gpu.utilization),
+            vram_percent: gpus.first().and_then(|gpu| gpu.vram_percent),
+            gpus,
+            cpu_temperature: self.cpu_package_temperature(),
+            cpu_power: self.rapl.as_ref().map(|rapl| CpuPowerSnapshot {
+                package_watts: rapl.package_watts(),
+                core_watts: rapl.core_watts(),
+            }),
+            scheduler: self.proc_stat.as_ref().map(|proc_stat| SchedulerSnapshot {
+                context_switches_per_sec: proc_stat.context_switches_per_sec(),
+                interrupts_per_sec: proc_stat.interrupts_per_sec(),
+            }),
+            processes: self.processes.clone(),
+            gpu_processes: self.gpu_processes.clone(),
+        };
+
+        if let Ok(mut shared) = shared.lock() {
+            *shared = snapshot;
+        }
+    }
+}
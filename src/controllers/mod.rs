@@ -1 +1,8 @@
-pub mod serial_input_controller;
\ No newline at end of file
+pub mod alert_manager;
+pub mod export;
+pub mod interface_filter;
+pub mod log_exporter;
+pub mod metrics_collector;
+pub mod record_replay;
+pub mod serial_input_controller;
+pub mod snapshot;
\ No newline at end of file
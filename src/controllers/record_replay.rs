@@ -0,0 +1,197 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::controllers::metrics_collector::{SharedSnapshot, SystemSnapshot};
+
+/// 녹화 파일 시작 표시 (형식이 바뀌면 끝의 버전 번호 증가)
+const RECORDING_MAGIC: &[u8; 8] = b"LVRCREC1";
+/// 이 프레임 수마다 디스크로 flush (크래시 시 손실 최소화)
+const FLUSH_EVERY_FRAMES: usize = 10;
+
+/// 녹화 시작 시각 기준 수집 시각과 스냅샷
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecordedFrame {
+    pub elapsed_ms: u64,
+    pub snapshot: SystemSnapshot,
+}
+
+fn to_io_error(e: bincode::ErrorKind) -> io::Error {
+    match e {
+        bincode::ErrorKind::Io(e) => e,
+        e => io::Error::new(io::ErrorKind::InvalidData, e),
+    }
+}
+
+/// 스냅샷을 bincode 프레임으로 파일에 기록 (`--record`)
+pub struct Recorder {
+    writer: BufWriter<File>,
+    started: Instant,
+    frames_since_flush: usize,
+}
+
+impl Recorder {
+    /// 파일을 새로 만들고 헤더 기록 (기존 파일은 덮어씀)
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(RECORDING_MAGIC)?;
+        Ok(Self {
+            writer,
+            started: Instant::now(),
+            frames_since_flush: 0,
+        })
+    }
+
+    pub fn write(&mut self, snapshot: &SystemSnapshot) -> io::Result<()> {
+        let frame = RecordedFrame {
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+            snapshot: snapshot.clone(),
+        };
+        bincode::serialize_into(&mut self.writer, &frame).map_err(|e| to_io_error(*e))?;
+
+        self.frames_since_flush += 1;
+        if self.frames_since_flush >= FLUSH_EVERY_FRAMES {
+            self.writer.flush()?;
+            self.frames_since_flush = 0;
+        }
+        Ok(())
+    }
+}
+
+/// 녹화 파일의 스냅샷을 원래 시간 간격대로 공유 스냅샷에 다시 발행 (`--replay`)
+pub struct Replayer {
+    frames: Vec<RecordedFrame>,
+    /// 다음에 발행할 프레임 인덱스
+    position: usize,
+    /// 일시정지 전까지 재생한 시간
+    played: Duration,
+    /// 재생 중이면 마지막으로 재개한 시각 (일시정지 중이면 None)
+    resumed_at: Option<Instant>,
+}
+
+impl Replayer {
+    /// 파일 전체를 읽음 (마지막 프레임이 잘려 있으면 그 앞까지만 사용)
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != RECORDING_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Not a recording file",
+            ));
+        }
+
+        let mut frames = Vec::new();
+        loop {
+            match bincode::deserialize_from::<_, RecordedFrame>(&mut reader) {
+                Ok(frame) => frames.push(frame),
+                Err(e) => match to_io_error(*e) {
+                    e if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                    e => return Err(e),
+                },
+            }
+        }
+        if frames.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Recording has no frames",
+            ));
+        }
+
+        Ok(Self {
+            frames,
+            position: 0,
+            played: Duration::ZERO,
+            resumed_at: Some(Instant::now()),
+        })
+    }
+
+    /// 재생 위치 (녹화 시작 기준)
+    fn playback_time(&self) -> Duration {
+        self.played + self.resumed_at.map_or(Duration::ZERO, |t| t.elapsed())
+    }
+
+    /// 녹화 전체 길이
+    pub fn duration(&self) -> Duration {
+        self.frames
+            .last()
+            .map_or(Duration::ZERO, |frame| Duration::from_millis(frame.elapsed_ms))
+    }
+
+    /// 재생 진행률 (0.0 ~ 1.0)
+    pub fn progress(&self) -> f64 {
+        let total = self.duration().as_secs_f64();
+        if total == 0.0 {
+            return if self.is_finished() { 1.0 } else { 0.0 };
+        }
+        (self.playback_time().as_secs_f64() / total).min(1.0)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.resumed_at.is_none()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.frames.len()
+    }
+
+    /// 재생/일시정지 전환
+    pub fn toggle_pause(&mut self) {
+        match self.resumed_at.take() {
+            Some(resumed_at) => self.played += resumed_at.elapsed(),
+            None => self.resumed_at = Some(Instant::now()),
+        }
+    }
+
+    /// 재생 위치까지의 프레임 중 마지막 것을 발행 (뷰가 새 데이터로 인식하도록 sequence 증가)
+    pub fn advance(&mut self, shared: &SharedSnapshot) {
+        let now = self.playback_time();
+        let mut latest = None;
+        while let Some(frame) = self.frames.get(self.position)
+            && Duration::from_millis(frame.elapsed_ms) <= now
+        {
+            latest = Some(self.position);
+            self.position += 1;
+        }
+
+        if let Some(index) = latest
+            && let Ok(mut shared) = shared.lock()
+        {
+            let sequence = shared.sequence + 1;
+            *shared = self.frames[index].snapshot.clone();
+            shared.sequence = sequence;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_snapshots_replay_with_new_sequence_numbers() {
+        let path = std::env::temp_dir().join(format!("l_vrc_console_replay_{}.bin", std::process::id()));
+        let mut recorder = Recorder::create(&path).unwrap();
+        for cpu_usage in [12.5, 80.0] {
+            let snapshot = SystemSnapshot { sequence: 7, cpu_usage, ..Default::default() };
+            recorder.write(&snapshot).unwrap();
+        }
+        drop(recorder);
+
+        let mut replayer = Replayer::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        let shared = SharedSnapshot::default();
+        replayer.advance(&shared);
+        let snapshot = shared.lock().unwrap().clone();
+        assert_eq!(snapshot.sequence, 1);
+        assert_eq!(snapshot.cpu_usage, 80.0);
+        assert!(replayer.is_finished());
+        assert_eq!(replayer.progress(), 1.0);
+    }
+}
@@ -0,0 +1,68 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+/// 다음 실행 때 복원할 UI 상태
+#[derive(Serialize, Deserialize)]
+struct ViewSnapshot {
+    /// 뷰 등록 키 (뷰 순서가 바뀌어도 같은 뷰를 복원)
+    current_view: String,
+    /// 저장 시점에 화면 갱신이 일시정지 상태였는지
+    #[serde(default)]
+    paused: bool,
+}
+
+/// 기본 스냅샷 경로 (설정 파일과 같은 디렉터리의 `snapshot.json`)
+pub fn default_snapshot_path() -> Option<PathBuf> {
+    Config::default_path()?
+        .parent()
+        .map(|dir| dir.join("snapshot.json"))
+}
+
+/// 현재 뷰 키와 일시정지 상태 저장 (디렉터리가 없으면 생성)
+pub fn save_snapshot(path: &Path, current_view: &str, paused: bool) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string(&ViewSnapshot {
+        current_view: current_view.to_string(),
+        paused,
+    })
+    .map_err(io::Error::other)?;
+    fs::write(path, json)
+}
+
+/// 저장된 뷰 키 로드 (파일이 없거나 형식이 맞지 않으면 None - 예전 인덱스 형식 포함)
+pub fn load_snapshot(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str::<ViewSnapshot>(&content)
+        .ok()
+        .map(|snapshot| snapshot.current_view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_key_round_trips_and_old_index_format_is_ignored() {
+        let path = std::env::temp_dir().join(format!(
+            "l_vrc_console_snapshot_{}.json",
+            std::process::id()
+        ));
+        save_snapshot(&path, "system", true).unwrap();
+        let restored = load_snapshot(&path);
+
+        fs::write(&path, r#"{"current_view":2,"paused":false}"#).unwrap();
+        let old_format = load_snapshot(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(restored.as_deref(), Some("system"));
+        assert_eq!(old_format, None);
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    collections::HashMap,
+    fs,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+/// TCP 소켓 상태 (`/proc/net/tcp`의 st 필드)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TcpState {
+    Established,
+    SynSent,
+    SynRecv,
+    FinWait1,
+    FinWait2,
+    TimeWait,
+    Close,
+    CloseWait,
+    LastAck,
+    Listen,
+    Closing,
+    Unknown,
+}
+
+impl TcpState {
+    fn from_hex(code: &str) -> Self {
+        match u8::from_str_radix(code, 16).unwrap_or(0) {
+            0x01 => TcpState::Established,
+            0x02 => TcpState::SynSent,
+            0x03 => TcpState::SynRecv,
+            0x04 => TcpState::FinWait1,
+            0x05 => TcpState::FinWait2,
+            0x06 => TcpState::TimeWait,
+            0x07 => TcpState::Close,
+            0x08 => TcpState::CloseWait,
+            0x09 => TcpState::LastAck,
+            0x0A => TcpState::Listen,
+            0x0B => TcpState::Closing,
+            _ => TcpState::Unknown,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TcpState::Established => "ESTABLISHED",
+            TcpState::SynSent => "SYN_SENT",
+            TcpState::SynRecv => "SYN_RECV",
+            TcpState::FinWait1 => "FIN_WAIT1",
+            TcpState::FinWait2 => "FIN_WAIT2",
+            TcpState::TimeWait => "TIME_WAIT",
+            TcpState::Close => "CLOSE",
+            TcpState::CloseWait => "CLOSE_WAIT",
+            TcpState::LastAck => "LAST_ACK",
+            TcpState::Listen => "LISTEN",
+            TcpState::Closing => "CLOSING",
+            TcpState::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// 열린 TCP 연결 한 건
+#[derive(Clone, Debug)]
+pub struct Connection {
+    pub local: SocketAddr,
+    pub remote: SocketAddr,
+    pub state: TcpState,
+    pub inode: u64,
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+}
+
+/// `/proc/net/tcp{,6}`과 `/proc/<pid>/fd`를 교차 참조한 연결 목록
+pub struct ConnectionTable {
+    connections: Vec<Connection>,
+}
+
+impl ConnectionTable {
+    pub fn new() -> Self {
+        Self {
+            connections: Vec::new(),
+        }
+    }
+
+    pub fn connections(&self) -> &[Connection] {
+        &self.connections
+    }
+
+    /// 연결 목록과 소켓 소유 프로세스를 다시 읽음
+    pub fn refresh(&mut self) {
+        let owners = Self::socket_owners();
+
+        let mut connections = Vec::new();
+        for path in ["/proc/net/tcp", "/proc/net/tcp6"] {
+            if let Ok(content) = fs::read_to_string(path) {
+                connections.extend(content.lines().skip(1).filter_map(Self::parse_line));
+            }
+        }
+
+        for connection in &mut connections {
+            if let Some((pid, name)) = owners.get(&connection.inode) {
+                connection.pid = Some(*pid);
+                connection.process_name = Some(name.clone());
+            }
+        }
+        self.connections = connections;
+    }
+
+    /// `/proc/net/tcp` 한 줄 파싱
+    fn parse_line(line: &str) -> Option<Connection> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 10 {
+            return None;
+        }
+        Some(Connection {
+            local: Self::parse_address(fields[1])?,
+            remote: Self::parse_address(fields[2])?,
+            state: TcpState::from_hex(fields[3]),
+            inode: fields[9].parse().ok()?,
+            pid: None,
+            process_name: None,
+        })
+    }
+
+    /// "0100007F:0277" 형식의 주소 파싱 (IP는 32비트 워드 단위 리틀 엔디언)
+    fn parse_address(field: &str) -> Option<SocketAddr> {
+        let (ip_hex, port_hex) = field.split_once(':')?;
+        let port = u16::from_str_radix(port_hex, 16).ok()?;
+
+        let mut bytes = Vec::with_capacity(16);
+        for chunk in ip_hex.as_bytes().chunks(8) {
+            let word = u32::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+
+        let ip = match bytes.len() {
+            4 => IpAddr::V4(Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3])),
+            16 => {
+                let octets: [u8; 16] = bytes.try_into().ok()?;
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            _ => return None,
+        };
+        Some(SocketAddr::new(ip, port))
+    }
+
+    /// 소켓 inode → (PID, 프로세스 이름) 매핑
+    fn socket_owners() -> HashMap<u64, (u32, String)> {
+        let mut owners = HashMap::new();
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return owners;
+        };
+
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|s| s.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            // 권한이 없는 프로세스는 건너뜀
+            let Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+                continue;
+            };
+            let name = fs::read_to_string(entry.path().join("comm"))
+                .map(|s| s.trim().to_string())
+                .unwrap_or_default();
+
+            for fd in fds.flatten() {
+                let Ok(target) = fs::read_link(fd.path()) else {
+                    continue;
+                };
+                let target = target.to_string_lossy();
+                if let Some(inode) = target
+                    .strip_prefix("socket:[")
+                    .and_then(|s| s.strip_suffix(']'))
+                    .and_then(|s| s.parse().ok())
+                {
+                    owners.insert(inode, (pid, name.clone()));
+                }
+            }
+        }
+        owners
+    }
+}
+
+impl Default for ConnectionTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
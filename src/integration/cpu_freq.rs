@@ -0,0 +1,25 @@
+/// 코어의 하드웨어 클럭 범위 (MHz)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CpuFreqBounds {
+    pub min_mhz: u64,
+    pub max_mhz: u64,
+}
+
+/// `/sys/devices/system/cpu/cpuN/cpufreq/cpuinfo_{min,max}_freq` (kHz) - cpufreq 드라이버가 없으면 None
+#[cfg(target_os = "linux")]
+pub fn read_cpu_freq_bounds(cpu: usize) -> Option<CpuFreqBounds> {
+    let dir = std::path::Path::new("/sys/devices/system/cpu")
+        .join(format!("cpu{}", cpu))
+        .join("cpufreq");
+    let read_khz = |name: &str| -> Option<u64> {
+        std::fs::read_to_string(dir.join(name)).ok()?.trim().parse().ok()
+    };
+    let min_mhz = read_khz("cpuinfo_min_freq")? / 1000;
+    let max_mhz = read_khz("cpuinfo_max_freq")? / 1000;
+    (max_mhz > 0).then_some(CpuFreqBounds { min_mhz, max_mhz })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_cpu_freq_bounds(_cpu: usize) -> Option<CpuFreqBounds> {
+    None
+}
@@ -0,0 +1,152 @@
+use std::{fs, path::Path};
+
+const CPU_SYSFS_DIR: &str = "/sys/devices/system/cpu";
+const NODE_SYSFS_DIR: &str = "/sys/devices/system/node";
+
+/// 논리 CPU 하나의 물리 위치
+#[derive(Clone, Debug)]
+pub struct LogicalCpu {
+    pub cpu: usize,
+    pub package_id: u32,
+}
+
+/// NUMA 노드와 소속 논리 CPU
+#[derive(Clone, Debug)]
+pub struct NumaNode {
+    pub id: u32,
+    pub cpus: Vec<LogicalCpu>,
+}
+
+impl NumaNode {
+    pub fn contains(&self, cpu: usize) -> bool {
+        self.cpus.iter().any(|c| c.cpu == cpu)
+    }
+
+    /// 노드에 속한 물리 패키지(소켓) 번호
+    pub fn packages(&self) -> Vec<u32> {
+        let mut packages: Vec<u32> = self.cpus.iter().map(|c| c.package_id).collect();
+        packages.sort_unstable();
+        packages.dedup();
+        packages
+    }
+}
+
+/// 하이퍼스레딩으로 같은 물리 코어를 공유하는 논리 CPU 묶음
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhysicalCore {
+    /// 묶음의 논리 CPU 번호 (오름차순)
+    pub threads: Vec<usize>,
+}
+
+impl PhysicalCore {
+    /// 논리 CPU별 `thread_siblings_list` 내용에서 물리 코어 목록 생성 (첫 논리 CPU 순)
+    pub fn group_siblings<'a>(lists: impl IntoIterator<Item = &'a str>) -> Vec<Self> {
+        let mut cores: Vec<Self> = lists
+            .into_iter()
+            .map(|list| {
+                let mut threads = parse_cpu_list(list);
+                threads.sort_unstable();
+                threads.dedup();
+                Self { threads }
+            })
+            .filter(|core| !core.threads.is_empty())
+            .collect();
+        cores.sort_by_key(|core| core.threads[0]);
+        cores.dedup();
+        cores
+    }
+
+    /// sysfs에서 물리 코어 목록 읽기 - sysfs가 없으면(Windows 등) None
+    pub fn detect() -> Option<Vec<Self>> {
+        let lists: Vec<String> = fs::read_dir(CPU_SYSFS_DIR)
+            .ok()?
+            .flatten()
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| name.strip_prefix("cpu"))
+                    .is_some_and(|n| n.parse::<usize>().is_ok())
+            })
+            .filter_map(|entry| {
+                fs::read_to_string(entry.path().join("topology").join("thread_siblings_list")).ok()
+            })
+            .collect();
+        let cores = Self::group_siblings(lists.iter().map(String::as_str));
+        (!cores.is_empty()).then_some(cores)
+    }
+}
+
+/// NUMA 노드별 논리 CPU 구성 (Linux sysfs)
+#[derive(Clone, Debug)]
+pub struct CpuTopology {
+    nodes: Vec<NumaNode>,
+}
+
+fn read_u32(path: &Path) -> Option<u32> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// "0-3,8-11" 형식의 CPU 목록 파싱
+fn parse_cpu_list(list: &str) -> Vec<usize> {
+    list.trim()
+        .split(',')
+        .filter(|part| !part.is_empty())
+        .flat_map(|part| {
+            let (start, end) = part.split_once('-').unwrap_or((part, part));
+            match (start.parse::<usize>(), end.parse::<usize>()) {
+                (Ok(start), Ok(end)) => (start..=end).collect(),
+                _ => Vec::new(),
+            }
+        })
+        .collect()
+}
+
+impl CpuTopology {
+    /// sysfs에서 토폴로지 읽기 - sysfs가 없으면(Windows 등) None
+    pub fn detect() -> Option<Self> {
+        let cpu = |cpu: usize| {
+            let topology = Path::new(CPU_SYSFS_DIR)
+                .join(format!("cpu{}", cpu))
+                .join("topology");
+            Some(LogicalCpu {
+                cpu,
+                package_id: read_u32(&topology.join("physical_package_id"))?,
+            })
+        };
+
+        let mut nodes: Vec<NumaNode> = fs::read_dir(NODE_SYSFS_DIR)
+            .ok()?
+            .flatten()
+            .filter_map(|entry| {
+                let id = entry.file_name().to_str()?.strip_prefix("node")?.parse().ok()?;
+                let list = fs::read_to_string(entry.path().join("cpulist")).ok()?;
+                let cpus: Vec<LogicalCpu> = parse_cpu_list(&list).into_iter().filter_map(cpu).collect();
+                Some(NumaNode { id, cpus })
+            })
+            // 메모리 전용 노드 등 CPU가 없는 노드는 제외
+            .filter(|node| !node.cpus.is_empty())
+            .collect();
+        if nodes.is_empty() {
+            return None;
+        }
+        nodes.sort_by_key(|node| node.id);
+        Some(Self { nodes })
+    }
+
+    pub fn nodes(&self) -> &[NumaNode] {
+        &self.nodes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thread_siblings_group_logical_cpus_by_physical_core() {
+        let cores = PhysicalCore::group_siblings(["1,17", "0,16", "16,0", "2", ""]);
+        let threads: Vec<Vec<usize>> = cores.into_iter().map(|core| core.threads).collect();
+        assert_eq!(threads, vec![vec![0, 16], vec![1, 17], vec![2]]);
+    }
+}
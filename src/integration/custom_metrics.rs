@@ -0,0 +1,148 @@
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, ErrorKind},
+    os::unix::{
+        fs::FileTypeExt,
+        net::{UnixListener, UnixStream},
+    },
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+/// 종료 플래그 확인 간격
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 소켓으로 받은 사용자 정의 메트릭 한 건 (`{"name":"my_metric","value":42.0}`)
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct CustomMetricSample {
+    pub name: String,
+    pub value: f64,
+}
+
+/// Unix 소켓에서 줄 단위 JSON 메트릭을 받는 수신기
+///
+/// 연결마다 스레드를 하나씩 두고, 형식이 맞지 않는 줄은 무시함
+pub struct CustomMetricReader {
+    path: PathBuf,
+    receiver: Receiver<CustomMetricSample>,
+    error: Option<String>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl CustomMetricReader {
+    /// 소켓을 만들고 수신 시작 - 실패 사유는 `error()`로 확인
+    pub fn start(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let (handle, error) = match Self::bind(&path) {
+            Ok(listener) => {
+                let stop = stop.clone();
+                let handle = thread::spawn(move || Self::accept_loop(listener, sender, stop));
+                (Some(handle), None)
+            }
+            Err(e) => (None, Some(format!("Cannot listen on {}: {}", path.display(), e))),
+        };
+
+        Self {
+            path,
+            receiver,
+            error,
+            stop,
+            handle,
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// 지난 호출 이후 받은 메트릭
+    pub fn poll(&self) -> Vec<CustomMetricSample> {
+        self.receiver.try_iter().collect()
+    }
+
+    /// 이전 실행이 남긴 소켓 파일은 지우고 바인드 (일반 파일은 건드리지 않음)
+    fn bind(path: &Path) -> io::Result<UnixListener> {
+        if fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_socket()) {
+            fs::remove_file(path)?;
+        }
+        let listener = UnixListener::bind(path)?;
+        // 종료 플래그를 확인할 수 있도록 accept를 막지 않음
+        listener.set_nonblocking(true)?;
+        Ok(listener)
+    }
+
+    fn accept_loop(
+        listener: UnixListener,
+        sender: Sender<CustomMetricSample>,
+        stop: Arc<AtomicBool>,
+    ) {
+        let mut clients = Vec::new();
+        while !stop.load(Ordering::Relaxed) {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    let sender = sender.clone();
+                    let stop = stop.clone();
+                    clients.push(thread::spawn(move || Self::read_client(stream, sender, stop)));
+                }
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
+                    thread::sleep(STOP_POLL_INTERVAL)
+                }
+                Err(_) => break,
+            }
+            clients.retain(|client: &JoinHandle<()>| !client.is_finished());
+        }
+        for client in clients {
+            let _ = client.join();
+        }
+    }
+
+    /// 연결이 끊기거나 종료 플래그가 설 때까지 줄 단위로 읽음
+    fn read_client(stream: UnixStream, sender: Sender<CustomMetricSample>, stop: Arc<AtomicBool>) {
+        if stream.set_nonblocking(false).is_err()
+            || stream.set_read_timeout(Some(STOP_POLL_INTERVAL)).is_err()
+        {
+            return;
+        }
+        let mut reader = BufReader::new(stream);
+        let mut line = Vec::new();
+        while !stop.load(Ordering::Relaxed) {
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) => break,
+                Ok(_) => {
+                    if let Ok(sample) = serde_json::from_slice::<CustomMetricSample>(&line)
+                        && sample.value.is_finite()
+                        && sender.send(sample).is_err()
+                    {
+                        break;
+                    }
+                    line.clear();
+                }
+                // 시간 초과 시 읽은 조각은 line에 남아 있으므로 이어서 읽음
+                Err(ref e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl Drop for CustomMetricReader {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use bollard::{
+    models::{ContainerStatsResponse, ContainerSummary},
+    query_parameters::{ListContainersOptionsBuilder, StatsOptionsBuilder},
+    Docker,
+};
+use futures_util::{future, StreamExt};
+
+/// 컨테이너 목록/통계 조회 간격
+pub const DOCKER_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// 종료 플래그 확인 간격
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 실행 중인 컨테이너 하나의 CPU/메모리 사용량
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerMetrics {
+    pub name: String,
+    /// 호스트 CPU 하나를 100%로 한 사용률 (코어 수만큼 100%를 넘을 수 있음)
+    pub cpu_pct: f64,
+    pub mem_used: u64,
+    pub mem_limit: u64,
+}
+
+impl ContainerMetrics {
+    /// 메모리 제한 대비 사용률 (%)
+    pub fn mem_percent(&self) -> f64 {
+        if self.mem_limit == 0 {
+            0.0
+        } else {
+            self.mem_used as f64 / self.mem_limit as f64 * 100.0
+        }
+    }
+
+    /// `docker stats`와 같은 방식으로 직전 샘플과의 차이에서 사용률 계산
+    fn from_stats(name: String, stats: &ContainerStatsResponse) -> Self {
+        let cpu = stats.cpu_stats.as_ref();
+        let precpu = stats.precpu_stats.as_ref();
+        let total = |s: Option<&bollard::models::ContainerCpuStats>| {
+            s.and_then(|s| s.cpu_usage.as_ref())
+                .and_then(|u| u.total_usage)
+                .unwrap_or(0)
+        };
+        let system = |s: Option<&bollard::models::ContainerCpuStats>| {
+            s.and_then(|s| s.system_cpu_usage).unwrap_or(0)
+        };
+        let cpu_delta = total(cpu).saturating_sub(total(precpu)) as f64;
+        let system_delta = system(cpu).saturating_sub(system(precpu)) as f64;
+        let online_cpus = cpu.and_then(|s| s.online_cpus).unwrap_or(1).max(1) as f64;
+        let cpu_pct = if system_delta > 0.0 {
+            cpu_delta / system_delta * online_cpus * 100.0
+        } else {
+            0.0
+        };
+
+        // 페이지 캐시는 회수 가능하므로 제외 (cgroup v2는 inactive_file, v1은 cache)
+        let memory = stats.memory_stats.as_ref();
+        let cache = memory
+            .and_then(|m| m.stats.as_ref())
+            .and_then(|s| s.get("inactive_file").or_else(|| s.get("cache")).copied())
+            .unwrap_or(0);
+        let mem_used = memory
+            .and_then(|m| m.usage)
+            .unwrap_or(0)
+            .saturating_sub(cache);
+
+        Self {
+            name,
+            cpu_pct,
+            mem_used,
+            mem_limit: memory.and_then(|m| m.limit).unwrap_or(0),
+        }
+    }
+}
+
+/// 백그라운드 스레드에서 전달되는 조회 결과
+enum DockerEvent {
+    Containers(Vec<ContainerMetrics>),
+    /// 소켓 없음, 권한 없음 등 (다음 주기에 다시 시도)
+    Unavailable(String),
+}
+
+/// Docker 소켓으로 실행 중인 컨테이너를 2초마다 조회하는 모니터
+pub struct DockerMonitor {
+    receiver: Receiver<DockerEvent>,
+    containers: Vec<ContainerMetrics>,
+    error: Option<String>,
+    /// 첫 조회 결과가 도착했는지
+    received: bool,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl DockerMonitor {
+    /// 조회 스레드 시작 (bollard는 async라 스레드 안에서 tokio 런타임을 돌림)
+    pub fn start() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let stop = stop.clone();
+            thread::spawn(move || Self::run(sender, stop))
+        };
+
+        Self {
+            receiver,
+            containers: Vec::new(),
+            error: None,
+            received: false,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// 이름순 컨테이너 목록
+    pub fn containers(&self) -> &[ContainerMetrics] {
+        &self.containers
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn has_received(&self) -> bool {
+        self.received
+    }
+
+    /// 새 결과 반영 - 바뀐 것이 있으면 true
+    pub fn poll(&mut self) -> bool {
+        let mut updated = false;
+        for event in self.receiver.try_iter() {
+            match event {
+                DockerEvent::Containers(containers) => {
+                    self.containers = containers;
+                    self.error = None;
+                }
+                DockerEvent::Unavailable(e) => {
+                    self.containers.clear();
+                    self.error = Some(e);
+                }
+            }
+            self.received = true;
+            updated = true;
+        }
+        updated
+    }
+
+    fn run(sender: Sender<DockerEvent>, stop: Arc<AtomicBool>) {
+        let runtime = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                let _ = sender.send(DockerEvent::Unavailable(e.to_string()));
+                return;
+            }
+        };
+
+        while !stop.load(Ordering::Relaxed) {
+            let event = match runtime.block_on(Self::fetch()) {
+                Ok(containers) => DockerEvent::Containers(containers),
+                Err(e) => DockerEvent::Unavailable(e),
+            };
+            if sender.send(event).is_err() {
+                break;
+            }
+
+            // 종료가 늦어지지 않도록 나눠서 대기
+            let mut waited = Duration::ZERO;
+            while waited < DOCKER_POLL_INTERVAL && !stop.load(Ordering::Relaxed) {
+                thread::sleep(STOP_POLL_INTERVAL);
+                waited += STOP_POLL_INTERVAL;
+            }
+        }
+    }
+
+    /// 실행 중인 컨테이너 목록과 각 컨테이너의 통계를 동시에 조회
+    async fn fetch() -> Result<Vec<ContainerMetrics>, String> {
+        let docker = Docker::connect_with_local_defaults().map_err(|e| e.to_string())?;
+        // 연결은 지연되므로 소켓이 없으면 여기서 실패
+        docker
+            .ping()
+            .await
+            .map_err(|e| format!("Docker socket not found or not accessible: {}", e))?;
+
+        let filters = HashMap::from([("status", vec!["running"])]);
+        let options = ListContainersOptionsBuilder::new().filters(&filters).build();
+        let summaries = docker
+            .list_containers(Some(options))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut containers: Vec<ContainerMetrics> =
+            future::join_all(summaries.iter().map(|summary| Self::fetch_stats(&docker, summary)))
+                .await
+                .into_iter()
+                .flatten()
+                .collect();
+        containers.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(containers)
+    }
+
+    /// 컨테이너 하나의 통계 (이미 종료된 경우 등은 None)
+    async fn fetch_stats(docker: &Docker, summary: &ContainerSummary) -> Option<ContainerMetrics> {
+        let id = summary.id.as_deref()?;
+        // 이름은 "/name" 형식
+        let name = summary
+            .names
+            .as_ref()
+            .and_then(|names| names.first())
+            .map_or_else(|| id.chars().take(12).collect(), |name| {
+                name.trim_start_matches('/').to_string()
+            });
+
+        let options = StatsOptionsBuilder::new().stream(false).build();
+        let stats = docker.stats(id, Some(options)).next().await?.ok()?;
+        Some(ContainerMetrics::from_stats(name, &stats))
+    }
+}
+
+impl Drop for DockerMonitor {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
@@ -0,0 +1,463 @@
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    fs,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+    time::Instant,
+};
+
+use nvml_wrapper::{
+    enum_wrappers::device::{Clock, TemperatureSensor},
+    enums::device::UsedGpuMemory,
+    Nvml,
+};
+use sysinfo::{Pid, System};
+
+/// DRM 카드 목록 디렉터리 (AMD GPU 열거용)
+const DRM_CLASS_DIR: &str = "/sys/class/drm";
+
+/// intel_gpu_top 샘플링 간격 (ms)
+const INTEL_GPU_TOP_PERIOD_MS: &str = "1000";
+
+/// GPU 사용량 제공자 (수집 스레드로 옮겨 사용하므로 Send)
+pub trait GpuProvider: Send {
+    /// GPU 사용률 (0.0 ~ 100.0)
+    fn utilization(&self) -> Option<f64>;
+
+    /// VRAM 사용률 (0.0 ~ 100.0)
+    fn vram_percent(&self) -> Option<f64>;
+
+    /// 그래프 제목에 표시할 장치 이름
+    fn name(&self) -> String;
+
+    /// GPU 코어 온도 (°C)
+    fn temperature(&self) -> Option<f64> {
+        None
+    }
+
+    /// 현재 그래픽 클럭 (MHz)
+    fn graphics_clock_mhz(&self) -> Option<f64> {
+        None
+    }
+
+    /// 최대 그래픽 클럭 (MHz, 그래프 y축 범위용)
+    fn max_graphics_clock_mhz(&self) -> Option<f64> {
+        None
+    }
+}
+
+/// sysfs 숫자 파일 읽기
+fn read_sysfs_u64(device_dir: &Path, name: &str) -> Option<u64> {
+    fs::read_to_string(device_dir.join(name))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// `pp_dpm_sclk`의 DPM 단계 목록 파싱 ("1: 1800Mhz *" 형식, *는 현재 단계)
+fn parse_dpm_levels(content: &str) -> Vec<(u64, bool)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once(':')?;
+            let rest = rest.trim();
+            let mhz = rest
+                .trim_end_matches('*')
+                .trim()
+                .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+                .parse()
+                .ok()?;
+            Some((mhz, rest.ends_with('*')))
+        })
+        .collect()
+}
+
+/// 사용량/전체 바이트를 백분율로 변환
+fn percent_of(used: u64, total: u64) -> Option<f64> {
+    if total == 0 {
+        return None;
+    }
+    Some(used as f64 / total as f64 * 100.0)
+}
+
+/// `/sys/class/drm/cardN` 목록 (card0-DP-1 같은 커넥터 항목 제외)
+fn drm_cards() -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(DRM_CLASS_DIR) else {
+        return Vec::new();
+    };
+    let mut cards: Vec<PathBuf> = entries
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.strip_prefix("card")
+                .is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(|entry| entry.path())
+        .collect();
+    cards.sort();
+    cards
+}
+
+/// NVML 초기화 (NVIDIA 드라이버가 없으면 None) - 장치 열거와 프로세스 조회가 함께 사용
+pub fn init_nvml() -> Option<Arc<Nvml>> {
+    Nvml::init().ok().map(Arc::new)
+}
+
+/// 모든 GPU 열거 (NVML → amdgpu sysfs → i915 순으로 처음 발견된 종류만)
+pub fn enumerate_gpus(nvml: Option<&Arc<Nvml>>) -> Vec<Box<dyn GpuProvider>> {
+    if let Some(nvml) = nvml {
+        let count = nvml.device_count().unwrap_or(0);
+        if count > 0 {
+            return (0..count)
+                .map(|index| {
+                    Box::new(NvidiaGpu {
+                        nvml: nvml.clone(),
+                        index,
+                    }) as Box<dyn GpuProvider>
+                })
+                .collect();
+        }
+    }
+
+    let cards = drm_cards();
+    let amd: Vec<Box<dyn GpuProvider>> = cards
+        .iter()
+        .map(|card| card.join("device"))
+        .filter(|device_dir| device_dir.join("gpu_busy_percent").exists())
+        .map(|device_dir| Box::new(AmdGpu { device_dir }) as Box<dyn GpuProvider>)
+        .collect();
+    if !amd.is_empty() {
+        return amd;
+    }
+
+    cards
+        .into_iter()
+        .filter(|card| IntelGpuProvider::is_i915(card))
+        .map(|card| Box::new(IntelGpuProvider::new(card)) as Box<dyn GpuProvider>)
+        .collect()
+}
+
+/// GPU 메모리를 쓰는 프로세스 하나
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GpuProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    /// 모든 GPU에서 쓰는 메모리 합 (MB)
+    pub gpu_mem_mb: u64,
+}
+
+/// NVML이 보고하는 GPU 사용 프로세스 (이름은 이미 갱신된 `system`의 프로세스 목록에서 조회)
+///
+/// 게임 등 그래픽 프로세스도 포함하기 위해 compute와 graphics 목록을 합침.
+/// Windows(WDDM)처럼 메모리를 알 수 없는 경우는 0 MB
+pub fn gpu_processes(nvml: &Nvml, system: &System) -> Vec<GpuProcessInfo> {
+    let mut used_bytes: HashMap<u32, u64> = HashMap::new();
+    for index in 0..nvml.device_count().unwrap_or(0) {
+        let Ok(device) = nvml.device_by_index(index) else {
+            continue;
+        };
+        // 같은 프로세스가 두 목록에 모두 나오면 장치 안에서는 한 번만 셈
+        let mut per_device: HashMap<u32, u64> = HashMap::new();
+        let compute = device.running_compute_processes().unwrap_or_default();
+        let graphics = device.running_graphics_processes().unwrap_or_default();
+        for process in compute.into_iter().chain(graphics) {
+            let bytes = match process.used_gpu_memory {
+                UsedGpuMemory::Used(bytes) => bytes,
+                UsedGpuMemory::Unavailable => 0,
+            };
+            let entry = per_device.entry(process.pid).or_default();
+            *entry = (*entry).max(bytes);
+        }
+        for (pid, bytes) in per_device {
+            *used_bytes.entry(pid).or_default() += bytes;
+        }
+    }
+    let mut processes: Vec<GpuProcessInfo> = used_bytes
+        .into_iter()
+        .map(|(pid, bytes)| GpuProcessInfo {
+            pid,
+            name: system
+                .process(Pid::from_u32(pid))
+                .map(|process| process.name().to_string_lossy().into_owned())
+                .unwrap_or_else(|| "-".to_string()),
+            gpu_mem_mb: bytes / 1024 / 1024,
+        })
+        .collect();
+    processes.sort_by(|a, b| b.gpu_mem_mb.cmp(&a.gpu_mem_mb).then(a.pid.cmp(&b.pid)));
+    processes
+}
+
+/// NVML 장치 하나
+pub struct NvidiaGpu {
+    nvml: Arc<Nvml>,
+    index: u32,
+}
+
+impl GpuProvider for NvidiaGpu {
+    fn utilization(&self) -> Option<f64> {
+        self.nvml
+            .device_by_index(self.index)
+            .and_then(|device| device.utilization_rates())
+            .ok()
+            .map(|utilization| utilization.gpu as f64)
+    }
+
+    fn vram_percent(&self) -> Option<f64> {
+        let memory_info = self
+            .nvml
+            .device_by_index(self.index)
+            .and_then(|device| device.memory_info())
+            .ok()?;
+        percent_of(memory_info.used, memory_info.total)
+    }
+
+    fn name(&self) -> String {
+        self.nvml
+            .device_by_index(self.index)
+            .and_then(|device| device.name())
+            .unwrap_or_else(|_| format!("NVIDIA GPU {}", self.index))
+    }
+
+    fn temperature(&self) -> Option<f64> {
+        self.nvml
+            .device_by_index(self.index)
+            .and_then(|device| device.temperature(TemperatureSensor::Gpu))
+            .ok()
+            .map(|celsius| celsius as f64)
+    }
+
+    fn graphics_clock_mhz(&self) -> Option<f64> {
+        self.nvml
+            .device_by_index(self.index)
+            .and_then(|device| device.clock_info(Clock::Graphics))
+            .ok()
+            .map(|mhz| mhz as f64)
+    }
+
+    fn max_graphics_clock_mhz(&self) -> Option<f64> {
+        self.nvml
+            .device_by_index(self.index)
+            .and_then(|device| device.max_clock_info(Clock::Graphics))
+            .ok()
+            .map(|mhz| mhz as f64)
+    }
+}
+
+/// amdgpu sysfs 장치 하나 (`/sys/class/drm/cardN/device`)
+pub struct AmdGpu {
+    device_dir: PathBuf,
+}
+
+impl AmdGpu {
+    fn dpm_levels(&self) -> Vec<(u64, bool)> {
+        fs::read_to_string(self.device_dir.join("pp_dpm_sclk"))
+            .map(|content| parse_dpm_levels(&content))
+            .unwrap_or_default()
+    }
+}
+
+impl GpuProvider for AmdGpu {
+    fn utilization(&self) -> Option<f64> {
+        read_sysfs_u64(&self.device_dir, "gpu_busy_percent").map(|v| v as f64)
+    }
+
+    fn vram_percent(&self) -> Option<f64> {
+        percent_of(
+            read_sysfs_u64(&self.device_dir, "mem_info_vram_used")?,
+            read_sysfs_u64(&self.device_dir, "mem_info_vram_total")?,
+        )
+    }
+
+    fn name(&self) -> String {
+        // product_name은 일부 보드에서만 제공됨
+        fs::read_to_string(self.device_dir.join("product_name"))
+            .ok()
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "AMD GPU".to_string())
+    }
+
+    /// `device/hwmon/hwmonN/temp1_input` (밀리도 단위)
+    fn temperature(&self) -> Option<f64> {
+        fs::read_dir(self.device_dir.join("hwmon"))
+            .ok()?
+            .flatten()
+            .find_map(|entry| read_sysfs_u64(&entry.path(), "temp1_input"))
+            .map(|millidegrees| millidegrees as f64 / 1000.0)
+    }
+
+    fn graphics_clock_mhz(&self) -> Option<f64> {
+        self.dpm_levels()
+            .into_iter()
+            .find(|&(_, current)| current)
+            .map(|(mhz, _)| mhz as f64)
+    }
+
+    fn max_graphics_clock_mhz(&self) -> Option<f64> {
+        self.dpm_levels()
+            .into_iter()
+            .map(|(mhz, _)| mhz)
+            .max()
+            .map(|mhz| mhz as f64)
+    }
+}
+
+/// `intel_gpu_top -J` 백그라운드 프로세스 (Render/3D 엔진 사용률 수집)
+struct IntelGpuTop {
+    child: Child,
+    busy: Arc<Mutex<Option<f64>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl IntelGpuTop {
+    /// 실행 파일이 없거나 시작에 실패하면 None
+    fn spawn(card: &Path) -> Option<Self> {
+        let device = card.file_name()?.to_string_lossy().into_owned();
+        let mut child = Command::new("intel_gpu_top")
+            .args(["-J", "-s", INTEL_GPU_TOP_PERIOD_MS])
+            .args(["-d", &format!("drm:/dev/dri/{}", device)])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .ok()?;
+        let stdout = child.stdout.take()?;
+
+        let busy = Arc::new(Mutex::new(None));
+        let handle = {
+            let busy = busy.clone();
+            thread::spawn(move || Self::read_samples(BufReader::new(stdout), &busy))
+        };
+        Some(Self {
+            child,
+            busy,
+            handle: Some(handle),
+        })
+    }
+
+    /// 끝없이 이어지는 JSON 배열에서 객체 단위로 잘라 파싱 (프로세스 종료 시 반환)
+    fn read_samples(reader: impl BufRead, busy: &Mutex<Option<f64>>) {
+        let mut object = String::new();
+        let mut depth = 0usize;
+        for line in reader.lines().map_while(Result::ok) {
+            for c in line.chars() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                if depth > 0 || c == '}' {
+                    object.push(c);
+                }
+                if depth == 0 && c == '}' {
+                    if let Some(percent) = Self::render_busy(&object)
+                        && let Ok(mut busy) = busy.lock()
+                    {
+                        *busy = Some(percent);
+                    }
+                    object.clear();
+                }
+            }
+        }
+    }
+
+    /// "Render/3D" 엔진의 busy 값 (버전에 따라 "Render/3D/0"일 수 있음)
+    fn render_busy(object: &str) -> Option<f64> {
+        let json: serde_json::Value = serde_json::from_str(object).ok()?;
+        json["engines"]
+            .as_object()?
+            .iter()
+            .filter(|(name, _)| name.starts_with("Render/3D"))
+            .filter_map(|(_, engine)| engine["busy"].as_f64())
+            .reduce(f64::max)
+    }
+
+    fn busy(&self) -> Option<f64> {
+        self.busy.lock().ok().and_then(|busy| *busy)
+    }
+}
+
+impl Drop for IntelGpuTop {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// i915 드라이버 GPU 하나 (`/sys/class/drm/cardN`)
+pub struct IntelGpuProvider {
+    /// `gt/gt0` 디렉터리 (RC6 잔류 시간, 주파수)
+    gt_dir: PathBuf,
+    /// intel_gpu_top을 실행할 수 있으면 그 값을 우선 사용
+    gpu_top: Option<IntelGpuTop>,
+    /// 직전 RC6 잔류 시간 (ms)과 측정 시각
+    last_rc6: Cell<Option<(u64, Instant)>>,
+}
+
+impl IntelGpuProvider {
+    pub fn new(card: PathBuf) -> Self {
+        let gpu_top = IntelGpuTop::spawn(&card);
+        Self {
+            gt_dir: card.join("gt").join("gt0"),
+            gpu_top,
+            last_rc6: Cell::new(None),
+        }
+    }
+
+    /// 카드의 커널 드라이버가 i915인지 확인
+    fn is_i915(card: &Path) -> bool {
+        fs::read_link(card.join("device").join("driver"))
+            .ok()
+            .and_then(|driver| driver.file_name().map(|name| name == "i915"))
+            .unwrap_or(false)
+    }
+
+    /// 지난 호출 이후 절전(RC6) 상태가 아니었던 시간 비율로 사용률 추정
+    fn rc6_utilization(&self) -> Option<f64> {
+        let residency_ms = read_sysfs_u64(&self.gt_dir, "rc6_residency_ms")?;
+        let now = Instant::now();
+        let previous = self.last_rc6.replace(Some((residency_ms, now)));
+        let (last_residency_ms, last_time) = previous?;
+
+        let elapsed_ms = now.duration_since(last_time).as_secs_f64() * 1000.0;
+        if elapsed_ms <= 0.0 {
+            return None;
+        }
+        let idle_ms = residency_ms.saturating_sub(last_residency_ms) as f64;
+        Some((100.0 - idle_ms / elapsed_ms * 100.0).clamp(0.0, 100.0))
+    }
+}
+
+impl GpuProvider for IntelGpuProvider {
+    fn utilization(&self) -> Option<f64> {
+        self.gpu_top
+            .as_ref()
+            .and_then(IntelGpuTop::busy)
+            .or_else(|| self.rc6_utilization())
+    }
+
+    /// 내장 GPU는 시스템 메모리를 공유하므로 전용 VRAM 없음
+    fn vram_percent(&self) -> Option<f64> {
+        None
+    }
+
+    fn name(&self) -> String {
+        "Intel GPU".to_string()
+    }
+
+    fn graphics_clock_mhz(&self) -> Option<f64> {
+        read_sysfs_u64(&self.gt_dir, "rps_cur_freq_mhz").map(|mhz| mhz as f64)
+    }
+
+    fn max_graphics_clock_mhz(&self) -> Option<f64> {
+        read_sysfs_u64(&self.gt_dir, "rps_max_freq_mhz").map(|mhz| mhz as f64)
+    }
+}
@@ -0,0 +1,145 @@
+use std::{collections::HashMap, fs, time::Instant};
+
+use crate::util::RingBuffer;
+
+const PROC_INTERRUPTS_PATH: &str = "/proc/interrupts";
+/// IRQ별로 보관할 초당 횟수 샘플 수
+const IRQ_HISTORY_SIZE: usize = 60;
+
+/// `/proc/interrupts`의 번호 있는 IRQ 한 줄
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IrqEntry {
+    /// 장치 이름 (컨트롤러/트리거 종류 뒤의 나머지)
+    pub description: String,
+    /// CPU별 누적 횟수
+    pub per_cpu: Vec<u64>,
+}
+
+impl IrqEntry {
+    pub fn total(&self) -> u64 {
+        self.per_cpu.iter().sum()
+    }
+}
+
+/// IRQ별 초당 발생 횟수 (Linux `/proc/interrupts`)
+pub struct IrqReader {
+    entries: HashMap<u32, IrqEntry>,
+    last_read: Instant,
+    rates: HashMap<u32, f64>,
+    history: HashMap<u32, RingBuffer<u64>>,
+}
+
+impl IrqReader {
+    /// `/proc/interrupts`를 읽을 수 없으면 None
+    pub fn detect() -> Option<Self> {
+        let content = fs::read_to_string(PROC_INTERRUPTS_PATH).ok()?;
+        Some(Self {
+            entries: Self::parse(&content),
+            last_read: Instant::now(),
+            rates: HashMap::new(),
+            history: HashMap::new(),
+        })
+    }
+
+    /// 번호 있는 IRQ만 파싱 (NMI, LOC 같은 이름 있는 항목은 제외)
+    pub fn parse(content: &str) -> HashMap<u32, IrqEntry> {
+        let mut lines = content.lines();
+        let cpu_count = lines
+            .next()
+            .map_or(0, |header| header.split_whitespace().count());
+
+        let mut entries = HashMap::new();
+        for line in lines {
+            let Some((irq, rest)) = line.split_once(':') else {
+                continue;
+            };
+            let Ok(irq) = irq.trim().parse::<u32>() else {
+                continue;
+            };
+            let mut fields = rest.split_whitespace().peekable();
+            let mut per_cpu = Vec::with_capacity(cpu_count);
+            while per_cpu.len() < cpu_count
+                && let Some(count) = fields.peek().and_then(|f| f.parse::<u64>().ok())
+            {
+                per_cpu.push(count);
+                fields.next();
+            }
+            // "IO-APIC 5-edge ACPI:Ged" → 컨트롤러와 트리거 종류 뒤가 장치 이름
+            let rest: Vec<&str> = fields.collect();
+            let description = if rest.len() > 2 {
+                rest[2..].join(" ")
+            } else {
+                rest.join(" ")
+            };
+            entries.insert(irq, IrqEntry { description, per_cpu });
+        }
+        entries
+    }
+
+    /// 이전 값과의 차이를 경과 시간으로 나눠 초당 횟수 계산
+    pub fn refresh(&mut self) {
+        let Ok(content) = fs::read_to_string(PROC_INTERRUPTS_PATH) else {
+            return;
+        };
+        let entries = Self::parse(&content);
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_read).as_secs_f64();
+        if elapsed <= 0.0 {
+            return;
+        }
+
+        self.rates.clear();
+        for (irq, entry) in &entries {
+            let previous = self.entries.get(irq).map_or(entry.total(), IrqEntry::total);
+            let rate = entry.total().saturating_sub(previous) as f64 / elapsed;
+            self.rates.insert(*irq, rate);
+            self.history
+                .entry(*irq)
+                .or_insert_with(|| RingBuffer::new(IRQ_HISTORY_SIZE))
+                .push(rate.round() as u64);
+        }
+        // 사라진 IRQ (장치 제거 등)의 기록은 버림
+        self.history.retain(|irq, _| entries.contains_key(irq));
+        self.entries = entries;
+        self.last_read = now;
+    }
+
+    pub fn entries(&self) -> &HashMap<u32, IrqEntry> {
+        &self.entries
+    }
+
+    pub fn rate(&self, irq: u32) -> f64 {
+        self.rates.get(&irq).copied().unwrap_or(0.0)
+    }
+
+    pub fn history(&self, irq: u32) -> Option<&RingBuffer<u64>> {
+        self.history.get(&irq)
+    }
+
+    /// 초당 횟수 내림차순 상위 `count`개의 IRQ 번호 (같으면 번호순)
+    pub fn top(&self, count: usize) -> Vec<u32> {
+        let mut irqs: Vec<u32> = self.entries.keys().copied().collect();
+        irqs.sort_by(|a, b| self.rate(*b).total_cmp(&self.rate(*a)).then(a.cmp(b)));
+        irqs.truncate(count);
+        irqs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proc_interrupts_parses_numbered_irqs_only() {
+        let entries = IrqReader::parse(
+            "           CPU0       CPU1\n\
+             \x20 16:        10          5   IO-APIC  16-fasteoi   ehci_hcd:usb1, nvidia\n\
+             \x20 24:         1          0  PCI-MSI 524288-edge      nvme0q0\n\
+             NMI:          0          0   Non-maskable interrupts\n",
+        );
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[&16].description, "ehci_hcd:usb1, nvidia");
+        assert_eq!(entries[&16].total(), 15);
+        assert_eq!(entries[&24].per_cpu, vec![1, 0]);
+    }
+}
@@ -0,0 +1,88 @@
+use std::{collections::HashMap, fs, io};
+
+const MEMINFO_PATH: &str = "/proc/meminfo";
+
+/// Huge pages 사용 정보
+#[derive(Clone, Copy, Debug)]
+pub struct HugePages {
+    pub total: u64,
+    pub free: u64,
+    /// 페이지 하나의 크기 (KB)
+    pub page_size_kb: u64,
+}
+
+impl HugePages {
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.free)
+    }
+
+    /// 모든 페이지가 사용 중이면 설정 문제 가능성 있음
+    pub fn is_exhausted(&self) -> bool {
+        self.total > 0 && self.free == 0
+    }
+}
+
+/// `/proc/meminfo` 파싱 결과 (단위는 KB, HugePages_* 항목은 페이지 수)
+pub struct MemInfo {
+    fields: HashMap<String, u64>,
+}
+
+impl MemInfo {
+    pub fn get(&self, key: &str) -> Option<u64> {
+        self.fields.get(key).copied()
+    }
+
+    pub fn buffers_kb(&self) -> Option<u64> {
+        self.get("Buffers")
+    }
+
+    pub fn cached_kb(&self) -> Option<u64> {
+        self.get("Cached")
+    }
+
+    /// 회수 가능한 슬랩 캐시
+    pub fn s_reclaimable_kb(&self) -> Option<u64> {
+        self.get("SReclaimable")
+    }
+
+    pub fn total_kb(&self) -> Option<u64> {
+        self.get("MemTotal")
+    }
+
+    pub fn free_kb(&self) -> Option<u64> {
+        self.get("MemFree")
+    }
+
+    /// Huge pages 정보 (필드가 없거나 비활성화된 경우 None)
+    pub fn huge_pages(&self) -> Option<HugePages> {
+        let huge_pages = HugePages {
+            total: self.get("HugePages_Total")?,
+            free: self.get("HugePages_Free")?,
+            page_size_kb: self.get("Hugepagesize")?,
+        };
+        (huge_pages.total > 0).then_some(huge_pages)
+    }
+}
+
+/// `/proc/meminfo` 리더
+pub struct MemInfoReader;
+
+impl MemInfoReader {
+    pub fn read() -> io::Result<MemInfo> {
+        let content = fs::read_to_string(MEMINFO_PATH)?;
+        Ok(Self::parse(&content))
+    }
+
+    /// "Key:   1234 kB" 형식의 줄들을 파싱
+    pub fn parse(content: &str) -> MemInfo {
+        let fields = content
+            .lines()
+            .filter_map(|line| {
+                let (key, rest) = line.split_once(':')?;
+                let value = rest.split_whitespace().next()?.parse().ok()?;
+                Some((key.trim().to_string(), value))
+            })
+            .collect();
+        MemInfo { fields }
+    }
+}
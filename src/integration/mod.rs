@@ -0,0 +1,26 @@
+#[cfg(target_os = "linux")]
+pub mod meminfo;
+#[cfg(target_os = "linux")]
+pub mod connections;
+#[cfg(target_os = "linux")]
+pub mod net_connections;
+pub mod cpu_freq;
+pub mod cpu_topology;
+#[cfg(unix)]
+pub mod custom_metrics;
+pub mod docker;
+pub mod gpu;
+pub mod irq_reader;
+pub mod net_speed;
+pub mod net_stats;
+pub mod ping;
+pub mod proc_stat;
+pub mod prometheus;
+pub mod rapl;
+pub mod smart;
+pub mod syslog;
+pub mod vrc_log;
+pub mod vrc_osc;
+pub mod vrc_process;
+#[cfg(target_os = "windows")]
+pub mod windows_memory;
@@ -0,0 +1,109 @@
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+    process::Command,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// 조회 결과(실패 포함)를 다시 묻지 않는 기간
+const HOSTNAME_TTL: Duration = Duration::from_secs(300);
+
+/// 연결 상대 IP의 호스트 이름 캐시 (`/proc/net/tcp` 파싱은 `connections` 모듈)
+///
+/// 역방향 조회는 수 초씩 걸릴 수 있어 백그라운드 스레드에서 `getent hosts`로 처리하고,
+/// UI에서는 이미 조회된 이름만 읽음
+pub struct HostnameCache {
+    /// IP → (호스트 이름, 조회 시각) - 이름이 없으면 None
+    entries: HashMap<IpAddr, (Option<String>, Instant)>,
+    /// 조회 요청을 보냈지만 아직 결과가 오지 않은 IP
+    pending: HashSet<IpAddr>,
+    requests: Option<Sender<IpAddr>>,
+    results: Receiver<(IpAddr, Option<String>)>,
+}
+
+impl HostnameCache {
+    pub fn new() -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<IpAddr>();
+        let (result_tx, result_rx) = mpsc::channel();
+
+        // 요청 송신자가 drop되면 recv가 실패하면서 스레드 종료
+        thread::spawn(move || {
+            for ip in request_rx {
+                if result_tx.send((ip, Self::resolve(ip))).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            entries: HashMap::new(),
+            pending: HashSet::new(),
+            requests: Some(request_tx),
+            results: result_rx,
+        }
+    }
+
+    /// 도착한 조회 결과를 캐시에 반영
+    pub fn poll(&mut self) {
+        for (ip, name) in self.results.try_iter() {
+            self.pending.remove(&ip);
+            self.entries.insert(ip, (name, Instant::now()));
+        }
+    }
+
+    /// 캐시에 없거나 만료된 IP면 백그라운드 조회 요청 (루프백/미지정 주소는 제외)
+    pub fn request(&mut self, ip: IpAddr) {
+        if ip.is_unspecified() || ip.is_loopback() || self.pending.contains(&ip) {
+            return;
+        }
+        if self
+            .entries
+            .get(&ip)
+            .is_some_and(|(_, resolved_at)| resolved_at.elapsed() < HOSTNAME_TTL)
+        {
+            return;
+        }
+        if let Some(ref requests) = self.requests
+            && requests.send(ip).is_ok()
+        {
+            self.pending.insert(ip);
+        }
+    }
+
+    /// 조회된 호스트 이름 (만료된 값도 다시 조회될 때까지 표시)
+    pub fn get(&self, ip: &IpAddr) -> Option<&str> {
+        self.entries.get(ip)?.0.as_deref()
+    }
+
+    /// `getent hosts <ip>` 출력의 두 번째 열
+    fn resolve(ip: IpAddr) -> Option<String> {
+        let output = Command::new("getent")
+            .args(["hosts", &ip.to_string()])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()?
+            .split_whitespace()
+            .nth(1)
+            .map(str::to_string)
+    }
+}
+
+impl Default for HostnameCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for HostnameCache {
+    fn drop(&mut self) {
+        // 조회 중인 getent를 기다리지 않도록 join하지 않고 송신자만 닫음
+        self.requests.take();
+    }
+}
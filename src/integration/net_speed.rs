@@ -0,0 +1,47 @@
+/// 링크 속도를 알 수 없을 때 가정하는 값 (Mbps)
+pub const FALLBACK_LINK_SPEED_MBPS: u64 = 1000;
+
+/// 인터페이스의 공칭 링크 속도
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LinkSpeed {
+    pub mbps: u64,
+    /// 읽지 못해 `FALLBACK_LINK_SPEED_MBPS`를 가정한 값인지
+    pub assumed: bool,
+}
+
+impl LinkSpeed {
+    /// 링크 속도를 읽고, 실패하면 1000 Mbps로 가정
+    pub fn detect(interface: &str) -> Self {
+        match read_link_speed_mbps(interface) {
+            Some(mbps) => Self { mbps, assumed: false },
+            None => Self {
+                mbps: FALLBACK_LINK_SPEED_MBPS,
+                assumed: true,
+            },
+        }
+    }
+
+    /// 링크 속도를 bytes/s로 환산
+    pub fn bytes_per_sec(&self) -> f64 {
+        self.mbps as f64 * 1_000_000.0 / 8.0
+    }
+
+    /// 링크 속도 대비 사용률 (%)
+    pub fn percent_of(&self, bytes_per_sec: f64) -> f64 {
+        bytes_per_sec / self.bytes_per_sec() * 100.0
+    }
+}
+
+/// `/sys/class/net/{iface}/speed` (Mbps) - 링크가 내려갔거나 가상 인터페이스면 None
+#[cfg(target_os = "linux")]
+pub fn read_link_speed_mbps(interface: &str) -> Option<u64> {
+    let path = std::path::Path::new("/sys/class/net").join(interface).join("speed");
+    // 알 수 없으면 -1을 돌려주므로 부호 있는 정수로 읽음
+    let speed: i64 = std::fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    u64::try_from(speed).ok().filter(|&mbps| mbps > 0)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_link_speed_mbps(_interface: &str) -> Option<u64> {
+    None
+}
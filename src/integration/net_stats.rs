@@ -0,0 +1,74 @@
+use std::io;
+
+/// 인터페이스별 누적 카운터
+#[derive(Clone, Debug, Default)]
+pub struct InterfaceCounters {
+    pub name: String,
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub rx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub tx_errors: u64,
+    pub tx_drops: u64,
+}
+
+/// 모든 인터페이스의 누적 카운터 읽기 (`/proc/net/dev`)
+#[cfg(target_os = "linux")]
+pub fn read_interface_counters() -> io::Result<Vec<InterfaceCounters>> {
+    let content = std::fs::read_to_string("/proc/net/dev")?;
+    Ok(parse_proc_net_dev(&content))
+}
+
+/// 모든 인터페이스의 누적 카운터 읽기 (drop 카운터는 제공되지 않음)
+#[cfg(not(target_os = "linux"))]
+pub fn read_interface_counters() -> io::Result<Vec<InterfaceCounters>> {
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    Ok(networks
+        .list()
+        .iter()
+        .map(|(name, data)| InterfaceCounters {
+            name: name.clone(),
+            rx_bytes: data.total_received(),
+            rx_packets: data.total_packets_received(),
+            rx_errors: data.total_errors_on_received(),
+            tx_bytes: data.total_transmitted(),
+            tx_packets: data.total_packets_transmitted(),
+            tx_errors: data.total_errors_on_transmitted(),
+            ..Default::default()
+        })
+        .collect())
+}
+
+/// `/proc/net/dev` 파싱 (앞 두 줄은 헤더)
+#[cfg(target_os = "linux")]
+fn parse_proc_net_dev(content: &str) -> Vec<InterfaceCounters> {
+    content
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(':')?;
+            let values: Vec<u64> = rest
+                .split_whitespace()
+                .filter_map(|v| v.parse().ok())
+                .collect();
+            if values.len() < 12 {
+                return None;
+            }
+            // rx: bytes packets errs drop fifo frame compressed multicast
+            // tx: bytes packets errs drop ...
+            Some(InterfaceCounters {
+                name: name.trim().to_string(),
+                rx_bytes: values[0],
+                rx_packets: values[1],
+                rx_errors: values[2],
+                rx_drops: values[3],
+                tx_bytes: values[8],
+                tx_packets: values[9],
+                tx_errors: values[10],
+                tx_drops: values[11],
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,151 @@
+use std::{
+    net::{IpAddr, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use fastping_rs::{PingResult, Pinger};
+
+/// ICMP echo 간격 (응답 대기 시간과 같음)
+pub const PING_INTERVAL: Duration = Duration::from_secs(1);
+/// 종료 플래그 확인 간격
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 백그라운드 스레드에서 전달되는 ping 결과
+#[derive(Clone, Debug)]
+pub enum PingEvent {
+    Reply(Duration),
+    Timeout,
+    /// 주소 해석 실패, raw 소켓 권한 없음 등 (이후 ping 중단)
+    Error(String),
+}
+
+/// 대상 하나에 1초마다 ICMP echo를 보내는 모니터
+pub struct PingMonitor {
+    target: String,
+    receiver: Receiver<PingEvent>,
+    sent: u64,
+    lost: u64,
+    error: Option<String>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl PingMonitor {
+    /// 호스트 이름 또는 IP 주소로 ping 스레드 시작
+    pub fn start(target: impl Into<String>) -> Self {
+        let target = target.into();
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let target = target.clone();
+            let stop = stop.clone();
+            thread::spawn(move || Self::run(&target, sender, stop))
+        };
+
+        Self {
+            target,
+            receiver,
+            sent: 0,
+            lost: 0,
+            error: None,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// 보낸 echo 중 응답이 없었던 비율 (%)
+    pub fn loss_percent(&self) -> f64 {
+        if self.sent == 0 {
+            0.0
+        } else {
+            self.lost as f64 / self.sent as f64 * 100.0
+        }
+    }
+
+    /// 지난 호출 이후 도착한 결과 (응답 없음은 None)
+    pub fn poll(&mut self) -> Vec<Option<Duration>> {
+        let mut samples = Vec::new();
+        for event in self.receiver.try_iter() {
+            match event {
+                PingEvent::Reply(rtt) => {
+                    self.sent += 1;
+                    samples.push(Some(rtt));
+                }
+                PingEvent::Timeout => {
+                    self.sent += 1;
+                    self.lost += 1;
+                    samples.push(None);
+                }
+                PingEvent::Error(e) => self.error = Some(e),
+            }
+        }
+        samples
+    }
+
+    fn resolve(target: &str) -> Result<IpAddr, String> {
+        (target, 0)
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+            .map(|addr| addr.ip())
+            .ok_or_else(|| format!("Cannot resolve {}", target))
+    }
+
+    fn run(target: &str, sender: Sender<PingEvent>, stop: Arc<AtomicBool>) {
+        let addr = match Self::resolve(target) {
+            Ok(addr) => addr,
+            Err(e) => {
+                let _ = sender.send(PingEvent::Error(e));
+                return;
+            }
+        };
+
+        // raw 소켓이 필요하므로 권한이 없으면 여기서 실패
+        let (pinger, results) = match Pinger::new(Some(PING_INTERVAL.as_millis() as u64), None) {
+            Ok(pinger) => pinger,
+            Err(e) => {
+                let _ = sender.send(PingEvent::Error(format!("ICMP unavailable: {}", e)));
+                return;
+            }
+        };
+        pinger.add_ipaddr(&addr.to_string());
+        pinger.run_pinger();
+
+        while !stop.load(Ordering::Relaxed) {
+            let event = match results.recv_timeout(STOP_POLL_INTERVAL) {
+                Ok(PingResult::Receive { rtt, .. }) => PingEvent::Reply(rtt),
+                Ok(PingResult::Idle { .. }) => PingEvent::Timeout,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+            if sender.send(event).is_err() {
+                break;
+            }
+        }
+        pinger.stop_pinger();
+    }
+}
+
+impl Drop for PingMonitor {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
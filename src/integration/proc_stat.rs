@@ -0,0 +1,75 @@
+use std::{fs, time::Instant};
+
+const PROC_STAT_PATH: &str = "/proc/stat";
+
+/// `/proc/stat`의 누적 카운터
+#[derive(Clone, Copy, Debug, Default)]
+struct Counters {
+    /// 부팅 이후 컨텍스트 스위치 수 (`ctxt`)
+    context_switches: u64,
+    /// 부팅 이후 인터럽트 수 (`intr`의 첫 번째 값)
+    interrupts: u64,
+}
+
+impl Counters {
+    fn read() -> Option<Self> {
+        let content = fs::read_to_string(PROC_STAT_PATH).ok()?;
+        let mut context_switches = None;
+        let mut interrupts = None;
+        for line in content.lines() {
+            let mut fields = line.split_whitespace();
+            match fields.next() {
+                Some("ctxt") => context_switches = fields.next().and_then(|v| v.parse().ok()),
+                Some("intr") => interrupts = fields.next().and_then(|v| v.parse().ok()),
+                _ => {}
+            }
+        }
+        Some(Self {
+            context_switches: context_switches?,
+            interrupts: interrupts?,
+        })
+    }
+}
+
+/// 초당 컨텍스트 스위치/인터럽트 수 (Linux `/proc/stat`)
+pub struct ProcStatReader {
+    last: (Counters, Instant),
+    context_switches_per_sec: f64,
+    interrupts_per_sec: f64,
+}
+
+impl ProcStatReader {
+    /// `/proc/stat`을 읽을 수 없으면 None
+    pub fn detect() -> Option<Self> {
+        Some(Self {
+            last: (Counters::read()?, Instant::now()),
+            context_switches_per_sec: 0.0,
+            interrupts_per_sec: 0.0,
+        })
+    }
+
+    /// 이전 값과의 차이를 경과 시간으로 나눠 초당 비율 계산
+    pub fn refresh(&mut self) {
+        let Some(counters) = Counters::read() else {
+            return;
+        };
+        let now = Instant::now();
+        let (last, last_time) = self.last;
+        let elapsed = now.duration_since(last_time).as_secs_f64();
+        if elapsed > 0.0 {
+            self.context_switches_per_sec =
+                counters.context_switches.saturating_sub(last.context_switches) as f64 / elapsed;
+            self.interrupts_per_sec =
+                counters.interrupts.saturating_sub(last.interrupts) as f64 / elapsed;
+        }
+        self.last = (counters, now);
+    }
+
+    pub fn context_switches_per_sec(&self) -> f64 {
+        self.context_switches_per_sec
+    }
+
+    pub fn interrupts_per_sec(&self) -> f64 {
+        self.interrupts_per_sec
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use crate::controllers::metrics_collector::{SharedSnapshot, SystemSnapshot};
+
+pub const DEFAULT_PROMETHEUS_PORT: u16 = 9898;
+/// 종료 플래그 확인 간격
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 공유 스냅샷을 Prometheus 텍스트 형식으로 `GET /metrics`에 응답하는 HTTP 서버
+pub struct PrometheusExporter {
+    /// 포트를 열지 못한 경우 그 사유
+    error: Option<String>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl PrometheusExporter {
+    /// 지정 포트(127.0.0.1)에서 서버 시작 - 바인드 실패 시 내보내기 없이 `error`에 기록
+    pub fn start(port: u16, snapshot: SharedSnapshot) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let listener = TcpListener::bind(("127.0.0.1", port)).and_then(|listener| {
+            // 종료 플래그를 확인할 수 있도록 논블로킹 accept
+            listener.set_nonblocking(true)?;
+            Ok(listener)
+        });
+
+        let (handle, error) = match listener {
+            Ok(listener) => {
+                let stop = stop.clone();
+                let handle = thread::spawn(move || Self::serve(listener, snapshot, stop));
+                (Some(handle), None)
+            }
+            Err(e) => (
+                None,
+                Some(format!("Prometheus port {} unavailable: {}", port, e)),
+            ),
+        };
+
+        Self {
+            error,
+            stop,
+            handle,
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// 연결을 한 번에 하나씩 처리
+    fn serve(listener: TcpListener, snapshot: SharedSnapshot, stop: Arc<AtomicBool>) {
+        while !stop.load(Ordering::Relaxed) {
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    let current = snapshot.lock().map(|s| s.clone()).unwrap_or_default();
+                    // 클라이언트 오류는 해당 연결만 버림
+                    let _ = Self::respond(stream, &current);
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    thread::sleep(ACCEPT_POLL_INTERVAL);
+                }
+                Err(_) => thread::sleep(ACCEPT_POLL_INTERVAL),
+            }
+        }
+    }
+
+    fn respond(stream: TcpStream, snapshot: &SystemSnapshot) -> io::Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+        stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+        let mut reader = BufReader::new(&stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // 나머지 헤더는 빈 줄까지 읽고 버림
+        let mut header = String::new();
+        while reader.read_line(&mut header)? > 0 && !header.trim_end().is_empty() {
+            header.clear();
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let (status, content_type, body) = match (parts.next(), parts.next()) {
+            (Some("GET"), Some("/metrics")) => (
+                "200 OK",
+                "text/plain; version=0.0.4",
+                Self::exposition(snapshot),
+            ),
+            (Some("GET"), _) => ("404 Not Found", "text/plain", "Not Found\n".to_string()),
+            _ => (
+                "405 Method Not Allowed",
+                "text/plain",
+                "Method Not Allowed\n".to_string(),
+            ),
+        };
+
+        let mut stream = &stream;
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            content_type,
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+
+    /// Prometheus 텍스트 형식 (GPU 값이 없으면 해당 항목 생략)
+    pub fn exposition(snapshot: &SystemSnapshot) -> String {
+        let metrics = [
+            (
+                "lvrc_cpu_usage_percent",
+                "Total CPU usage in percent.",
+                Some(snapshot.cpu_usage),
+            ),
+            (
+                "lvrc_memory_used_bytes",
+                "Used physical memory in bytes.",
+                Some(snapshot.used_memory as f64),
+            ),
+            (
+                "lvrc_memory_total_bytes",
+                "Total physical memory in bytes.",
+                Some(snapshot.total_memory as f64),
+            ),
+            (
+                "lvrc_gpu_usage_percent",
+                "GPU utilization in percent.",
+                snapshot.gpu_usage,
+            ),
+            (
+                "lvrc_vram_usage_percent",
+                "GPU memory usage in percent.",
+                snapshot.vram_percent,
+            ),
+        ];
+
+        let mut body = String::new();
+        for (name, help, value) in metrics {
+            let Some(value) = value else {
+                continue;
+            };
+            let _ = writeln!(body, "# HELP {} {}", name, help);
+            let _ = writeln!(body, "# TYPE {} gauge", name);
+            let _ = writeln!(body, "{} {}", name, value);
+        }
+        body
+    }
+}
+
+impl Drop for PrometheusExporter {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+/// powercap RAPL 도메인 디렉터리 (AMD도 같은 이름을 사용)
+const POWERCAP_DIR: &str = "/sys/class/powercap";
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+fn read_name(dir: &Path) -> Option<String> {
+    fs::read_to_string(dir.join("name"))
+        .ok()
+        .map(|name| name.trim().to_string())
+}
+
+/// 누적 에너지 카운터 하나 (`energy_uj`)
+struct RaplDomain {
+    energy_path: PathBuf,
+    /// 카운터가 0으로 돌아가기 전 최댓값
+    max_energy_uj: u64,
+    last: Option<(u64, Instant)>,
+    watts: f64,
+}
+
+impl RaplDomain {
+    /// 카운터를 읽을 수 있을 때만 생성 (대부분 root 권한 필요)
+    fn open(dir: &Path) -> Option<Self> {
+        let energy_path = dir.join("energy_uj");
+        read_u64(&energy_path)?;
+        Some(Self {
+            max_energy_uj: read_u64(&dir.join("max_energy_range_uj")).unwrap_or(u64::MAX),
+            energy_path,
+            last: None,
+            watts: 0.0,
+        })
+    }
+
+    /// 이전 값과의 차이를 경과 시간으로 나눠 평균 전력 계산
+    fn refresh(&mut self) {
+        let Some(energy) = read_u64(&self.energy_path) else {
+            return;
+        };
+        let now = Instant::now();
+        if let Some((last_energy, last_time)) = self.last {
+            let elapsed_us = now.duration_since(last_time).as_micros() as f64;
+            let delta = if energy >= last_energy {
+                energy - last_energy
+            } else {
+                // 카운터 한 바퀴 돎
+                self.max_energy_uj - last_energy + energy
+            };
+            if elapsed_us > 0.0 {
+                // µJ / µs = W
+                self.watts = delta as f64 / elapsed_us;
+            }
+        }
+        self.last = Some((energy, now));
+    }
+}
+
+/// CPU 패키지/코어 소비 전력 (Linux RAPL)
+pub struct RaplSensor {
+    package: RaplDomain,
+    core: Option<RaplDomain>,
+}
+
+impl RaplSensor {
+    /// 첫 번째 패키지 도메인 감지 - 없거나 읽을 수 없으면 None
+    pub fn detect() -> Option<Self> {
+        let package_dir = fs::read_dir(POWERCAP_DIR)
+            .ok()?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with("intel-rapl:"))
+            })
+            .find(|path| read_name(path).is_some_and(|name| name.starts_with("package")))?;
+        let package = RaplDomain::open(&package_dir)?;
+
+        // 하위 도메인 중 "core" (intel-rapl:0:0 등)
+        let core = fs::read_dir(&package_dir)
+            .ok()
+            .and_then(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .find(|path| read_name(path).as_deref() == Some("core"))
+            })
+            .and_then(|dir| RaplDomain::open(&dir));
+
+        let mut sensor = Self { package, core };
+        sensor.refresh();
+        Some(sensor)
+    }
+
+    pub fn refresh(&mut self) {
+        self.package.refresh();
+        if let Some(ref mut core) = self.core {
+            core.refresh();
+        }
+    }
+
+    pub fn package_watts(&self) -> f64 {
+        self.package.watts
+    }
+
+    /// 코어 도메인이 없는 CPU(대부분의 AMD)는 0
+    pub fn core_watts(&self) -> f64 {
+        self.core.as_ref().map_or(0.0, |core| core.watts)
+    }
+}
@@ -0,0 +1,160 @@
+use std::{io, process::Command};
+
+use serde_json::Value;
+use sysinfo::Disks;
+
+/// SMART 속성 ID
+const ATTR_REALLOCATED_SECTORS: u64 = 5;
+const ATTR_PENDING_SECTORS: u64 = 197;
+const ATTR_OFFLINE_UNCORRECTABLE: u64 = 198;
+/// 이 온도(°C)를 넘으면 건강도 감점
+const TEMPERATURE_WARN_C: i64 = 55;
+
+/// 디스크 하나의 SMART 요약
+#[derive(Clone, Debug, Default)]
+pub struct SmartReport {
+    pub device: String,
+    pub model: Option<String>,
+    /// 드라이브 자체 판정 (SMART overall-health)
+    pub passed: Option<bool>,
+    pub reallocated_sectors: Option<u64>,
+    pub pending_sectors: Option<u64>,
+    pub offline_uncorrectable: Option<u64>,
+    pub temperature_c: Option<i64>,
+    pub power_on_hours: Option<u64>,
+    /// smartctl 실행/파싱 실패 사유 (권한 부족 등)
+    pub error: Option<String>,
+}
+
+impl SmartReport {
+    /// 100%에서 불량 섹터/과열 가중 감점을 뺀 건강도 (자체 판정 실패면 0)
+    pub fn health_percent(&self) -> f64 {
+        if self.passed == Some(false) {
+            return 0.0;
+        }
+        let penalty = self.reallocated_sectors.unwrap_or(0) as f64 * 5.0
+            + self.pending_sectors.unwrap_or(0) as f64 * 10.0
+            + self.offline_uncorrectable.unwrap_or(0) as f64 * 10.0
+            + self
+                .temperature_c
+                .map_or(0.0, |t| (t - TEMPERATURE_WARN_C).max(0) as f64 * 2.0);
+        (100.0 - penalty).clamp(0.0, 100.0)
+    }
+
+    /// smartctl `-j` 출력에서 필요한 값만 추출
+    fn parse(device: &str, json: &Value) -> Self {
+        let attribute = |id: u64| {
+            json["ata_smart_attributes"]["table"]
+                .as_array()?
+                .iter()
+                .find(|attr| attr["id"].as_u64() == Some(id))?["raw"]["value"]
+                .as_u64()
+        };
+        // smartctl 자체 오류 메시지 (장치를 열 수 없음 등)
+        let error = json["smartctl"]["messages"]
+            .as_array()
+            .and_then(|messages| {
+                messages
+                    .iter()
+                    .find(|m| m["severity"].as_str() == Some("error"))
+            })
+            .and_then(|m| m["string"].as_str())
+            .map(str::to_string);
+
+        Self {
+            device: device.to_string(),
+            model: json["model_name"].as_str().map(str::to_string),
+            passed: json["smart_status"]["passed"].as_bool(),
+            reallocated_sectors: attribute(ATTR_REALLOCATED_SECTORS),
+            pending_sectors: attribute(ATTR_PENDING_SECTORS),
+            offline_uncorrectable: attribute(ATTR_OFFLINE_UNCORRECTABLE),
+            temperature_c: json["temperature"]["current"].as_i64(),
+            power_on_hours: json["power_on_time"]["hours"].as_u64(),
+            error,
+        }
+    }
+}
+
+/// `smartctl -j -a`로 sysinfo가 찾은 디스크의 SMART 정보 조회
+#[derive(Clone)]
+pub struct SmartReader {
+    devices: Vec<String>,
+}
+
+impl SmartReader {
+    /// 마운트된 파티션에서 장치 경로 수집 (/dev/sda1 → /dev/sda)
+    pub fn new() -> Self {
+        let mut devices: Vec<String> = Disks::new_with_refreshed_list()
+            .list()
+            .iter()
+            .map(|disk| disk.name().to_string_lossy().into_owned())
+            .filter(|name| name.starts_with("/dev/"))
+            .map(|name| Self::base_device(&name))
+            .collect();
+        devices.sort();
+        devices.dedup();
+        Self { devices }
+    }
+
+    pub fn devices(&self) -> &[String] {
+        &self.devices
+    }
+
+    /// 파티션 번호 제거 (nvme0n1p2 → nvme0n1, sda1 → sda)
+    fn base_device(name: &str) -> String {
+        // 이름 자체가 숫자로 끝나는 장치는 'p' 뒤의 번호가 파티션
+        if name.contains("nvme") || name.contains("mmcblk") {
+            return match name.rfind('p') {
+                Some(index)
+                    if index + 1 < name.len()
+                        && name[index + 1..].chars().all(|c| c.is_ascii_digit()) =>
+                {
+                    name[..index].to_string()
+                }
+                _ => name.to_string(),
+            };
+        }
+        name.trim_end_matches(|c: char| c.is_ascii_digit()).to_string()
+    }
+
+    /// smartctl이 PATH에 있는지 확인
+    pub fn is_available() -> bool {
+        !matches!(
+            Command::new("smartctl").arg("--version").output(),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound
+        )
+    }
+
+    /// 모든 장치 조회 (장치당 smartctl 한 번 실행 - 수백 ms 걸릴 수 있음)
+    pub fn read_all(&self) -> Vec<SmartReport> {
+        self.devices.iter().map(|device| Self::read(device)).collect()
+    }
+
+    fn read(device: &str) -> SmartReport {
+        // 종료 코드는 상태 비트 플래그라 0이 아니어도 JSON은 유효함
+        let output = match Command::new("smartctl").args(["-j", "-a", device]).output() {
+            Ok(output) => output,
+            Err(e) => {
+                return SmartReport {
+                    device: device.to_string(),
+                    error: Some(e.to_string()),
+                    ..Default::default()
+                };
+            }
+        };
+        match serde_json::from_slice::<Value>(&output.stdout) {
+            Ok(json) => SmartReport::parse(device, &json),
+            Err(e) => SmartReport {
+                device: device.to_string(),
+                error: Some(format!("Invalid smartctl output: {}", e)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Default for SmartReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
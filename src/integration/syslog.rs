@@ -0,0 +1,286 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom},
+    process::{Child, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+pub const SYSLOG_PATH: &str = "/var/log/syslog";
+/// 시작할 때 보여줄 최근 줄 수
+const INITIAL_LINES: usize = 100;
+/// 처음 열 때 파일 끝에서 읽을 최대 바이트
+const INITIAL_READ_BYTES: u64 = 64 * 1024;
+const FILE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 메시지 키워드로 추정한 심각도
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl LogSeverity {
+    fn classify(message: &str) -> Self {
+        let message = message.to_lowercase();
+        const ERROR_KEYWORDS: [&str; 6] =
+            ["error", "fail", "panic", "out of memory", "oom-kill", "segfault"];
+        if ERROR_KEYWORDS.iter().any(|keyword| message.contains(keyword)) {
+            LogSeverity::Error
+        } else if message.contains("warn") {
+            LogSeverity::Warning
+        } else {
+            LogSeverity::Info
+        }
+    }
+}
+
+/// syslog 한 줄 ("Oct 16 12:34:56 host kernel[123]: message")
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub hostname: String,
+    /// 프로세스 이름 (PID 제외)
+    pub process: String,
+    pub message: String,
+    pub severity: LogSeverity,
+}
+
+/// 앞의 공백을 건너뛰고 (첫 단어, 나머지)로 분리
+fn split_word(text: &str) -> Option<(&str, &str)> {
+    let text = text.trim_start();
+    if text.is_empty() {
+        return None;
+    }
+    Some(text.split_once(char::is_whitespace).unwrap_or((text, "")))
+}
+
+impl LogEntry {
+    /// BSD 형식과 RFC 3339 타임스탬프 형식 모두 파싱 (journalctl 안내 줄은 None)
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.starts_with("--") {
+            return None;
+        }
+
+        let (first, rest) = split_word(line)?;
+        let (timestamp, rest) = if first.starts_with(|c: char| c.is_ascii_digit()) {
+            // 2026-10-16T12:34:56.123456+09:00
+            (first.to_string(), rest)
+        } else {
+            let (day, rest) = split_word(rest)?;
+            let (time, rest) = split_word(rest)?;
+            (format!("{} {} {}", first, day, time), rest)
+        };
+        let (hostname, rest) = split_word(rest)?;
+        let (process, message) = match rest.split_once(": ") {
+            Some((tag, message)) => (tag.split('[').next().unwrap_or(tag), message),
+            None => ("", rest),
+        };
+
+        Some(Self {
+            timestamp,
+            hostname: hostname.to_string(),
+            process: process.to_string(),
+            message: message.to_string(),
+            severity: LogSeverity::classify(message),
+        })
+    }
+}
+
+/// 로그를 읽어 오는 곳
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyslogSource {
+    File,
+    Journal,
+}
+
+impl SyslogSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SyslogSource::File => SYSLOG_PATH,
+            SyslogSource::Journal => "journalctl",
+        }
+    }
+}
+
+/// 백그라운드 스레드에서 `/var/log/syslog`를 따라 읽거나,
+/// 파일을 열 수 없으면 `journalctl --follow` 출력을 읽음
+pub struct SyslogTail {
+    source: Option<SyslogSource>,
+    receiver: Option<Receiver<String>>,
+    error: Option<String>,
+    stop: Arc<AtomicBool>,
+    /// journalctl 프로세스 (종료 시 kill해야 읽기 스레드가 끝남)
+    child: Option<Child>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SyslogTail {
+    pub fn start() -> Self {
+        let mut tail = Self {
+            source: None,
+            receiver: None,
+            error: None,
+            stop: Arc::new(AtomicBool::new(false)),
+            child: None,
+            handle: None,
+        };
+        let (sender, receiver) = mpsc::channel();
+
+        match File::open(SYSLOG_PATH) {
+            Ok(file) => {
+                let stop = tail.stop.clone();
+                tail.handle = Some(thread::spawn(move || Self::follow_file(file, sender, stop)));
+                tail.source = Some(SyslogSource::File);
+            }
+            Err(file_error) => match Self::spawn_journalctl() {
+                Ok(mut child) => {
+                    let stdout = child.stdout.take();
+                    tail.handle = stdout.map(|stdout| {
+                        thread::spawn(move || Self::follow_stream(stdout, sender))
+                    });
+                    tail.child = Some(child);
+                    tail.source = Some(SyslogSource::Journal);
+                }
+                Err(journal_error) => {
+                    tail.error = Some(format!(
+                        "{}: {} / journalctl: {}",
+                        SYSLOG_PATH, file_error, journal_error
+                    ));
+                }
+            },
+        }
+        tail.receiver = Some(receiver);
+        tail
+    }
+
+    pub fn source(&self) -> Option<SyslogSource> {
+        self.source
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// 지난 호출 이후 읽은 줄 중 파싱된 항목
+    pub fn poll(&mut self) -> Vec<LogEntry> {
+        let Some(ref receiver) = self.receiver else {
+            return Vec::new();
+        };
+        receiver
+            .try_iter()
+            .filter_map(|line| LogEntry::parse(&line))
+            .collect()
+    }
+
+    fn spawn_journalctl() -> io::Result<Child> {
+        Command::new("journalctl")
+            .args(["--follow", "--output=short", "-n", &INITIAL_LINES.to_string()])
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+    }
+
+    /// journalctl 출력을 줄 단위로 전달 (프로세스가 끝나면 종료)
+    fn follow_stream(stdout: impl Read, sender: Sender<String>) {
+        for line in BufReader::new(stdout).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    }
+
+    /// 최근 줄을 먼저 보낸 뒤 파일 끝에 추가되는 줄을 주기적으로 전달
+    fn follow_file(mut file: File, sender: Sender<String>, stop: Arc<AtomicBool>) {
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        let mut offset = len.saturating_sub(INITIAL_READ_BYTES);
+        let mut pending = String::new();
+        let mut first_read = true;
+
+        while !stop.load(Ordering::Relaxed) {
+            // 로테이트로 파일이 줄었으면 처음부터 다시 읽음
+            if let Ok(metadata) = fs::metadata(SYSLOG_PATH)
+                && metadata.len() < offset
+                && let Ok(reopened) = File::open(SYSLOG_PATH)
+            {
+                file = reopened;
+                offset = 0;
+                pending.clear();
+            }
+
+            let mut buf = Vec::new();
+            if file.seek(SeekFrom::Start(offset)).is_ok() && file.read_to_end(&mut buf).is_ok() {
+                offset += buf.len() as u64;
+                pending.push_str(&String::from_utf8_lossy(&buf));
+            }
+
+            let mut lines: Vec<&str> = pending.split('\n').collect();
+            let rest = lines.pop().unwrap_or_default().to_string();
+            if first_read {
+                // 중간부터 읽었으면 첫 줄은 잘린 줄
+                if len > INITIAL_READ_BYTES && !lines.is_empty() {
+                    lines.remove(0);
+                }
+                lines.drain(..lines.len().saturating_sub(INITIAL_LINES));
+                first_read = false;
+            }
+            for line in lines {
+                if sender.send(line.to_string()).is_err() {
+                    return;
+                }
+            }
+            pending = rest;
+
+            thread::sleep(FILE_POLL_INTERVAL);
+        }
+    }
+}
+
+impl Drop for SyslogTail {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syslog_lines_parse_in_bsd_and_rfc3339_formats() {
+        let entry = LogEntry::parse(
+            "Oct  6 12:34:56 desktop kernel[0]: Out of memory: Killed process 4242 (VRChat.exe)",
+        )
+        .unwrap();
+        assert_eq!(entry.timestamp, "Oct 6 12:34:56");
+        assert_eq!(entry.hostname, "desktop");
+        assert_eq!(entry.process, "kernel");
+        assert_eq!(entry.severity, LogSeverity::Error);
+
+        let entry =
+            LogEntry::parse("2026-10-16T12:34:56.123+09:00 desktop systemd: Started session").unwrap();
+        assert_eq!(entry.timestamp, "2026-10-16T12:34:56.123+09:00");
+        assert_eq!(entry.message, "Started session");
+        assert_eq!(entry.severity, LogSeverity::Info);
+
+        assert!(LogEntry::parse("-- No entries --").is_none());
+    }
+}
@@ -0,0 +1,581 @@
+use std::{
+    collections::VecDeque,
+    fs::{self, File},
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use super::vrc_process::{ProcessTransition, VrchatProcessMonitor};
+
+const EXIT_LINE_MARKER: &str = "VRChat exited with code";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// 보관할 로그 이벤트 최대 개수
+const MAX_LOG_EVENTS: usize = 200;
+const FPS_MARKER: &str = "FPS:";
+const AUTHENTICATED_MARKER: &str = "User Authenticated: ";
+const AVATAR_SWITCH_MARKER: &str = "[Behaviour] Switching ";
+const AVATAR_DATA_MARKER: &str = "Loading Avatar Data:";
+const AVATAR_STATS_MARKER: &str = "[AvatarPerformance]";
+const WORLD_JOINING_MARKER: &str = "[Behaviour] Joining wrld_";
+const WORLD_ENTERING_MARKER: &str = "[Behaviour] Entering Room: ";
+const WORLD_LEFT_MARKER: &str = "[Behaviour] OnLeftRoom";
+/// 보관할 월드 입장/퇴장 기록 최대 개수
+const MAX_WORLD_EVENTS: usize = 100;
+
+/// VRChat 세션 이벤트
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VrchatEvent {
+    Started,
+    /// 로그에 종료 코드가 남은 정상 종료
+    Exited { exit_code: Option<i32> },
+    /// 종료 로그 없이 프로세스가 사라짐
+    Crash { exit_code: Option<i32> },
+}
+
+/// 로그 줄에서 인식한 이벤트 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VrcLogEventKind {
+    WorldJoin,
+    PlayerJoin,
+    PlayerLeave,
+    AvatarChange,
+    Error,
+}
+
+impl VrcLogEventKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            VrcLogEventKind::WorldJoin => "World",
+            VrcLogEventKind::PlayerJoin => "Join",
+            VrcLogEventKind::PlayerLeave => "Leave",
+            VrcLogEventKind::AvatarChange => "Avatar",
+            VrcLogEventKind::Error => "Error",
+        }
+    }
+}
+
+/// VRChat 로그에서 파싱한 이벤트 한 건
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VrcLogEvent {
+    /// 로그에 기록된 시각 ("HH:MM:SS")
+    pub time: String,
+    pub kind: VrcLogEventKind,
+    pub detail: String,
+}
+
+impl VrcLogEvent {
+    /// "2024.01.01 12:34:56 Log        -  [Behaviour] ..." 형식의 줄 파싱
+    pub fn parse(line: &str) -> Option<Self> {
+        let (header, message) = line.split_once(" -  ")?;
+        let mut header = header.split_whitespace();
+        let _date = header.next()?;
+        let time = header.next()?.to_string();
+        let level = header.next()?;
+        let message = message.trim();
+
+        let (kind, detail) = if level == "Error" {
+            (VrcLogEventKind::Error, message.to_string())
+        } else if let Some(world) = message.strip_prefix("[Behaviour] Entering Room: ") {
+            (VrcLogEventKind::WorldJoin, world.to_string())
+        } else if let Some(player) = message.strip_prefix("[Behaviour] OnPlayerJoined ") {
+            (VrcLogEventKind::PlayerJoin, player.to_string())
+        } else if let Some(player) = message.strip_prefix("[Behaviour] OnPlayerLeft ") {
+            (VrcLogEventKind::PlayerLeave, player.to_string())
+        } else if let Some(rest) = message.strip_prefix("[Behaviour] Switching ") {
+            let (player, avatar) = rest.split_once(" to avatar ")?;
+            (VrcLogEventKind::AvatarChange, format!("{} → {}", player, avatar))
+        } else {
+            return None;
+        };
+        Some(Self { time, kind, detail })
+    }
+}
+
+/// 로그의 "[VRC_API] FPS: 72.3" 줄에서 읽은 프레임레이트 기록
+pub struct VrcFpsTracker {
+    /// 아직 화면에 반영되지 않은 샘플
+    new_samples: Vec<f64>,
+}
+
+impl VrcFpsTracker {
+    pub fn new() -> Self {
+        Self {
+            new_samples: Vec::new(),
+        }
+    }
+
+    /// FPS 줄이면 값을 기록하고 반환
+    pub fn observe_line(&mut self, line: &str) -> Option<f64> {
+        let (_, rest) = line.split_once(FPS_MARKER)?;
+        let fps: f64 = rest.split_whitespace().next()?.parse().ok()?;
+        if !fps.is_finite() || fps < 0.0 {
+            return None;
+        }
+        self.new_samples.push(fps);
+        Some(fps)
+    }
+
+    /// 마지막 호출 이후 새로 기록된 샘플
+    pub fn drain_new_samples(&mut self) -> Vec<f64> {
+        std::mem::take(&mut self.new_samples)
+    }
+}
+
+impl Default for VrcFpsTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// VRChat 아바타 성능 등급
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PerfRank {
+    Excellent,
+    Good,
+    Medium,
+    Poor,
+    VeryPoor,
+}
+
+impl PerfRank {
+    /// 로그에 쓰이는 등급 이름 파싱 ("Very Poor", "VeryPoor" 모두 허용)
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.replace(' ', "").to_lowercase().as_str() {
+            "excellent" => Some(PerfRank::Excellent),
+            "good" => Some(PerfRank::Good),
+            "medium" => Some(PerfRank::Medium),
+            "poor" => Some(PerfRank::Poor),
+            "verypoor" => Some(PerfRank::VeryPoor),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PerfRank::Excellent => "Excellent",
+            PerfRank::Good => "Good",
+            PerfRank::Medium => "Medium",
+            PerfRank::Poor => "Poor",
+            PerfRank::VeryPoor => "Very Poor",
+        }
+    }
+}
+
+/// 현재 착용 중인 아바타 정보 (로그에 없는 값은 None)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AvatarInfo {
+    /// "avtr_..." 형식의 ID (아직 모르면 빈 문자열)
+    pub id: String,
+    pub name: String,
+    pub poly_count: Option<u64>,
+    pub perf_rank: Option<PerfRank>,
+}
+
+/// 월드 입장/퇴장 구분
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorldEventType {
+    Join,
+    Leave,
+}
+
+/// 월드 입장 또는 퇴장 한 건
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorldEvent {
+    /// 로그 줄을 읽은 시각
+    pub timestamp: SystemTime,
+    /// "wrld_..." 형식의 ID
+    pub world_id: String,
+    /// "Entering Room" 줄이 오기 전까지는 None
+    pub world_name: Option<String>,
+    pub event_type: WorldEventType,
+}
+
+/// 로그에서 월드 입장/퇴장 기록을 모음
+///
+/// "Joining wrld_..." 줄로 입장을 기록하고, 이어지는 "Entering Room" 줄의 월드 이름을 붙임
+#[derive(Default)]
+pub struct VrcWorldHistory {
+    /// 오래된 것부터
+    events: VecDeque<WorldEvent>,
+    /// 현재 있는 월드의 ID와 이름
+    current: Option<(String, Option<String>)>,
+}
+
+impl VrcWorldHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> &VecDeque<WorldEvent> {
+        &self.events
+    }
+
+    fn push(&mut self, event: WorldEvent) {
+        if self.events.len() == MAX_WORLD_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /// 월드 입장/퇴장 줄이면 기록하고 true 반환
+    pub fn observe_line(&mut self, line: &str) -> bool {
+        if let Some((_, rest)) = line.split_once(WORLD_JOINING_MARKER) {
+            // "wrld_...:12345~private(...)" 형식에서 인스턴스 부분 제외
+            let id = rest.split([':', ' ']).next().unwrap_or_default();
+            let world_id = format!("wrld_{}", id);
+            self.current = Some((world_id.clone(), None));
+            self.push(WorldEvent {
+                timestamp: SystemTime::now(),
+                world_id,
+                world_name: None,
+                event_type: WorldEventType::Join,
+            });
+            return true;
+        }
+        if let Some((_, name)) = line.split_once(WORLD_ENTERING_MARKER) {
+            let name = name.trim().to_string();
+            if let Some((_, ref mut current_name)) = self.current {
+                *current_name = Some(name.clone());
+            }
+            // 직전 입장 기록에 이름 붙임
+            if let Some(event) = self.events.back_mut()
+                && event.event_type == WorldEventType::Join
+                && event.world_name.is_none()
+            {
+                event.world_name = Some(name);
+            }
+            return true;
+        }
+        if line.contains(WORLD_LEFT_MARKER) {
+            let Some((world_id, world_name)) = self.current.take() else {
+                return false;
+            };
+            self.push(WorldEvent {
+                timestamp: SystemTime::now(),
+                world_id,
+                world_name,
+                event_type: WorldEventType::Leave,
+            });
+            return true;
+        }
+        false
+    }
+}
+
+/// 로그에서 로컬 플레이어의 아바타 변경을 추적
+///
+/// "User Authenticated" 줄로 로컬 플레이어 이름을 알아낸 뒤
+/// 그 플레이어의 "Switching ... to avatar ..." 줄만 현재 아바타로 취급
+#[derive(Default)]
+pub struct VrcAvatarWatcher {
+    local_player: Option<String>,
+    current: Option<AvatarInfo>,
+    /// 로컬 플레이어가 아바타를 바꾼 직후 (다음 ID/성능 줄을 이 아바타에 붙임)
+    awaiting_details: bool,
+}
+
+impl VrcAvatarWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn current(&self) -> Option<&AvatarInfo> {
+        self.current.as_ref()
+    }
+
+    /// OSC `/avatar/change`로 받은 아바타 ID 반영
+    pub fn set_avatar_id(&mut self, id: impl Into<String>) {
+        let id = id.into();
+        match self.current {
+            Some(ref mut avatar) if avatar.id.is_empty() || avatar.id == id => avatar.id = id,
+            // 로그보다 OSC가 먼저 온 경우 이름은 ID로 대신 표시
+            _ => {
+                self.current = Some(AvatarInfo {
+                    name: id.clone(),
+                    id,
+                    ..Default::default()
+                })
+            }
+        }
+    }
+
+    /// 아바타 관련 줄이면 상태를 갱신하고 true 반환
+    pub fn observe_line(&mut self, line: &str) -> bool {
+        if let Some((_, rest)) = line.split_once(AUTHENTICATED_MARKER) {
+            // "Name (usr_...)" 형식
+            let name = rest.rsplit_once(" (").map_or(rest, |(name, _)| name);
+            self.local_player = Some(name.trim().to_string());
+            return true;
+        }
+        if let Some((_, rest)) = line.split_once(AVATAR_SWITCH_MARKER) {
+            let Some((player, avatar)) = rest.split_once(" to avatar ") else {
+                return false;
+            };
+            if self.local_player.as_deref() != Some(player.trim()) {
+                return false;
+            }
+            self.current = Some(AvatarInfo {
+                name: avatar.trim().to_string(),
+                ..Default::default()
+            });
+            self.awaiting_details = true;
+            return true;
+        }
+        if !self.awaiting_details {
+            return false;
+        }
+        let Some(avatar) = self.current.as_mut() else {
+            return false;
+        };
+        if let Some((_, rest)) = line.split_once(AVATAR_DATA_MARKER) {
+            if let Some(id) = rest.split_whitespace().find(|word| word.starts_with("avtr_")) {
+                avatar.id = id.to_string();
+                return true;
+            }
+            return false;
+        }
+        if let Some((_, rest)) = line.split_once(AVATAR_STATS_MARKER) {
+            // "... Polygons: 70000, Rating: Poor" 형식의 키-값 목록
+            for field in rest.split(',') {
+                let Some((key, value)) = field.split_once(':') else {
+                    continue;
+                };
+                match key.trim().to_lowercase().as_str() {
+                    "polygons" | "polys" => avatar.poly_count = value.trim().parse().ok(),
+                    "rating" | "rank" => avatar.perf_rank = PerfRank::parse(value.trim()),
+                    _ => {}
+                }
+            }
+            self.awaiting_details = false;
+            return true;
+        }
+        false
+    }
+}
+
+/// 플랫폼별 VRChat 로그 디렉터리
+pub fn default_log_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    {
+        std::env::var_os("USERPROFILE")
+            .map(|home| PathBuf::from(home).join(r"AppData\LocalLow\VRChat\VRChat"))
+    }
+    #[cfg(not(windows))]
+    {
+        // Linux: Steam Proton 프리픽스 내부
+        std::env::var_os("HOME").map(|home| {
+            PathBuf::from(home).join(
+                ".local/share/Steam/steamapps/compatdata/438100/pfx/drive_c/users/steamuser/AppData/LocalLow/VRChat/VRChat",
+            )
+        })
+    }
+}
+
+/// VRChat 로그를 따라 읽으며 프로세스 감시 결과와 합쳐 세션 이벤트 생성
+pub struct VrchatLogWatcher {
+    log_dir: Option<PathBuf>,
+    current_file: Option<PathBuf>,
+    offset: u64,
+    /// 아직 줄바꿈이 오지 않은 마지막 줄 조각
+    pending: String,
+    process: VrchatProcessMonitor,
+    /// 로그에서 정상 종료 줄을 본 경우 그 종료 코드
+    clean_exit: Option<Option<i32>>,
+    last_poll: Option<Instant>,
+    /// 최근 로그 이벤트 (오래된 것부터)
+    log_events: VecDeque<VrcLogEvent>,
+    /// 마지막 drain 이후 새로 파싱한 이벤트
+    new_log_events: Vec<VrcLogEvent>,
+    fps: VrcFpsTracker,
+    avatar: VrcAvatarWatcher,
+    worlds: VrcWorldHistory,
+}
+
+impl VrchatLogWatcher {
+    pub fn new() -> Self {
+        Self::with_log_dir(default_log_dir())
+    }
+
+    pub fn with_log_dir(log_dir: Option<PathBuf>) -> Self {
+        Self {
+            log_dir,
+            current_file: None,
+            offset: 0,
+            pending: String::new(),
+            process: VrchatProcessMonitor::new(),
+            clean_exit: None,
+            last_poll: None,
+            log_events: VecDeque::with_capacity(MAX_LOG_EVENTS),
+            new_log_events: Vec::new(),
+            fps: VrcFpsTracker::new(),
+            avatar: VrcAvatarWatcher::new(),
+            worlds: VrcWorldHistory::new(),
+        }
+    }
+
+    pub fn process(&self) -> &VrchatProcessMonitor {
+        &self.process
+    }
+
+    pub fn log_events(&self) -> &VecDeque<VrcLogEvent> {
+        &self.log_events
+    }
+
+    /// 마지막 호출 이후 새로 파싱한 로그 이벤트
+    pub fn drain_new_log_events(&mut self) -> Vec<VrcLogEvent> {
+        std::mem::take(&mut self.new_log_events)
+    }
+
+    pub fn fps_mut(&mut self) -> &mut VrcFpsTracker {
+        &mut self.fps
+    }
+
+    pub fn avatar(&self) -> &VrcAvatarWatcher {
+        &self.avatar
+    }
+
+    pub fn avatar_mut(&mut self) -> &mut VrcAvatarWatcher {
+        &mut self.avatar
+    }
+
+    pub fn worlds(&self) -> &VrcWorldHistory {
+        &self.worlds
+    }
+
+    /// 로그 한 줄에서 종료 코드, FPS, 아바타, 월드 기록, 표시용 이벤트 추출
+    fn consume_line(&mut self, line: &str) {
+        if let Some(code) = Self::parse_exit_code(line) {
+            self.clean_exit = Some(code);
+        }
+        if self.fps.observe_line(line).is_some() {
+            return;
+        }
+        // 아바타 변경 줄은 이벤트 목록에도 남김
+        self.avatar.observe_line(line);
+        self.worlds.observe_line(line);
+        if let Some(event) = VrcLogEvent::parse(line) {
+            if self.log_events.len() == MAX_LOG_EVENTS {
+                self.log_events.pop_front();
+            }
+            self.new_log_events.push(event.clone());
+            self.log_events.push_back(event);
+        }
+    }
+
+    /// 새 로그 줄과 프로세스 상태를 확인하여 이벤트 반환
+    pub fn poll(&mut self) -> Vec<VrchatEvent> {
+        // 파일 시스템/프로세스 목록 접근은 1초 간격으로만
+        if self.last_poll.is_some_and(|t| t.elapsed() < POLL_INTERVAL) {
+            return Vec::new();
+        }
+        self.last_poll = Some(Instant::now());
+
+        for line in self.read_new_lines() {
+            self.consume_line(&line);
+        }
+
+        let mut events = Vec::new();
+        match self.process.poll() {
+            Some(ProcessTransition::Started) => {
+                self.clean_exit = None;
+                events.push(VrchatEvent::Started);
+            }
+            Some(ProcessTransition::Exited) => {
+                // 프로세스 종료 직후에 기록된 로그 줄까지 확인
+                for line in self.read_new_lines() {
+                    self.consume_line(&line);
+                }
+                events.push(match self.clean_exit.take() {
+                    Some(exit_code) => VrchatEvent::Exited { exit_code },
+                    None => VrchatEvent::Crash { exit_code: None },
+                });
+            }
+            None => {}
+        }
+        events
+    }
+
+    /// "[Always] VRChat exited with code X" 줄에서 종료 코드 추출
+    fn parse_exit_code(line: &str) -> Option<Option<i32>> {
+        let (_, rest) = line.split_once(EXIT_LINE_MARKER)?;
+        Some(rest.trim().parse().ok())
+    }
+
+    /// 가장 최근에 수정된 output_log_*.txt
+    fn latest_log_file(dir: &Path) -> Option<PathBuf> {
+        fs::read_dir(dir)
+            .ok()?
+            .flatten()
+            .filter(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.starts_with("output_log_") && name.ends_with(".txt")
+            })
+            .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok())
+            .map(|entry| entry.path())
+    }
+
+    /// 마지막으로 읽은 위치 이후에 추가된 완전한 줄들
+    fn read_new_lines(&mut self) -> Vec<String> {
+        let Some(latest) = self.log_dir.as_deref().and_then(Self::latest_log_file) else {
+            return Vec::new();
+        };
+
+        if self.current_file.as_ref() != Some(&latest) {
+            // 처음 발견한 파일은 끝에서부터, 새로 생긴 파일은 처음부터 읽음
+            self.offset = if self.current_file.is_none() {
+                fs::metadata(&latest).map(|m| m.len()).unwrap_or(0)
+            } else {
+                0
+            };
+            self.pending.clear();
+            self.current_file = Some(latest.clone());
+        }
+
+        let Ok(mut file) = File::open(&latest) else {
+            return Vec::new();
+        };
+        let mut buf = Vec::new();
+        if file.seek(SeekFrom::Start(self.offset)).is_err() || file.read_to_end(&mut buf).is_err()
+        {
+            return Vec::new();
+        }
+        self.offset += buf.len() as u64;
+        self.pending.push_str(&String::from_utf8_lossy(&buf));
+
+        let mut lines: Vec<String> = self.pending.split('\n').map(str::to_string).collect();
+        self.pending = lines.pop().unwrap_or_default();
+        lines
+            .into_iter()
+            .map(|line| line.trim_end_matches('\r').to_string())
+            .collect()
+    }
+}
+
+impl Default for VrchatLogWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn world_history_names_joins_and_records_leaves() {
+        let mut worlds = VrcWorldHistory::new();
+        worlds.observe_line("2024.01.01 12:00:00 Log        -  [Behaviour] Joining wrld_abc:1234~private(usr_x)");
+        worlds.observe_line("2024.01.01 12:00:01 Log        -  [Behaviour] Entering Room: The Black Cat");
+        worlds.observe_line("2024.01.01 12:30:00 Log        -  [Behaviour] OnLeftRoom");
+
+        let events: Vec<_> = worlds.events().iter().collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type, WorldEventType::Join);
+        assert_eq!(events[0].world_id, "wrld_abc");
+        assert_eq!(events[0].world_name.as_deref(), Some("The Black Cat"));
+        assert_eq!(events[1].event_type, WorldEventType::Leave);
+        assert_eq!(events[1].world_name.as_deref(), Some("The Black Cat"));
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    collections::HashMap,
+    io,
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use rosc::{OscMessage, OscPacket, OscType};
+
+/// OSC 메시지 인자 값
+#[derive(Clone, Debug, PartialEq)]
+pub enum OscValue {
+    Float(f32),
+    Bool(bool),
+    Int(i32),
+    String(String),
+}
+
+impl OscValue {
+    /// 타입 이름 (표시용)
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            OscValue::Float(_) => "Float",
+            OscValue::Bool(_) => "Bool",
+            OscValue::Int(_) => "Int",
+            OscValue::String(_) => "String",
+        }
+    }
+}
+
+impl std::fmt::Display for OscValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OscValue::Float(v) => write!(f, "{:.3}", v),
+            OscValue::Bool(v) => write!(f, "{}", v),
+            OscValue::Int(v) => write!(f, "{}", v),
+            OscValue::String(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// 수신된 OSC 메시지 한 건
+#[derive(Clone, Debug)]
+pub struct OscEvent {
+    pub address: String,
+    pub value: OscValue,
+    pub received_at: Instant,
+}
+
+impl OscEvent {
+    pub fn new(address: impl Into<String>, value: OscValue) -> Self {
+        Self {
+            address: address.into(),
+            value,
+            received_at: Instant::now(),
+        }
+    }
+}
+
+/// VRChat이 OSC를 보내는 기본 포트
+pub const DEFAULT_OSC_RECEIVE_PORT: u16 = 9001;
+/// VRChat이 OSC를 받는 기본 포트
+pub const DEFAULT_OSC_SEND_PORT: u16 = 9000;
+/// 아바타 파라미터 주소 접두사
+pub const AVATAR_PARAMETER_PREFIX: &str = "/avatar/parameters/";
+/// 뷰가 가져가기 전까지 보관할 최대 이벤트 수
+const MAX_PENDING_EVENTS: usize = 500;
+
+/// 수신 스레드와 뷰가 공유하는 OSC 상태
+#[derive(Default)]
+pub struct OscState {
+    /// 아바타 파라미터 이름 → 최신 값
+    pub parameters: HashMap<String, OscValue>,
+    /// 아직 뷰가 가져가지 않은 수신 이벤트
+    pending_events: Vec<OscEvent>,
+    /// 소켓 바인드 실패 등 수신 불가 사유
+    pub error: Option<String>,
+    /// 수신 중인 포트
+    pub port: Option<u16>,
+}
+
+impl OscState {
+    /// 쌓인 이벤트를 모두 꺼냄
+    pub fn drain_events(&mut self) -> Vec<OscEvent> {
+        std::mem::take(&mut self.pending_events)
+    }
+
+    fn record(&mut self, event: OscEvent) {
+        if let Some(name) = event.address.strip_prefix(AVATAR_PARAMETER_PREFIX) {
+            self.parameters.insert(name.to_string(), event.value.clone());
+        }
+        if self.pending_events.len() >= MAX_PENDING_EVENTS {
+            self.pending_events.remove(0);
+        }
+        self.pending_events.push(event);
+    }
+}
+
+pub type SharedOscState = Arc<Mutex<OscState>>;
+
+/// 백그라운드 UDP 스레드에서 VRChat OSC 메시지를 수신
+pub struct OscReceiver {
+    state: SharedOscState,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl OscReceiver {
+    /// 지정 포트(127.0.0.1)에서 수신 시작 - 바인드 실패는 `OscState::error`에 기록
+    pub fn start(port: u16) -> Self {
+        let state = SharedOscState::default();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let socket = UdpSocket::bind(("127.0.0.1", port))
+            .and_then(|socket| {
+                // 종료 플래그를 확인할 수 있도록 읽기 타임아웃 설정
+                socket.set_read_timeout(Some(Duration::from_millis(200)))?;
+                Ok(socket)
+            });
+
+        let handle = match socket {
+            Ok(socket) => {
+                if let Ok(mut s) = state.lock() {
+                    s.port = Some(port);
+                }
+                let state = state.clone();
+                let stop = stop.clone();
+                Some(thread::spawn(move || Self::receive_loop(socket, state, stop)))
+            }
+            Err(e) => {
+                if let Ok(mut s) = state.lock() {
+                    s.error = Some(format!("OSC port {} unavailable: {}", port, e));
+                }
+                None
+            }
+        };
+
+        Self {
+            state,
+            stop,
+            handle,
+        }
+    }
+
+    pub fn state(&self) -> SharedOscState {
+        self.state.clone()
+    }
+
+    fn receive_loop(socket: UdpSocket, state: SharedOscState, stop: Arc<AtomicBool>) {
+        let mut buf = [0u8; rosc::decoder::MTU];
+        while !stop.load(Ordering::Relaxed) {
+            let Ok(size) = socket.recv(&mut buf) else {
+                continue;
+            };
+            let Ok((_, packet)) = rosc::decoder::decode_udp(&buf[..size]) else {
+                continue;
+            };
+
+            let mut events = Vec::new();
+            Self::collect_events(packet, &mut events);
+            if let Ok(mut state) = state.lock() {
+                for event in events {
+                    state.record(event);
+                }
+            }
+        }
+    }
+
+    /// 번들을 펼쳐 메시지별 이벤트로 변환 (첫 번째 인자만 사용)
+    fn collect_events(packet: OscPacket, events: &mut Vec<OscEvent>) {
+        match packet {
+            OscPacket::Message(message) => {
+                let value = match message.args.into_iter().next() {
+                    Some(OscType::Float(v)) => OscValue::Float(v),
+                    Some(OscType::Bool(v)) => OscValue::Bool(v),
+                    Some(OscType::Int(v)) => OscValue::Int(v),
+                    Some(OscType::String(v)) => OscValue::String(v),
+                    _ => return,
+                };
+                events.push(OscEvent::new(message.addr, value));
+            }
+            OscPacket::Bundle(bundle) => {
+                for packet in bundle.content {
+                    Self::collect_events(packet, events);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for OscReceiver {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// VRChat으로 OSC 메시지를 보내는 UDP 송신기
+pub struct VrchatOscSender {
+    socket: UdpSocket,
+    target: SocketAddr,
+}
+
+impl VrchatOscSender {
+    /// 임의 포트에 바인드하고 대상 주소 확인 (이름이면 첫 번째 주소 사용)
+    pub fn new(host: &str, port: u16) -> io::Result<Self> {
+        let target = (host, port).to_socket_addrs()?.next().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, format!("cannot resolve {}", host))
+        })?;
+        Ok(Self {
+            socket: UdpSocket::bind("0.0.0.0:0")?,
+            target,
+        })
+    }
+
+    pub fn send_bool(&self, address: &str, value: bool) -> io::Result<()> {
+        self.send(address, OscType::Bool(value))
+    }
+
+    pub fn send_float(&self, address: &str, value: f32) -> io::Result<()> {
+        self.send(address, OscType::Float(value))
+    }
+
+    pub fn send_int(&self, address: &str, value: i32) -> io::Result<()> {
+        self.send(address, OscType::Int(value))
+    }
+
+    fn send(&self, address: &str, arg: OscType) -> io::Result<()> {
+        let packet = OscPacket::Message(OscMessage {
+            addr: address.to_string(),
+            args: vec![arg],
+        });
+        let buf = rosc::encoder::encode(&packet).map_err(io::Error::other)?;
+        self.socket.send_to(&buf, self.target)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, OscBindingValue};
+
+    #[test]
+    fn osc_bindings_parse_and_sender_reaches_local_socket() {
+        let config: Config = toml::from_str(
+            r#"
+            [[osc_bindings]]
+            key = "m"
+            address = "/avatar/parameters/MenuOpen"
+            value = true
+            toggle = true
+
+            [[osc_bindings]]
+            key = "v"
+            address = "/avatar/parameters/Volume"
+            value = 0.5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.osc_bindings[0].value, OscBindingValue::Bool(true));
+        assert!(config.osc_bindings[0].toggle);
+        assert_eq!(config.osc_bindings[1].value, OscBindingValue::Float(0.5));
+
+        let receiver = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+        let port = receiver.local_addr().unwrap().port();
+        let sender = VrchatOscSender::new("127.0.0.1", port).unwrap();
+        sender.send_float("/avatar/parameters/Volume", 0.5).unwrap();
+
+        let mut buf = [0u8; 256];
+        let len = receiver.recv(&mut buf).unwrap();
+        let (_, packet) = rosc::decoder::decode_udp(&buf[..len]).unwrap();
+        let rosc::OscPacket::Message(message) = packet else {
+            panic!("expected a message");
+        };
+        assert_eq!(message.addr, "/avatar/parameters/Volume");
+        assert_eq!(message.args, vec![rosc::OscType::Float(0.5)]);
+    }
+}
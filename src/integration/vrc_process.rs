@@ -0,0 +1,62 @@
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+/// VRChat 실행 파일 이름 (Windows / Proton 모두 VRChat.exe, 확장자 없는 경우 포함)
+const VRCHAT_EXECUTABLE_NAMES: [&str; 2] = ["VRChat.exe", "VRChat"];
+
+/// 프로세스 이름이 VRChat 실행 파일과 정확히 일치하는지 (대소문자 무시)
+fn is_vrchat_executable(name: &str) -> bool {
+    VRCHAT_EXECUTABLE_NAMES
+        .iter()
+        .any(|executable| name.eq_ignore_ascii_case(executable))
+}
+
+/// VRChat 프로세스 상태 변화
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessTransition {
+    Started,
+    Exited,
+}
+
+/// VRChat 프로세스 실행 여부 감시
+pub struct VrchatProcessMonitor {
+    system: System,
+    pid: Option<Pid>,
+}
+
+impl VrchatProcessMonitor {
+    pub fn new() -> Self {
+        Self {
+            system: System::new(),
+            pid: None,
+        }
+    }
+
+    pub fn pid(&self) -> Option<u32> {
+        self.pid.map(|pid| pid.as_u32())
+    }
+
+    /// 프로세스 목록을 다시 읽고 상태 변화가 있으면 반환
+    pub fn poll(&mut self) -> Option<ProcessTransition> {
+        self.system.refresh_processes(ProcessesToUpdate::All, true);
+        let found = self
+            .system
+            .processes()
+            .iter()
+            .find(|(_, process)| is_vrchat_executable(&process.name().to_string_lossy()))
+            .map(|(pid, _)| *pid);
+
+        let transition = match (self.pid, found) {
+            (None, Some(_)) => Some(ProcessTransition::Started),
+            (Some(_), None) => Some(ProcessTransition::Exited),
+            _ => None,
+        };
+        self.pid = found;
+        transition
+    }
+}
+
+impl Default for VrchatProcessMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,45 @@
+use std::mem;
+
+use winapi::um::psapi::{GetPerformanceInfo, PERFORMANCE_INFORMATION};
+use winapi::um::sysinfoapi::{GlobalMemoryStatusEx, MEMORYSTATUSEX};
+
+/// Windows 커밋 사용량 (단위는 바이트)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WindowsMemoryInfo {
+    /// 현재 커밋된 양 (물리 메모리 + 페이지 파일 사용분)
+    pub commit_total: u64,
+    /// 커밋 한도 (물리 메모리 + 페이지 파일 크기)
+    pub commit_limit: u64,
+    /// 부팅 이후 최대 커밋량
+    pub commit_peak: u64,
+}
+
+impl WindowsMemoryInfo {
+    pub fn read() -> Option<Self> {
+        // sysinfo는 페이지 파일 정보를 주지 않으므로 직접 조회
+        let mut status: MEMORYSTATUSEX = unsafe { mem::zeroed() };
+        status.dwLength = mem::size_of::<MEMORYSTATUSEX>() as u32;
+        if unsafe { GlobalMemoryStatusEx(&mut status) } == 0 {
+            return None;
+        }
+        let commit_limit = status.ullTotalPageFile;
+        let commit_total = status.ullTotalPageFile.saturating_sub(status.ullAvailPageFile);
+
+        Some(Self {
+            commit_total,
+            commit_limit,
+            commit_peak: Self::read_commit_peak().unwrap_or(commit_total),
+        })
+    }
+
+    /// 최대 커밋량은 GlobalMemoryStatusEx에 없어서 GetPerformanceInfo로 조회
+    fn read_commit_peak() -> Option<u64> {
+        let mut info: PERFORMANCE_INFORMATION = unsafe { mem::zeroed() };
+        let size = mem::size_of::<PERFORMANCE_INFORMATION>() as u32;
+        info.cb = size;
+        if unsafe { GetPerformanceInfo(&mut info, size) } == 0 {
+            return None;
+        }
+        Some(info.CommitPeak as u64 * info.PageSize as u64)
+    }
+}
@@ -1,16 +1,52 @@
 mod ui;
+mod cli;
 mod contracts;
 mod integration;
 mod config;
 mod controllers;
 mod queues;
+mod util;
+
+use clap::Parser;
 
 fn main() {
+    let args = cli::Args::parse();
+    let ctx = queues::app_context::AppContext::new(config::Config::load_or_default_with_args(&args));
+    let mut app = ui::viewer::App::new(ctx);
+
+    // 시작 뷰를 지정하지 않았으면 지난 실행의 마지막 뷰 복원
+    if app.config().initial_view.is_empty()
+        && let Some(key) = controllers::snapshot::default_snapshot_path()
+            .and_then(|path| controllers::snapshot::load_snapshot(&path))
+    {
+        app.switch_to_view_by_name(&key);
+    }
+
+    // 알 수 없는 뷰 이름이면 사용 가능한 이름을 보여주고 종료
+    if let Some(ref view) = args.view
+        && !app.switch_to_view_by_name(view)
+    {
+        eprintln!("알 수 없는 뷰: {}", view);
+        eprintln!("사용 가능한 뷰: {}", app.view_names().join(", "));
+        std::process::exit(1);
+    }
+
+    // 녹화/재생 파일을 열 수 없으면 UI를 띄우기 전에 종료
+    if let Some(ref path) = args.record
+        && let Err(e) = app.start_recording(path)
+    {
+        eprintln!("녹화 파일을 만들 수 없음: {} ({})", e, path.display());
+        std::process::exit(1);
+    }
+    if let Some(ref path) = args.replay
+        && let Err(e) = app.start_replay(path)
+    {
+        eprintln!("녹화 파일을 읽을 수 없음: {} ({})", e, path.display());
+        std::process::exit(1);
+    }
+
     // 터미널 UI 실행
-    let order_channel = queues::view_command::get_viewer_channels();
-    if let Err(e) = ui::viewer::show_ui(
-        order_channel.tx_command.clone(),
-    ) {
+    if let Err(e) = ui::viewer::show_ui(app) {
         eprintln!("UI 오류: {}", e);
     }
 }
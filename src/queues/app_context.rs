@@ -0,0 +1,37 @@
+use crossbeam_channel::Sender;
+
+use crate::config::Config;
+use crate::queues::view_command::ViewerChannels;
+use crate::ui::viewer::ViewerCommand;
+
+/// 앱 실행에 필요한 공유 자원 (main에서 만들어 App에 주입)
+#[derive(Clone)]
+pub struct AppContext {
+    pub config: Config,
+    pub channels: ViewerChannels,
+}
+
+impl AppContext {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            channels: ViewerChannels::new(),
+        }
+    }
+
+    /// 뷰나 백그라운드 작업에 넘겨줄 명령 송신자
+    pub fn command_sender(&self) -> Sender<ViewerCommand> {
+        self.channels.tx_command.clone()
+    }
+
+    /// 앱에 명령 전송 (앱이 이미 종료되었으면 무시)
+    pub fn send(&self, command: ViewerCommand) {
+        let _ = self.channels.tx_command.send(command);
+    }
+}
+
+impl Default for AppContext {
+    fn default() -> Self {
+        Self::new(Config::default())
+    }
+}
@@ -1 +1,2 @@
-pub mod view_command;
\ No newline at end of file
+pub mod app_context;
+pub mod view_command;
@@ -1,27 +1,31 @@
-use std::sync::{mpsc, Arc, Mutex, OnceLock};
+use crossbeam_channel::{Receiver, Sender};
 
-use crate::ui::viewer::{ViewerCommand, ViewerMessage};
+use crate::ui::viewer::ViewerCommand;
 
-/// 채널 쌍을 담을 구조체
+/// 채널 쌍을 담을 구조체 (수신자도 복제 가능하므로 잠금 없이 공유)
+#[derive(Clone)]
 pub struct ViewerChannels {
-    pub tx_command: mpsc::Sender<ViewerCommand>,
-    pub rx_command: Arc<Mutex<mpsc::Receiver<ViewerCommand>>>,
-    pub tx_message: mpsc::Sender<ViewerMessage>,
-    pub rx_message: Arc<Mutex<mpsc::Receiver<ViewerMessage>>>,
+    pub tx_command: Sender<ViewerCommand>,
+    pub rx_command: Receiver<ViewerCommand>,
 }
 
-static VIEWER_CHANNELS: OnceLock<ViewerChannels> = OnceLock::new();
-
-/// 싱글톤 채널 접근 함수
-pub fn get_viewer_channels() -> &'static ViewerChannels {
-    VIEWER_CHANNELS.get_or_init(|| {
-        let (tx_command, rx_command) = mpsc::channel();
-        let (tx_message, rx_message) = mpsc::channel();
-        ViewerChannels {
+impl ViewerChannels {
+    pub fn new() -> Self {
+        let (tx_command, rx_command) = crossbeam_channel::unbounded();
+        Self {
             tx_command,
-            rx_command: Arc::new(Mutex::new(rx_command)),
-            tx_message,
-            rx_message: Arc::new(Mutex::new(rx_message)),
+            rx_command,
         }
-    })
-}
\ No newline at end of file
+    }
+
+    /// 대기 중인 명령을 모두 꺼냄
+    pub fn drain_commands(&self) -> Vec<ViewerCommand> {
+        self.rx_command.try_iter().collect()
+    }
+}
+
+impl Default for ViewerChannels {
+    fn default() -> Self {
+        Self::new()
+    }
+}
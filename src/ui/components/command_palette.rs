@@ -0,0 +1,220 @@
+use std::cmp::Reverse;
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use super::FilterBar;
+use crate::ui::viewer::App;
+
+/// 모달 최대 크기
+const PALETTE_WIDTH: u16 = 50;
+const PALETTE_MAX_HEIGHT: u16 = 20;
+/// 일치 종류별 기본 점수 (접두사 > 부분 문자열 > 글자 순서 일치)
+const PREFIX_SCORE: u32 = 3000;
+const SUBSTRING_SCORE: u32 = 2000;
+const SUBSEQUENCE_SCORE: u32 = 1000;
+/// 같은 종류 안에서 위치/간격에 따라 깎는 최대 점수
+const MAX_PENALTY: u32 = 999;
+
+/// 팔레트에서 실행할 명령
+pub type CommandAction = Box<dyn Fn(&mut App)>;
+
+/// 이름으로 찾아 실행할 수 있는 명령 목록
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: Vec<(String, CommandAction)>,
+}
+
+impl CommandRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, name: impl Into<String>, action: impl Fn(&mut App) + 'static) {
+        self.commands.push((name.into(), Box::new(action)));
+    }
+
+    pub fn name(&self, index: usize) -> Option<&str> {
+        self.commands.get(index).map(|(name, _)| name.as_str())
+    }
+
+    /// 등록 순서대로 이름 목록
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.commands.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// `index`번째 명령 실행 (없으면 false)
+    pub fn execute(&self, index: usize, app: &mut App) -> bool {
+        let Some((_, action)) = self.commands.get(index) else {
+            return false;
+        };
+        action(app);
+        true
+    }
+}
+
+/// 검색어와 명령 이름의 일치 점수 (대소문자 무시, 일치하지 않으면 None)
+///
+/// 접두사 일치가 가장 높고, 부분 문자열은 앞쪽일수록, 글자 순서 일치는 간격이 좁을수록 높음
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    let query = query.to_lowercase();
+    let candidate = candidate.to_lowercase();
+    if query.is_empty() || candidate.starts_with(&query) {
+        return Some(PREFIX_SCORE);
+    }
+    if let Some(position) = candidate.find(&query) {
+        return Some(SUBSTRING_SCORE - (position as u32).min(MAX_PENALTY));
+    }
+
+    // 검색어 글자가 순서대로 나오는지 확인하며 건너뛴 글자 수 합산
+    let mut chars = candidate.chars();
+    let mut gaps = 0u32;
+    let mut started = false;
+    for wanted in query.chars() {
+        loop {
+            let c = chars.next()?;
+            if c == wanted {
+                started = true;
+                break;
+            }
+            if started {
+                gaps += 1;
+            }
+        }
+    }
+    Some(SUBSEQUENCE_SCORE - gaps.min(MAX_PENALTY))
+}
+
+/// `:`로 여는 명령 검색/실행 모달
+pub struct CommandPalette {
+    input: FilterBar,
+    /// 현재 검색어와 일치하는 명령 인덱스 (점수 높은 순)
+    matches: Vec<usize>,
+    selected: usize,
+    open: bool,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self {
+            input: FilterBar::new().with_label("Command"),
+            matches: Vec::new(),
+            selected: 0,
+            open: false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// 검색어를 비우고 모든 명령을 표시하며 열기
+    pub fn open(&mut self, registry: &CommandRegistry) {
+        self.input.clear();
+        self.input.focus();
+        self.open = true;
+        self.update_matches(registry);
+    }
+
+    fn update_matches(&mut self, registry: &CommandRegistry) {
+        let mut scored: Vec<(u32, usize)> = registry
+            .names()
+            .enumerate()
+            .filter_map(|(index, name)| Some((fuzzy_score(self.input.text(), name)?, index)))
+            .collect();
+        // 점수가 같으면 등록 순서 유지
+        scored.sort_by_key(|&(score, _)| Reverse(score));
+        self.matches = scored.into_iter().map(|(_, index)| index).collect();
+        self.selected = 0;
+    }
+
+    /// 키 입력 처리 - Enter로 고르면 명령 인덱스 반환, Esc는 취소
+    pub fn handle_key(&mut self, key: KeyCode, registry: &CommandRegistry) -> Option<usize> {
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.matches.len().saturating_sub(1));
+            }
+            KeyCode::Enter => {
+                self.open = false;
+                return self.matches.get(self.selected).copied();
+            }
+            KeyCode::Esc => self.open = false,
+            key => {
+                self.input.handle_key(key);
+                self.update_matches(registry);
+            }
+        }
+        None
+    }
+
+    /// 화면 위쪽 가운데에 배경을 지우고 렌더링
+    pub fn render(&self, frame: &mut Frame, area: Rect, registry: &CommandRegistry) {
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .filter_map(|&index| registry.name(index))
+            .map(|name| ListItem::new(Line::from(name.to_string())))
+            .collect();
+
+        // 테두리 2줄 + 입력 1줄 포함
+        let width = PALETTE_WIDTH.min(area.width);
+        let height = (self.matches.len() as u16 + 3)
+            .min(PALETTE_MAX_HEIGHT)
+            .min(area.height);
+        let palette_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + area.height / 6,
+            width,
+            height.min(area.height - area.height / 6),
+        );
+
+        let block = Block::default()
+            .title(" Commands ")
+            .title_bottom(" Enter: run  Esc: cancel ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(palette_area);
+        frame.render_widget(Clear, palette_area);
+        frame.render_widget(block, palette_area);
+
+        let [input_area, list_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
+        self.input.render(frame, input_area);
+
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_score_prefers_prefix_then_substring_then_subsequence() {
+        let prefix = fuzzy_score("tog", "toggle pause").unwrap();
+        let substring = fuzzy_score("pause", "toggle pause").unwrap();
+        let subsequence = fuzzy_score("tgp", "toggle pause").unwrap();
+        assert!(prefix > substring && substring > subsequence);
+        assert_eq!(fuzzy_score("xyz", "toggle pause"), None);
+    }
+}
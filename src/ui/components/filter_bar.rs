@@ -0,0 +1,137 @@
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// 커서 깜빡임 주기 (tick 수)
+const BLINK_TICKS: u64 = 10;
+
+/// 한 줄짜리 텍스트 필터 입력창 (포커스 중에만 키 입력을 받음)
+pub struct FilterBar {
+    buffer: String,
+    /// 문자 단위 커서 위치
+    cursor: usize,
+    focused: bool,
+    ticks: u64,
+    /// 입력창 앞에 붙는 이름 (기본 "Filter")
+    label: &'static str,
+}
+
+impl FilterBar {
+    pub fn new() -> Self {
+        Self {
+            buffer: String::new(),
+            cursor: 0,
+            focused: false,
+            ticks: 0,
+            label: "Filter",
+        }
+    }
+
+    /// 필터가 아닌 입력(경로 등)에 쓸 때 이름 변경
+    pub fn with_label(mut self, label: &'static str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// 입력 내용을 바꾸고 커서를 끝으로 이동
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.buffer = text.into();
+        self.cursor = self.buffer.chars().count();
+    }
+
+    pub fn text(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn focus(&mut self) {
+        self.focused = true;
+        self.ticks = 0;
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.cursor = 0;
+    }
+
+    /// 커서 깜빡임용 tick
+    pub fn tick(&mut self) {
+        self.ticks = self.ticks.wrapping_add(1);
+    }
+
+    /// 필터 문자열이 `text`를 포함하는지 (대소문자 무시)
+    pub fn matches(&self, text: &str) -> bool {
+        self.buffer.is_empty() || text.to_lowercase().contains(&self.buffer.to_lowercase())
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.buffer
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.buffer.len(), |(i, _)| i)
+    }
+
+    /// 포커스 중인 키 입력 처리 - Enter는 적용, Esc는 비우고 포커스 해제
+    pub fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char(c) => {
+                let index = self.byte_index(self.cursor);
+                self.buffer.insert(index, c);
+                self.cursor += 1;
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let index = self.byte_index(self.cursor);
+                self.buffer.remove(index);
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.buffer.chars().count()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.buffer.chars().count(),
+            KeyCode::Enter => self.focused = false,
+            KeyCode::Esc => {
+                self.clear();
+                self.focused = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// 한 줄 영역에 렌더링 (포커스 중이면 커서 표시)
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let line = if self.focused {
+            let index = self.byte_index(self.cursor);
+            // 홀수 주기에는 커서 자리를 공백으로 두어 깜빡이게 함
+            let cursor = if (self.ticks / BLINK_TICKS).is_multiple_of(2) { "|" } else { " " };
+            Line::from(vec![
+                Span::raw(format!("{}: ", self.label)),
+                Span::raw(&self.buffer[..index]),
+                Span::raw(cursor),
+                Span::raw(&self.buffer[index..]),
+            ])
+            .style(Style::default().fg(Color::Yellow))
+        } else {
+            Line::from(format!("{}: {} (Esc: clear)", self.label, self.buffer))
+                .style(Style::default().fg(Color::Gray))
+        };
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
+impl Default for FilterBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
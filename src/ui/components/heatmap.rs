@@ -0,0 +1,67 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::Style,
+    widgets::{Cell, Row, Table},
+    Frame,
+};
+
+use crate::config::Theme;
+
+/// 셀 하나의 폭 (`[##]`)
+const CELL_WIDTH: u16 = 4;
+/// 셀 사이 간격
+const CELL_SPACING: u16 = 1;
+
+/// 코어별 사용률을 색상 격자로 표시 (코어가 많아 게이지/그래프로는 좁을 때)
+pub struct CoreHeatmap<'a> {
+    usages: &'a [f64],
+    theme: Theme,
+    /// 색상 변경 기준값 (기본 50 / 75)
+    thresholds: (f64, f64),
+}
+
+impl<'a> CoreHeatmap<'a> {
+    pub fn new(usages: &'a [f64]) -> Self {
+        Self {
+            usages,
+            theme: Theme::default(),
+            thresholds: (50.0, 75.0),
+        }
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = theme.clone();
+        self
+    }
+
+    pub fn thresholds(mut self, low: f64, high: f64) -> Self {
+        self.thresholds = (low, high);
+        self
+    }
+
+    /// 영역 폭에 들어가는 최대 열 수
+    pub fn columns(width: u16) -> usize {
+        (((width + CELL_SPACING) / (CELL_WIDTH + CELL_SPACING)) as usize).max(1)
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let columns = Self::columns(area.width);
+
+        let rows: Vec<Row> = self
+            .usages
+            .chunks(columns)
+            .map(|chunk| {
+                Row::new(chunk.iter().map(|&usage| {
+                    // 두 자리에 맞추기 위해 100%는 99로 표시
+                    let percent = usage.round().clamp(0.0, 99.0) as u32;
+                    let color = self.theme.level_color(usage, self.thresholds.0, self.thresholds.1);
+                    Cell::from(format!("[{:>2}]", percent)).style(Style::default().fg(color))
+                }))
+            })
+            .collect();
+
+        let table = Table::new(rows, vec![Constraint::Length(CELL_WIDTH); columns])
+            .column_spacing(CELL_SPACING);
+        frame.render_widget(table, area);
+    }
+}
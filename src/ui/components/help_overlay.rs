@@ -0,0 +1,81 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, Row, Table},
+    Frame,
+};
+
+/// 모든 뷰에서 동작하는 전역 단축키
+const GLOBAL_KEYS: [(&str, &str); 13] = [
+    ("q / Esc", "Quit"),
+    ("Tab / →", "Next view"),
+    ("BackTab / ←", "Previous view"),
+    ("Wheel", "Switch view"),
+    ("\\", "Split view / back to single"),
+    (":", "Command palette"),
+    ("L", "Load saved layout"),
+    ("E", "Toggle CSV export"),
+    ("P", "Pause / resume updates"),
+    ("Ctrl+S", "Save screenshot (.ans)"),
+    ("Space", "Dismiss alert / pause replay"),
+    ("?", "Toggle this help"),
+    ("!", "Toggle debug view"),
+];
+const HELP_WIDTH: u16 = 50;
+
+/// 전역 단축키와 현재 뷰 단축키를 보여주는 도움말 모달
+pub struct HelpOverlay<'a> {
+    view_name: &'a str,
+    view_keys: &'a [(&'static str, &'static str)],
+}
+
+impl<'a> HelpOverlay<'a> {
+    pub fn new(view_name: &'a str, view_keys: &'a [(&'static str, &'static str)]) -> Self {
+        Self {
+            view_name,
+            view_keys,
+        }
+    }
+
+    /// 화면 가운데에 배경을 지우고 렌더링
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let section = |title: String| {
+            Row::new(vec![title, String::new()]).style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+        };
+        let key_row = |&(key, action): &(&str, &str)| {
+            Row::new(vec![format!("  {}", key), action.to_string()])
+        };
+
+        let mut rows = vec![section("Global".to_string())];
+        rows.extend(GLOBAL_KEYS.iter().map(key_row));
+        if !self.view_keys.is_empty() {
+            rows.push(Row::new(vec![String::new(), String::new()]));
+            rows.push(section(self.view_name.to_string()));
+            rows.extend(self.view_keys.iter().map(key_row));
+        }
+
+        // 테두리 2줄 포함
+        let width = HELP_WIDTH.min(area.width);
+        let height = (rows.len() as u16 + 2).min(area.height);
+        let help_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let table = Table::new(rows, [Constraint::Length(16), Constraint::Fill(1)]).block(
+            Block::default()
+                .title(" Help ")
+                .title_bottom(" ?/Esc: close ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        frame.render_widget(Clear, help_area);
+        frame.render_widget(table, help_area);
+    }
+}
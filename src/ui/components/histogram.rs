@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Bar, BarChart, Block, Borders},
+    Frame,
+};
+
+use crate::config::Theme;
+
+/// 10% 단위 구간 수
+const BUCKET_COUNT: usize = 10;
+const BUCKET_LABELS: [&str; BUCKET_COUNT] = [
+    "0-10", "10-20", "20-30", "30-40", "40-50", "50-60", "60-70", "70-80", "80-90", "90-100",
+];
+
+/// 사용률 분포 히스토그램 (최근 `window`개 샘플만 집계)
+pub struct UsageHistogram {
+    title: String,
+    buckets: [u64; BUCKET_COUNT],
+    /// 집계 중인 샘플의 구간 인덱스 (오래된 것부터)
+    samples: VecDeque<usize>,
+    /// 집계할 최대 샘플 수 (0이면 무제한)
+    window: usize,
+    theme: Theme,
+}
+
+impl UsageHistogram {
+    pub fn new(title: impl Into<String>, window: usize) -> Self {
+        Self {
+            title: title.into(),
+            buckets: [0; BUCKET_COUNT],
+            samples: VecDeque::new(),
+            window,
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.theme = theme.clone();
+    }
+
+    /// 사용률(0.0 ~ 100.0)에 해당하는 구간 증가 (100%는 마지막 구간)
+    pub fn push(&mut self, percent: f64) {
+        let bucket = ((percent.clamp(0.0, 100.0) / 10.0) as usize).min(BUCKET_COUNT - 1);
+        self.buckets[bucket] += 1;
+
+        if self.window == 0 {
+            return;
+        }
+        self.samples.push_back(bucket);
+        if self.samples.len() > self.window
+            && let Some(oldest) = self.samples.pop_front()
+        {
+            self.buckets[oldest] -= 1;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.buckets = [0; BUCKET_COUNT];
+        self.samples.clear();
+    }
+
+    pub fn total(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    /// 막대 그래프 렌더링 (막대 위에는 전체 대비 비율 표시)
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let total = self.total().max(1);
+        let bars: Vec<Bar> = self
+            .buckets
+            .iter()
+            .zip(BUCKET_LABELS)
+            .enumerate()
+            .map(|(index, (&count, label))| {
+                let lower = index as f64 * 10.0;
+                let color = self.theme.level_color(lower, 50.0, 70.0);
+                Bar::default()
+                    .value(count)
+                    .text_value(format!("{:.0}%", count as f64 / total as f64 * 100.0))
+                    .label(Line::from(label))
+                    .style(Style::default().fg(color))
+            })
+            .collect();
+
+        // 테두리와 막대 사이 간격을 빼고 남은 폭을 10개 막대에 나눔
+        let bar_width = (area.width.saturating_sub(2 + BUCKET_COUNT as u16 - 1)
+            / BUCKET_COUNT as u16)
+            .max(1);
+
+        let chart = BarChart::vertical(bars)
+            .bar_width(bar_width)
+            .bar_gap(1)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!("{} ({} samples)", self.title, self.total()),
+                        Style::default().fg(self.theme.title).bold(),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.border)),
+            );
+        frame.render_widget(chart, area);
+    }
+}
@@ -0,0 +1,60 @@
+use std::cell::Cell;
+
+use ratatui::crossterm::event::KeyCode;
+
+/// ←/→ 한 번에 이동할 글자 수
+const SCROLL_STEP: u16 = 4;
+
+/// 폭이 좁아 잘리는 표 셀을 좌우로 밀어 보여주는 스크롤 상태
+#[derive(Default)]
+pub struct HorizontalScroller {
+    scroll_offset: u16,
+    /// 마지막 렌더링 기준 최대 오프셋 (내용이 모두 보이면 0)
+    max_offset: Cell<u16>,
+}
+
+impl HorizontalScroller {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 화면 크기가 바뀌어도 한계를 넘지 않는 현재 오프셋
+    pub fn offset(&self) -> u16 {
+        self.scroll_offset.min(self.max_offset.get())
+    }
+
+    /// 잘리는 내용이 있는지
+    pub fn can_scroll(&self) -> bool {
+        self.max_offset.get() > 0
+    }
+
+    /// 렌더링할 때 가장 긴 셀 폭과 열 폭으로 스크롤 한계 갱신
+    pub fn update_bounds(&self, content_width: usize, visible_width: u16) {
+        let content_width = content_width.min(u16::MAX as usize) as u16;
+        self.max_offset.set(content_width.saturating_sub(visible_width));
+    }
+
+    /// 셀 문자열 앞부분을 오프셋만큼 잘라냄
+    pub fn shift<'a>(&self, text: &'a str) -> &'a str {
+        match text.char_indices().nth(self.offset() as usize) {
+            Some((index, _)) => &text[index..],
+            None => "",
+        }
+    }
+
+    /// 더 밀 수 있을 때만 ←/→를 소비 (끝에 닿으면 뷰 전환 키로 전달)
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        let offset = self.offset();
+        match key {
+            KeyCode::Left if offset > 0 => {
+                self.scroll_offset = offset.saturating_sub(SCROLL_STEP);
+                true
+            }
+            KeyCode::Right if offset < self.max_offset.get() => {
+                self.scroll_offset = (offset + SCROLL_STEP).min(self.max_offset.get());
+                true
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeSet;
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// 모달 최대 크기
+const SELECTOR_WIDTH: u16 = 48;
+const SELECTOR_MAX_HEIGHT: u16 = 20;
+
+/// 표시할 네트워크 인터페이스를 체크박스로 고르는 모달
+pub struct InterfaceSelectorOverlay {
+    /// (인터페이스 이름, 표시 여부)
+    entries: Vec<(String, bool)>,
+    selected: usize,
+    open: bool,
+}
+
+impl InterfaceSelectorOverlay {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+            open: false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// 현재 인터페이스 목록과 숨김 목록으로 모달 열기
+    pub fn open<'a>(
+        &mut self,
+        interfaces: impl IntoIterator<Item = &'a str>,
+        hidden: &BTreeSet<String>,
+    ) {
+        self.entries = interfaces
+            .into_iter()
+            .map(|name| (name.to_string(), !hidden.contains(name)))
+            .collect();
+        self.selected = 0;
+        self.open = true;
+    }
+
+    /// 체크 해제된 인터페이스 목록
+    fn hidden(&self) -> BTreeSet<String> {
+        self.entries
+            .iter()
+            .filter(|(_, visible)| !visible)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// 키 입력 처리 - Enter로 적용하면 새 숨김 목록 반환, Esc는 취소
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<BTreeSet<String>> {
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.entries.len().saturating_sub(1));
+            }
+            KeyCode::Char(' ') => {
+                if let Some((_, visible)) = self.entries.get_mut(self.selected) {
+                    *visible = !*visible;
+                }
+            }
+            KeyCode::Enter => {
+                self.open = false;
+                return Some(self.hidden());
+            }
+            KeyCode::Esc => self.open = false,
+            _ => {}
+        }
+        None
+    }
+
+    /// 화면 가운데에 배경을 지우고 렌더링
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|(name, visible)| {
+                let checkbox = if *visible { "[x]" } else { "[ ]" };
+                ListItem::new(Line::from(format!("{} {}", checkbox, name)))
+            })
+            .collect();
+
+        // 테두리 2줄 포함
+        let width = SELECTOR_WIDTH.min(area.width);
+        let height = (self.entries.len() as u16 + 2)
+            .min(SELECTOR_MAX_HEIGHT)
+            .min(area.height);
+        let selector_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .title(" Interfaces ")
+                    .title_bottom(" Space: toggle  Enter: apply  Esc: cancel ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            );
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_widget(Clear, selector_area);
+        frame.render_stateful_widget(list, selector_area, &mut state);
+    }
+}
+
+impl Default for InterfaceSelectorOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,138 @@
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::config::LayoutPreset;
+use crate::config::layout::LayoutMode;
+
+/// 모달 최대 크기
+const PICKER_WIDTH: u16 = 50;
+const PICKER_MAX_HEIGHT: u16 = 20;
+
+/// 레이아웃 선택 모달에서 고른 동작
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutPickerAction {
+    /// 프리셋 적용 (설정의 `layouts` 인덱스)
+    Apply(usize),
+    Delete(usize),
+}
+
+/// 저장된 뷰 배치를 고르는 모달 (L 키)
+pub struct LayoutPickerOverlay {
+    /// 목록에 표시할 문구 ("이름 - 뷰 | 뷰")
+    entries: Vec<String>,
+    selected: usize,
+    open: bool,
+}
+
+impl LayoutPickerOverlay {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+            open: false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// 프리셋 목록으로 모달 열기
+    pub fn open(&mut self, presets: &[LayoutPreset]) {
+        self.set_presets(presets);
+        self.selected = 0;
+        self.open = true;
+    }
+
+    /// 삭제 후 목록 갱신 (선택 위치는 범위 안으로)
+    pub fn set_presets(&mut self, presets: &[LayoutPreset]) {
+        self.entries = presets
+            .iter()
+            .map(|preset| match (preset.layout_mode, &preset.secondary_view) {
+                (LayoutMode::SideBySide, Some(secondary)) => {
+                    format!("{} - {} | {}", preset.name, preset.primary_view, secondary)
+                }
+                _ => format!("{} - {}", preset.name, preset.primary_view),
+            })
+            .collect();
+        self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+    }
+
+    /// 키 입력 처리 - Enter는 적용, X는 삭제, Esc나 L은 닫기
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<LayoutPickerAction> {
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.entries.len().saturating_sub(1));
+            }
+            KeyCode::Enter if !self.entries.is_empty() => {
+                self.open = false;
+                return Some(LayoutPickerAction::Apply(self.selected));
+            }
+            KeyCode::Char('x') | KeyCode::Char('X') if !self.entries.is_empty() => {
+                return Some(LayoutPickerAction::Delete(self.selected));
+            }
+            KeyCode::Esc | KeyCode::Char('l') | KeyCode::Char('L') => self.open = false,
+            _ => {}
+        }
+        None
+    }
+
+    /// 화면 가운데에 배경을 지우고 렌더링
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        // 테두리 2줄 포함 (비어 있으면 안내 한 줄)
+        let width = PICKER_WIDTH.min(area.width);
+        let height = (self.entries.len().max(1) as u16 + 2)
+            .min(PICKER_MAX_HEIGHT)
+            .min(area.height);
+        let picker_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let block = Block::default()
+            .title(" Layouts ")
+            .title_bottom(" Enter: apply  X: delete  Esc: close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        frame.render_widget(Clear, picker_area);
+
+        if self.entries.is_empty() {
+            let message = Paragraph::new("No saved layouts (: → save layout as)")
+                .style(Style::default().fg(Color::Gray))
+                .block(block);
+            frame.render_widget(message, picker_area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|entry| ListItem::new(Line::from(entry.as_str())))
+            .collect();
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(block);
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, picker_area, &mut state);
+    }
+}
+
+impl Default for LayoutPickerOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,6 +1,26 @@
+pub mod command_palette;
+pub mod filter_bar;
+pub mod heatmap;
+pub mod help_overlay;
+pub mod histogram;
+pub mod horizontal_scroller;
+pub mod interface_selector;
+pub mod layout_picker;
+pub mod osc_debug_log;
+pub mod stats_footer;
+pub mod tab_bar;
+pub mod time_range;
 pub mod usage_gauge;
+pub mod view_picker;
 
-pub use usage_gauge::{
-    CoreGraph, CpuGauge, CpuGraph, GpuGauge, GpuGraph, MemoryGauge, MemoryGraph, UsageGauge,
-    UsageGraph,
-};
\ No newline at end of file
+pub use command_palette::{CommandPalette, CommandRegistry};
+pub use filter_bar::FilterBar;
+pub use heatmap::CoreHeatmap;
+pub use help_overlay::HelpOverlay;
+pub use horizontal_scroller::HorizontalScroller;
+pub use layout_picker::{LayoutPickerAction, LayoutPickerOverlay};
+pub use stats_footer::StatsFooter;
+pub use tab_bar::TabBar;
+pub use time_range::{SharedTimeRange, TimeRangeSelector};
+pub use view_picker::ViewPickerOverlay;
+pub use usage_gauge::{CoreFrequencyGraph, UsageGauge};
@@ -0,0 +1,204 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Row, Table},
+    Frame,
+};
+
+use crate::integration::vrc_osc::{OscEvent, OscValue};
+
+const MAX_ROWS: usize = 100; // 최대 보관 행 수
+
+/// 수신된 OSC 메시지를 표 형태로 보여주는 디버그 로그
+pub struct OscDebugLog {
+    entries: VecDeque<OscEvent>,
+    /// 주소 접두사 필터
+    filter: Option<String>,
+    /// 필터 입력 중인 문자열 (F 키로 시작)
+    filter_input: Option<String>,
+    /// 일시정지 시 새 항목을 받지 않음
+    paused: bool,
+    /// 맨 아래에서부터의 스크롤 오프셋 (0이면 자동 스크롤)
+    scroll: usize,
+}
+
+impl OscDebugLog {
+    pub fn new() -> Self {
+        Self {
+            entries: VecDeque::with_capacity(MAX_ROWS),
+            filter: None,
+            filter_input: None,
+            paused: false,
+            scroll: 0,
+        }
+    }
+
+    /// 새 OSC 이벤트 추가 (일시정지 중이면 무시)
+    pub fn push(&mut self, event: OscEvent) {
+        if self.paused {
+            return;
+        }
+        if self.entries.len() >= MAX_ROWS {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(event);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.scroll = 0;
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        if !self.paused {
+            self.scroll = 0;
+        }
+    }
+
+    /// 필터를 통과한 항목과 직전 항목 대비 경과 시간(ms)
+    fn visible_entries(&self) -> Vec<(u128, &OscEvent)> {
+        let mut prev: Option<&OscEvent> = None;
+        let mut result = Vec::new();
+        for event in &self.entries {
+            let delta = prev
+                .map(|p| event.received_at.duration_since(p.received_at).as_millis())
+                .unwrap_or(0);
+            prev = Some(event);
+            let matches = self
+                .filter
+                .as_ref()
+                .is_none_or(|prefix| event.address.starts_with(prefix.as_str()));
+            if matches {
+                result.push((delta, event));
+            }
+        }
+        result
+    }
+
+    /// 키 입력 처리 - true 반환 시 이벤트 소비됨
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        // 필터 입력 모드
+        if let Some(ref mut input) = self.filter_input {
+            match key {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => {
+                    let input = self.filter_input.take().unwrap_or_default();
+                    self.filter = (!input.is_empty()).then_some(input);
+                    self.scroll = 0;
+                }
+                KeyCode::Esc => self.filter_input = None,
+                _ => {}
+            }
+            return true;
+        }
+
+        match key {
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.filter_input = Some(self.filter.clone().unwrap_or_default());
+                true
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.clear();
+                true
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.toggle_pause();
+                true
+            }
+            KeyCode::Up => {
+                self.scroll = (self.scroll + 1).min(self.entries.len().saturating_sub(1));
+                true
+            }
+            KeyCode::Down => {
+                self.scroll = self.scroll.saturating_sub(1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn value_color(value: &OscValue) -> Color {
+        match value {
+            OscValue::Float(_) => Color::Cyan,
+            OscValue::Bool(_) => Color::Yellow,
+            OscValue::Int(_) => Color::Green,
+            OscValue::String(_) => Color::White,
+        }
+    }
+
+    /// 컴포넌트 렌더링
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let entries = self.visible_entries();
+
+        // 테두리 + 헤더를 제외한 표시 가능 행 수
+        let capacity = area.height.saturating_sub(3) as usize;
+        let end = entries.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(capacity);
+
+        let rows: Vec<Row> = entries[start..end]
+            .iter()
+            .map(|(delta, event)| {
+                Row::new(vec![
+                    Span::styled(format!("+{}ms", delta), Style::default().fg(Color::Gray)),
+                    Span::raw(event.address.clone()),
+                    Span::styled(event.value.type_name(), Style::default().fg(Color::Blue)),
+                    Span::styled(
+                        event.value.to_string(),
+                        Style::default().fg(Self::value_color(&event.value)),
+                    ),
+                ])
+            })
+            .collect();
+
+        let mut title = format!(" OSC Debug Log ({}) ", entries.len());
+        if let Some(ref input) = self.filter_input {
+            title.push_str(&format!("[Filter: {}_] ", input));
+        } else if let Some(ref filter) = self.filter {
+            title.push_str(&format!("[Filter: {}] ", filter));
+        }
+        if self.paused {
+            title.push_str("[PAUSED] ");
+        }
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Fill(1),
+                Constraint::Length(8),
+                Constraint::Length(16),
+            ],
+        )
+        .header(
+            Row::new(vec!["Timestamp", "Address", "Type", "Value"])
+                .style(Style::default().fg(Color::Cyan).bold()),
+        )
+        .block(
+            Block::default()
+                .title(title)
+                .title_bottom(" F: filter | C: clear | P: pause | ↑↓: scroll ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(if self.paused {
+                    Color::Yellow
+                } else {
+                    Color::Magenta
+                })),
+        );
+
+        frame.render_widget(table, area);
+    }
+}
+
+impl Default for OscDebugLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,48 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::util::statistics::{self, Statistics};
+
+/// 히스토리의 최솟값/평균/최댓값/표준편차를 보여주는 한 줄 요약 (사용률 % 기준)
+pub struct StatsFooter {
+    stats: Statistics,
+}
+
+impl StatsFooter {
+    /// 히스토리 슬라이스로 통계 계산 (NaN 공백은 제외)
+    pub fn new(history: &[f64]) -> Self {
+        Self {
+            stats: statistics::compute(history),
+        }
+    }
+
+    /// `min: 2.1%  avg: 34.7%  max: 97.3%  σ: 12.4%` 형식의 문자열
+    pub fn text(&self) -> String {
+        format!(
+            "min: {:.1}%  avg: {:.1}%  max: {:.1}%  σ: {:.1}%",
+            self.stats.min, self.stats.avg, self.stats.max, self.stats.stddev
+        )
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(
+            Paragraph::new(self.text()).style(Style::default().fg(Color::Gray)),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_summarizes_history_as_percent() {
+        let footer = StatsFooter::new(&[30.0, 40.0, 50.0]);
+        assert_eq!(footer.text(), "min: 30.0%  avg: 40.0%  max: 50.0%  σ: 8.2%");
+    }
+}
@@ -0,0 +1,138 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::Tabs,
+    Frame,
+};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::config::Theme;
+
+/// 재생 진행 막대 칸 수
+const REPLAY_BAR_WIDTH: usize = 12;
+
+/// 뷰 제목(`ViewComponent::title`)을 보여주고 현재 뷰를 강조하는 탭 바
+pub struct TabBar<'a> {
+    titles: &'a [&'a str],
+    selected: usize,
+    /// 나란히 보기 중인 두 번째 뷰
+    secondary: Option<usize>,
+    highlight: Color,
+    paused: bool,
+    /// 녹화 재생 진행률과 일시정지 여부 (재생 중일 때만 Some)
+    replay: Option<(f64, bool)>,
+}
+
+impl<'a> TabBar<'a> {
+    pub fn new(titles: &'a [&'a str], selected: usize) -> Self {
+        Self {
+            titles,
+            selected,
+            secondary: None,
+            highlight: Color::Cyan,
+            paused: false,
+            replay: None,
+        }
+    }
+
+    /// 오른쪽 끝에 녹화 재생 진행 막대 표시
+    pub fn replay(mut self, replay: Option<(f64, bool)>) -> Self {
+        self.replay = replay;
+        self
+    }
+
+    /// 나란히 보기 중인 두 번째 뷰도 강조 (선택된 탭보다 옅게)
+    pub fn secondary(mut self, secondary: Option<usize>) -> Self {
+        self.secondary = secondary;
+        self
+    }
+
+    /// 오른쪽 끝에 `[PAUSED]` 표시
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// 선택된 탭 배경을 테마 제목 색상으로 표시
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.highlight = theme.title;
+        self
+    }
+
+    /// 한 줄 높이 영역에 렌더링
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let area = match self.replay {
+            Some((progress, paused)) => {
+                let [tabs_area, replay_area] = Layout::horizontal([
+                    Constraint::Min(0),
+                    Constraint::Length(REPLAY_BAR_WIDTH as u16 + 8),
+                ])
+                .areas(area);
+                let filled = (progress.clamp(0.0, 1.0) * REPLAY_BAR_WIDTH as f64).round() as usize;
+                let text = format!(
+                    "{} {}{} {:>3.0}%",
+                    if paused { "⏸" } else { "▶" },
+                    "█".repeat(filled),
+                    "░".repeat(REPLAY_BAR_WIDTH - filled),
+                    progress * 100.0
+                );
+                let color = if paused { Color::Yellow } else { Color::Green };
+                frame.buffer_mut().set_string(
+                    replay_area.x + 1,
+                    replay_area.y,
+                    text,
+                    Style::default().fg(color),
+                );
+                tabs_area
+            }
+            None => area,
+        };
+        let area = if self.paused {
+            let [tabs_area, paused_area] =
+                Layout::horizontal([Constraint::Min(0), Constraint::Length(9)]).areas(area);
+            frame.buffer_mut().set_string(
+                paused_area.x + 1,
+                paused_area.y,
+                "[PAUSED]",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            );
+            tabs_area
+        } else {
+            area
+        };
+
+        let tabs = Tabs::new(self.titles.iter().copied())
+            .select(self.selected)
+            .style(Style::default().fg(Color::Gray))
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(self.highlight)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .divider("│");
+        frame.render_widget(tabs, area);
+
+        // Tabs는 한 탭만 강조하므로 두 번째 뷰 탭은 직접 칠함 (탭마다 양옆 공백 1칸 + 구분자 1칸)
+        if let Some(secondary) = self.secondary.filter(|&index| index != self.selected) {
+            let offset: usize = self.titles[..secondary.min(self.titles.len())]
+                .iter()
+                .map(|title| title.width() + 3)
+                .sum();
+            let width = self.titles.get(secondary).map_or(0, |title| title.width() + 2);
+            let x = area.x.saturating_add(offset as u16);
+            if x < area.right() {
+                let tab = Rect::new(x, area.y, (width as u16).min(area.right() - x), 1);
+                frame.buffer_mut().set_style(
+                    tab,
+                    Style::default()
+                        .fg(self.highlight)
+                        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                );
+            }
+        }
+    }
+}
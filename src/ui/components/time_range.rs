@@ -0,0 +1,106 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// 시간 범위 프리셋 (이름, 표시할 샘플 수)
+pub const TIME_RANGES: [(&str, usize); 4] = [("3s", 60), ("30s", 600), ("5m", 6000), ("30m", 36000)];
+
+/// 가장 긴 프리셋의 샘플 수 (그래프 히스토리 크기 기준)
+pub const MAX_TIME_RANGE_SAMPLES: usize = TIME_RANGES[TIME_RANGES.len() - 1].1;
+
+/// 선택된 범위의 샘플 수 - 그래프를 가진 뷰들이 tick마다 읽어 반영
+pub type SharedTimeRange = Arc<AtomicUsize>;
+
+/// `[ 3s | 30s | 5m | 30m ]` 형태의 한 줄짜리 시간 범위 선택기 (↑/↓로 순환)
+pub struct TimeRangeSelector {
+    /// `TIME_RANGES` 인덱스
+    index: usize,
+    shared: SharedTimeRange,
+}
+
+impl TimeRangeSelector {
+    pub fn new() -> Self {
+        Self {
+            index: 0,
+            shared: Arc::new(AtomicUsize::new(TIME_RANGES[0].1)),
+        }
+    }
+
+    /// 다른 뷰에 넘겨줄 공유 핸들
+    pub fn shared(&self) -> SharedTimeRange {
+        self.shared.clone()
+    }
+
+    /// 현재 범위의 샘플 수
+    pub fn samples(&self) -> usize {
+        TIME_RANGES[self.index].1
+    }
+
+    fn select(&mut self, index: usize) {
+        self.index = index;
+        self.shared.store(self.samples(), Ordering::Relaxed);
+    }
+
+    /// 다음(더 긴) 범위로 - 마지막이면 처음으로
+    pub fn next(&mut self) {
+        self.select((self.index + 1) % TIME_RANGES.len());
+    }
+
+    /// 이전(더 짧은) 범위로 - 처음이면 마지막으로
+    pub fn prev(&mut self) {
+        self.select((self.index + TIME_RANGES.len() - 1) % TIME_RANGES.len());
+    }
+
+    /// ↑는 더 긴 범위, ↓는 더 짧은 범위 (←/→는 뷰 전환에 쓰이므로 사용하지 않음)
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Up => self.next(),
+            KeyCode::Down => self.prev(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// 렌더링에 필요한 폭
+    pub fn width(&self) -> u16 {
+        let labels: usize = TIME_RANGES.iter().map(|(label, _)| label.len()).sum();
+        // "[ " + 레이블 + 구분자 " | " + " ]"
+        (labels + (TIME_RANGES.len() - 1) * 3 + 4) as u16
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = vec![Span::raw("[ ")];
+        for (i, (label, _)) in TIME_RANGES.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+            }
+            let style = if i == self.index {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            spans.push(Span::styled(*label, style));
+        }
+        spans.push(Span::raw(" ]"));
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+impl Default for TimeRangeSelector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
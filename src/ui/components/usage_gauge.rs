@@ -1,149 +1,564 @@
+use std::time::{Duration, Instant};
+
 use ratatui::{
     layout::Rect,
     style::{Color, Style},
     symbols,
-    text::Span,
-    widgets::{Axis, Block, Borders, Chart, Dataset, Gauge, GraphType},
+    text::{Line, Span},
+    widgets::{Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, Paragraph, Sparkline},
     Frame,
 };
 
-const HISTORY_SIZE: usize = 60; // 60개 데이터 포인트 (약 3초 @ 50ms tick)
+use crate::config::Theme;
+#[cfg(target_os = "windows")]
+use crate::integration::windows_memory::WindowsMemoryInfo;
+use crate::util::RingBuffer;
+
+/// 기본 히스토리 길이 - 60개 데이터 포인트 (약 3초 @ 50ms tick)
+const HISTORY_SIZE: usize = 60;
+
+/// 히스토리에 삽입되는 데이터 공백 표시값
+const GAP_SENTINEL: f64 = f64::NAN;
+
+/// 히스토리를 공백 표시값 기준으로 나눈 연속 구간들과 공백 위치(x)
+fn split_segments<'a>(
+    history: impl IntoIterator<Item = &'a f64>,
+) -> (Vec<Vec<(f64, f64)>>, Vec<f64>) {
+    let mut segments = vec![Vec::new()];
+    let mut gaps = Vec::new();
+    for (i, &v) in history.into_iter().enumerate() {
+        if v.is_nan() {
+            gaps.push(i as f64);
+            segments.push(Vec::new());
+        } else if let Some(segment) = segments.last_mut() {
+            segment.push((i as f64, v));
+        }
+    }
+    segments.retain(|segment| !segment.is_empty());
+    (segments, gaps)
+}
+
+/// 슬라이스의 마지막 `count`개
+fn tail<T>(values: &[T], count: usize) -> &[T] {
+    &values[values.len().saturating_sub(count)..]
+}
+
+/// 구간별 Dataset 생성 (범례 이름은 첫 구간에만 표시)
+fn segment_datasets<'a>(
+    segments: &'a [Vec<(f64, f64)>],
+    name: String,
+    color: Color,
+) -> Vec<Dataset<'a>> {
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let dataset = Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(color))
+                .data(segment);
+            if i == 0 {
+                dataset.name(name.clone())
+            } else {
+                dataset
+            }
+        })
+        .collect()
+}
+
+/// 차트 하단 테두리의 공백 위치에 `[gap]` 표시
+fn render_gap_markers(
+    frame: &mut Frame,
+    area: Rect,
+    gaps: &[f64],
+    history_size: usize,
+    y_label_width: u16,
+) {
+    // 테두리 1칸 + y축 레이블 + 축 선 1칸 이후부터 그래프 영역
+    let graph_x = area.x + 1 + y_label_width + 1;
+    let graph_width = area.width.saturating_sub(y_label_width + 3);
+    let y = area.bottom().saturating_sub(1);
+    let right = area.right().saturating_sub(1);
+
+    for &gap in gaps {
+        let x = graph_x + ((gap / history_size as f64) * graph_width as f64) as u16;
+        if x + 5 <= right {
+            frame
+                .buffer_mut()
+                .set_string(x, y, "[gap]", Style::default().fg(Color::DarkGray));
+        }
+    }
+}
+
+/// y축 레이블 생성 (모든 레이블에 기본 스타일, 양 끝은 강조 스타일을 덧씌움)
+fn axis_labels(
+    low: impl Into<String>,
+    mid: impl Into<String>,
+    high: impl Into<String>,
+    style: Style,
+    edge_style: Style,
+) -> Vec<Span<'static>> {
+    vec![
+        Span::styled(low.into(), style.patch(edge_style)),
+        Span::styled(mid.into(), style),
+        Span::styled(high.into(), style.patch(edge_style)),
+    ]
+}
 
 /// 사용량을 표시하는 게이지 컴포넌트
 pub struct UsageGauge {
     title: String,
     usage_percent: f64,
     color: Color,
+    theme: Theme,
+    /// 이 값 이하면 초록색
+    low_threshold: f64,
+    /// 이 값 이하면 노란색, 초과하면 빨간색
+    high_threshold: f64,
+    /// 막대 위 문구 (None이면 사용률 %)
+    label: Option<String>,
 }
 
 impl UsageGauge {
     pub fn new(title: impl Into<String>) -> Self {
+        let theme = Theme::default();
         Self {
             title: title.into(),
             usage_percent: 0.0,
-            color: Color::Green,
+            color: theme.low,
+            theme,
+            low_threshold: 50.0,
+            high_threshold: 75.0,
+            label: None,
         }
     }
 
+    /// 색상 변경 기준값 지정 (기본 50 / 75)
+    pub fn with_thresholds(mut self, low: f64, high: f64) -> Self {
+        self.set_thresholds(low, high);
+        self
+    }
+
+    /// 색상 변경 기준값 설정 (현재 사용량 기준으로 색상 재계산)
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        self.low_threshold = low;
+        self.high_threshold = high;
+        self.set_usage(self.usage_percent);
+    }
+
+    /// 테마 색상 적용 (현재 사용량 기준으로 색상 재계산)
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.theme = theme.clone();
+        self.set_usage(self.usage_percent);
+    }
+
     /// 사용량 업데이트 (0.0 ~ 100.0)
     pub fn set_usage(&mut self, percent: f64) {
         self.usage_percent = percent.clamp(0.0, 100.0);
         // 사용량에 따라 색상 변경
-        self.color = match self.usage_percent as u32 {
-            0..=50 => Color::Green,
-            51..=75 => Color::Yellow,
-            _ => Color::Red,
-        };
+        self.color = self.theme.level_color(
+            self.usage_percent.trunc(),
+            self.low_threshold,
+            self.high_threshold,
+        );
     }
 
     pub fn get_usage(&self) -> f64 {
         self.usage_percent
     }
 
-    pub fn get_color(&self) -> Color {
-        self.color
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
+    /// 막대 위 문구 지정 (None이면 사용률 %)
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
+    }
+
+    /// 사용량 기준 색상 대신 지정한 색상 사용 (set_usage 이후에 호출)
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
     }
 
     /// 컴포넌트 렌더링
     pub fn render(&self, frame: &mut Frame, area: Rect) {
+        match self.label {
+            Some(ref label) => self.render_with_label(frame, area, label),
+            None => self.render_with_label(frame, area, &format!("{:.1}%", self.usage_percent)),
+        }
+    }
+
+    /// 사용률 % 대신 지정한 문구로 렌더링 (예: "3.2 / 16.0 GB", "65.0°C")
+    pub fn render_with_label(&self, frame: &mut Frame, area: Rect, label: &str) {
         let gauge = Gauge::default()
             .block(
                 Block::default()
                     .title(self.title.clone())
-                    .borders(Borders::ALL),
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.border)),
             )
             .gauge_style(Style::default().fg(self.color))
             .percent(self.usage_percent as u16)
-            .label(format!("{:.1}%", self.usage_percent));
+            .label(label.to_string());
 
         frame.render_widget(gauge, area);
     }
 }
 
+/// 그래프에 저장하기 전 샘플 평활화 방식
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SmoothingMode {
+    /// 원본 값 그대로
+    #[default]
+    None,
+    /// 지수 이동 평균 (α: 0 < α ≤ 1, 클수록 최신 값 비중이 큼)
+    Ema(f64),
+}
+
 /// 시계열 그래프 컴포넌트
 pub struct UsageGraph {
     title: String,
-    history: Vec<f64>,
+    history: RingBuffer<f64>,
     color: Color,
     initialized: bool,
+    theme: Theme,
+    axis_label_style: Style,
+    axis_label_bold_style: Style,
+    /// y축 범위 (기본 0 ~ 100)
+    y_bounds: [f64; 2],
+    /// y축 레이블 (최소, 중간, 최대)
+    y_labels: [String; 3],
+    /// 값 뒤에 붙는 단위 (기본 "%")
+    unit: String,
+    /// 범례 문구 (None이면 현재 값 + 단위)
+    label: Option<String>,
+    /// 이 값 이하면 초록색
+    low_threshold: f64,
+    /// 이 값 이하면 노란색, 초과하면 빨간색
+    high_threshold: f64,
+    /// 값이 높을수록 좋은 지표 (FPS 등) - 기준값 비교 방향이 반대
+    higher_is_better: bool,
+    /// 마지막 push 시각 (데이터 공백 감지용)
+    last_push: Option<Instant>,
+    /// 예상 push 간격 - 두 배 이상 지연되면 공백으로 처리
+    expected_interval: Duration,
+    smoothing: SmoothingMode,
+    /// 마지막 평활화 결과
+    last_smoothed: Option<f64>,
+    /// 표시할 최근 샘플 수 (None이면 히스토리 전체)
+    visible_samples: Option<usize>,
+    /// 지금까지(또는 reset_peak 이후) 가장 높은 값 (아직 없으면 -∞)
+    peak_value: f64,
+    /// 최고값 샘플의 순번 (`samples_pushed` 기준)
+    peak_x: usize,
+    /// 히스토리에 추가된 샘플 수 (공백 포함)
+    samples_pushed: usize,
 }
 
 impl UsageGraph {
     pub fn new(title: impl Into<String>) -> Self {
         Self {
             title: title.into(),
-            history: vec![0.0; HISTORY_SIZE],
+            history: RingBuffer::filled(0.0, HISTORY_SIZE),
             color: Color::Green,
             initialized: false,
+            theme: Theme::default(),
+            axis_label_style: Style::default(),
+            axis_label_bold_style: Style::default(),
+            y_bounds: [0.0, 100.0],
+            y_labels: ["0".to_string(), "50".to_string(), "100".to_string()],
+            unit: "%".to_string(),
+            label: None,
+            low_threshold: 50.0,
+            high_threshold: 75.0,
+            higher_is_better: false,
+            last_push: None,
+            // 동적 tick rate의 최댓값 기준 (tick 변동을 공백으로 오인하지 않도록)
+            expected_interval: Duration::from_millis(200),
+            smoothing: SmoothingMode::None,
+            last_smoothed: None,
+            visible_samples: None,
+            peak_value: f64::NEG_INFINITY,
+            peak_x: 0,
+            samples_pushed: 0,
         }
     }
 
-    /// 새 데이터 추가 (0.0 ~ 100.0)
+    /// 히스토리 길이 설정 (기존 히스토리는 버림)
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.history = RingBuffer::filled(0.0, size.max(1));
+        self.initialized = false;
+        self.last_smoothed = None;
+        self.reset_peak();
+        self
+    }
+
+    /// 최고값 표시 초기화 (다음 샘플부터 다시 기록)
+    pub fn reset_peak(&mut self) {
+        self.peak_value = f64::NEG_INFINITY;
+        self.peak_x = 0;
+    }
+
+    /// 최고값 (아직 샘플이 없으면 None)
+    pub fn peak(&self) -> Option<f64> {
+        self.peak_value.is_finite().then_some(self.peak_value)
+    }
+
+    /// 샘플 평활화 방식 지정 (기본 None)
+    pub fn with_smoothing(mut self, mode: SmoothingMode) -> Self {
+        self.smoothing = mode;
+        self.last_smoothed = None;
+        self
+    }
+
+    /// 최근 `samples`개만 표시 (히스토리보다 크면 전체)
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.visible_samples = Some(samples.max(1));
+    }
+
+    /// x축 폭 - 표시할 샘플 수
+    fn visible_len(&self) -> usize {
+        let capacity = self.history.capacity();
+        self.visible_samples.map_or(capacity, |samples| samples.min(capacity))
+    }
+
+    /// 표시 범위에 해당하는 최근 히스토리
+    pub fn visible_history(&self) -> &[f64] {
+        tail(self.history.as_slice(), self.visible_len())
+    }
+
+    /// 예상 push 간격 설정
+    pub fn set_expected_interval(&mut self, interval: Duration) {
+        self.expected_interval = interval;
+    }
+
+    /// y축 범위 설정 (push 값도 이 범위로 제한됨)
+    pub fn set_y_bounds(&mut self, min: f64, max: f64) {
+        self.y_bounds = [min, max];
+    }
+
+    /// y축 레이블 설정 (최소, 중간, 최대)
+    pub fn set_y_labels(
+        &mut self,
+        low: impl Into<String>,
+        mid: impl Into<String>,
+        high: impl Into<String>,
+    ) {
+        self.y_labels = [low.into(), mid.into(), high.into()];
+    }
+
+    pub fn set_unit(&mut self, unit: impl Into<String>) {
+        self.unit = unit.into();
+    }
+
+    /// 범례 문구 지정 (None이면 현재 값 + 단위)
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
+    }
+
+    /// 색상 변경 기준값 지정 (기본 50 / 75)
+    pub fn with_thresholds(mut self, low: f64, high: f64) -> Self {
+        self.set_thresholds(low, high);
+        self
+    }
+
+    /// 색상 변경 기준값 설정
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        self.low_threshold = low;
+        self.high_threshold = high;
+    }
+
+    /// 높은 값을 초록색으로 표시 (high 이상 초록, low 이상 노랑, 미만 빨강)
+    pub fn set_higher_is_better(&mut self, higher_is_better: bool) {
+        self.higher_is_better = higher_is_better;
+    }
+
+    /// 값에 해당하는 색상
+    fn color_for(&self, value: f64) -> Color {
+        if self.higher_is_better {
+            return if value >= self.high_threshold {
+                self.theme.low
+            } else if value >= self.low_threshold {
+                self.theme.mid
+            } else {
+                self.theme.high
+            };
+        }
+        self.theme.level_color(value, self.low_threshold, self.high_threshold)
+    }
+
+    /// 테마의 색상과 축 레이블 스타일 적용
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.theme = theme.clone();
+        self.set_axis_label_style(theme.axis_label_style);
+        self.axis_label_bold_style = theme.axis_label_bold_style;
+        self.color = self.color_for(self.get_current());
+    }
+
+    /// 모든 축 레이블에 적용할 스타일 (최솟값/최댓값에는 강조 스타일이 더해짐)
+    pub fn set_axis_label_style(&mut self, style: Style) {
+        self.axis_label_style = style;
+    }
+
+    /// 새 데이터 추가 (y축 범위로 제한, 기본 0.0 ~ 100.0)
     pub fn push(&mut self, percent: f64) {
-        let clamped = percent.clamp(0.0, 100.0);
-        
+        let clamped = percent.clamp(self.y_bounds[0], self.y_bounds[1]);
+        let smoothed = self.smooth(clamped);
+
+        let now = Instant::now();
+
         // 첫 데이터가 들어오면 히스토리 전체를 현재 값으로 초기화
         if !self.initialized {
-            self.history = vec![clamped; HISTORY_SIZE];
+            self.history.fill(smoothed);
             self.initialized = true;
+            self.samples_pushed += 1;
         } else {
-            self.history.remove(0);
-            self.history.push(clamped);
+            // 시스템 일시정지 등으로 push가 오래 밀렸으면 공백 표시값 삽입
+            if let Some(last) = self.last_push
+                && now.duration_since(last) > self.expected_interval * 2
+            {
+                self.push_raw(GAP_SENTINEL);
+            }
+            self.push_raw(smoothed);
+        }
+        self.last_push = Some(now);
+
+        if smoothed > self.peak_value {
+            self.peak_value = smoothed;
+            self.peak_x = self.samples_pushed - 1;
         }
 
         // 최신 값에 따라 색상 변경
-        self.color = match clamped as u32 {
-            0..=50 => Color::Green,
-            51..=75 => Color::Yellow,
-            _ => Color::Red,
+        self.color = self.color_for(smoothed);
+    }
+
+    /// 평활화 방식에 따라 저장할 값 계산
+    fn smooth(&mut self, value: f64) -> f64 {
+        let smoothed = match (self.smoothing, self.last_smoothed) {
+            (SmoothingMode::Ema(alpha), Some(last)) => {
+                let alpha = alpha.clamp(f64::EPSILON, 1.0);
+                alpha * value + (1.0 - alpha) * last
+            }
+            _ => value,
         };
+        self.last_smoothed = Some(smoothed);
+        smoothed
+    }
+
+    /// 측정 실패 등으로 값이 없는 샘플 기록 (공백으로 표시)
+    pub fn push_gap(&mut self) {
+        if self.initialized {
+            self.push_raw(GAP_SENTINEL);
+            self.last_push = Some(Instant::now());
+        }
+    }
+
+    fn push_raw(&mut self, value: f64) {
+        self.history.push(value);
+        self.samples_pushed += 1;
     }
 
     pub fn get_current(&self) -> f64 {
         *self.history.last().unwrap_or(&0.0)
     }
 
+    /// 오래된 값부터의 히스토리 (공백은 NaN)
+    pub fn history(&self) -> &[f64] {
+        self.history.as_slice()
+    }
+
+    /// 표시 범위 중 실제로 기록된 샘플 (첫 샘플로 채운 초기값 제외, 공백은 NaN)
+    pub fn recorded_history(&self) -> &[f64] {
+        tail(self.visible_history(), self.samples_pushed)
+    }
+
+    /// y축 레이블 중 가장 긴 폭
+    fn y_label_width(&self) -> u16 {
+        self.y_labels.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16
+    }
+
+    /// 최고값 가로 점선 (차트 안쪽 폭 기준 두 칸마다 점 하나)
+    fn peak_line(&self, area: Rect, peak: f64) -> Vec<(f64, f64)> {
+        let visible = self.visible_len() as f64;
+        // 테두리, y축 레이블, 축선 제외
+        let width = area.width.saturating_sub(self.y_label_width() + 3).max(1) as usize;
+        (0..width)
+            .step_by(2)
+            .map(|cell| (cell as f64 * visible / width as f64, peak))
+            .collect()
+    }
+
+    /// 최고값 샘플 위치 (표시 범위를 벗어났으면 None)
+    fn peak_marker(&self, peak: f64) -> Option<(f64, f64)> {
+        let visible = self.visible_len();
+        let age = self.samples_pushed - 1 - self.peak_x;
+        (age < visible).then(|| ((visible - 1 - age) as f64, peak))
+    }
+
     /// 그래프 렌더링
     pub fn render(&self, frame: &mut Frame, area: Rect) {
-        // 데이터를 (x, y) 형태로 변환
-        let data: Vec<(f64, f64)> = self
-            .history
-            .iter()
-            .enumerate()
-            .map(|(i, &v)| (i as f64, v))
-            .collect();
-
-        let datasets = vec![Dataset::default()
-            .name(format!("{:.1}%", self.get_current()))
-            .marker(symbols::Marker::Braille)
-            .graph_type(GraphType::Line)
-            .style(Style::default().fg(self.color))
-            .data(&data)];
+        // 데이터를 공백 기준 연속 구간의 (x, y) 형태로 변환
+        let (segments, gaps) = split_segments(self.visible_history());
+        let peak_line = self.peak().map(|peak| self.peak_line(area, peak));
+        let peak_marker = self.peak().and_then(|peak| self.peak_marker(peak));
+
+        let mut datasets = segment_datasets(
+            &segments,
+            self.label
+                .clone()
+                .unwrap_or_else(|| format!("{:.1}{}", self.get_current(), self.unit)),
+            self.color,
+        );
+        if let Some(ref line) = peak_line {
+            datasets.push(
+                Dataset::default()
+                    .name(format!("peak: {:.1}{}", self.peak_value, self.unit))
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::Scatter)
+                    .style(Style::default().fg(self.theme.border))
+                    .data(line),
+            );
+        }
+        if let Some(ref marker) = peak_marker {
+            datasets.push(
+                Dataset::default()
+                    .marker(symbols::Marker::Block)
+                    .graph_type(GraphType::Scatter)
+                    .style(Style::default().fg(self.color_for(self.peak_value)))
+                    .data(std::slice::from_ref(marker)),
+            );
+        }
 
         let chart = Chart::new(datasets)
             .block(
                 Block::default()
                     .title(Span::styled(
                         self.title.clone(),
-                        Style::default().fg(Color::Cyan).bold(),
+                        Style::default().fg(self.theme.title).bold(),
                     ))
-                    .borders(Borders::ALL),
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.border)),
             )
             .x_axis(
                 Axis::default()
-                    .bounds([0.0, HISTORY_SIZE as f64]),
+                    .bounds([0.0, self.visible_len() as f64]),
             )
             .y_axis(
                 Axis::default()
-                    .bounds([0.0, 100.0])
-                    .labels(vec![
-                        Span::raw("0"),
-                        Span::raw("50"),
-                        Span::raw("100"),
-                    ]),
+                    .bounds(self.y_bounds)
+                    .labels(axis_labels(
+                        self.y_labels[0].clone(),
+                        self.y_labels[1].clone(),
+                        self.y_labels[2].clone(),
+                        self.axis_label_style,
+                        self.axis_label_bold_style,
+                    )),
             );
 
         frame.render_widget(chart, area);
+        render_gap_markers(frame, area, &gaps, self.visible_len(), self.y_label_width());
     }
 }
 
@@ -163,6 +578,40 @@ impl CpuGraph {
         self.graph.push(percent);
     }
 
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self
+    }
+
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
+    }
+
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+    }
+
+    /// 샘플 평활화 방식 지정
+    pub fn with_smoothing(mut self, mode: SmoothingMode) -> Self {
+        self.graph = self.graph.with_smoothing(mode);
+        self
+    }
+
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+    }
+
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        self.graph.set_thresholds(low, high);
+    }
+
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         self.graph.render(frame, area);
     }
@@ -188,6 +637,12 @@ impl GpuGraph {
         }
     }
 
+    /// 제목에 장치 이름 표시 (예: "GPU 0: RTX 4090")
+    pub fn set_device_name(&mut self, name: &str) {
+        self.graph.title = format!("GPU {}", name);
+        self.vram_graph.title = format!("VRAM {}", name);
+    }
+
     pub fn push(&mut self, percent: f64) {
         self.graph.push(percent);
     }
@@ -196,6 +651,49 @@ impl GpuGraph {
         self.vram_graph.push(percent);
     }
 
+    /// GPU/VRAM 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self.vram_graph = self.vram_graph.with_history_size(size);
+        self
+    }
+
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
+        self.vram_graph.set_visible_samples(samples);
+    }
+
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+        self.vram_graph.reset_peak();
+    }
+
+    /// GPU 사용률 샘플 평활화 방식 지정 (VRAM은 원본 유지)
+    pub fn with_smoothing(mut self, mode: SmoothingMode) -> Self {
+        self.graph = self.graph.with_smoothing(mode);
+        self
+    }
+
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
+
+    pub fn vram_recorded_history(&self) -> &[f64] {
+        self.vram_graph.recorded_history()
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+        self.vram_graph.apply_theme(theme);
+    }
+
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        self.graph.set_thresholds(low, high);
+        self.vram_graph.set_thresholds(low, high);
+    }
+
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         self.graph.render(frame, area);
     }
@@ -211,260 +709,546 @@ impl Default for GpuGraph {
     }
 }
 
-/// 메모리 그래프
-pub struct MemoryGraph {
+/// GPU 온도 그래프 (0 ~ 120°C)
+pub struct GpuTempGraph {
     graph: UsageGraph,
-    used_gb: f64,
-    total_gb: f64,
 }
 
-impl MemoryGraph {
+impl GpuTempGraph {
     pub fn new() -> Self {
-        Self {
-            graph: UsageGraph::new("Memory"),
-            used_gb: 0.0,
-            total_gb: 0.0,
-        }
+        let mut graph = UsageGraph::new("GPU Temp");
+        graph.set_y_bounds(0.0, 120.0);
+        graph.set_y_labels("0", "60", "120");
+        graph.set_unit("°C");
+        graph.set_thresholds(75.0, 90.0);
+        Self { graph }
     }
 
-    pub fn push(&mut self, used_bytes: u64, total_bytes: u64) {
-        self.used_gb = used_bytes as f64 / 1024.0 / 1024.0 / 1024.0;
-        self.total_gb = total_bytes as f64 / 1024.0 / 1024.0 / 1024.0;
-        let percent = if total_bytes > 0 {
-            (used_bytes as f64 / total_bytes as f64) * 100.0
-        } else {
-            0.0
-        };
-        self.graph.push(percent);
+    /// 제목에 장치 이름 표시
+    pub fn set_device_name(&mut self, name: &str) {
+        self.graph.title = format!("GPU Temp {}", name);
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
-        // 데이터를 (x, y) 형태로 변환
-        let data: Vec<(f64, f64)> = self
-            .graph
-            .history
-            .iter()
-            .enumerate()
-            .map(|(i, &v)| (i as f64, v))
-            .collect();
+    pub fn push(&mut self, celsius: f64) {
+        self.graph.push(celsius);
+    }
 
-        let current = self.graph.get_current();
-        let color = match current as u32 {
-            0..=50 => Color::Green,
-            51..=75 => Color::Yellow,
-            _ => Color::Red,
-        };
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self
+    }
 
-        let datasets = vec![Dataset::default()
-            .name(format!("{:.1}GB / {:.1}GB ({:.1}%)", self.used_gb, self.total_gb, current))
-            .marker(symbols::Marker::Braille)
-            .graph_type(GraphType::Line)
-            .style(Style::default().fg(color))
-            .data(&data)];
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
+    }
 
-        let chart = Chart::new(datasets)
-            .block(
-                Block::default()
-                    .title(Span::styled(
-                        "Memory",
-                        Style::default().fg(Color::Cyan).bold(),
-                    ))
-                    .borders(Borders::ALL),
-            )
-            .x_axis(
-                Axis::default()
-                    .bounds([0.0, HISTORY_SIZE as f64]),
-            )
-            .y_axis(
-                Axis::default()
-                    .bounds([0.0, 100.0])
-                    .labels(vec![
-                        Span::raw("0"),
-                        Span::raw("50"),
-                        Span::raw("100"),
-                    ]),
-            );
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+    }
 
-        frame.render_widget(chart, area);
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        self.graph.render(frame, area);
     }
 }
 
-impl Default for MemoryGraph {
+impl Default for GpuTempGraph {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// 개별 코어/항목용 그래프 컴포넌트 (재사용 가능)
-pub struct CoreGraph {
-    title: String,
-    history: Vec<f64>,
+/// GPU 그래픽 클럭 그래프 (0 ~ 최대 클럭 MHz)
+pub struct GpuClockGraph {
+    graph: UsageGraph,
 }
 
-impl CoreGraph {
-    pub fn new(title: impl Into<String>) -> Self {
-        Self {
-            title: title.into(),
-            history: vec![0.0; HISTORY_SIZE],
-        }
+impl GpuClockGraph {
+    /// 최대 클럭을 모르면 3000MHz 기준
+    const DEFAULT_MAX_MHZ: f64 = 3000.0;
+
+    pub fn new() -> Self {
+        let mut graph = UsageGraph::new("GPU Clock");
+        graph.set_unit(" MHz");
+        // 클럭은 높다고 문제가 아니므로 색상 구분 없음
+        graph.set_thresholds(f64::INFINITY, f64::INFINITY);
+        let mut clock_graph = Self { graph };
+        clock_graph.set_max_clock(Self::DEFAULT_MAX_MHZ);
+        clock_graph
     }
 
-    /// 새 데이터 추가 (0.0 ~ 100.0)
-    pub fn push(&mut self, percent: f64) {
-        self.history.remove(0);
-        self.history.push(percent.clamp(0.0, 100.0));
+    /// 제목에 장치 이름 표시
+    pub fn set_device_name(&mut self, name: &str) {
+        self.graph.title = format!("GPU Clock {}", name);
     }
 
-    pub fn current(&self) -> f64 {
-        *self.history.last().unwrap_or(&0.0)
+    /// y축 범위를 최대 클럭에 맞춤
+    pub fn set_max_clock(&mut self, max_mhz: f64) {
+        let max = max_mhz.max(1.0);
+        self.graph.set_y_bounds(0.0, max);
+        self.graph
+            .set_y_labels("0", format!("{:.0}", max / 2.0), format!("{:.0}MHz", max));
     }
 
-    pub fn color(&self) -> Color {
-        match self.current() as u32 {
-            0..=50 => Color::Green,
-            51..=75 => Color::Yellow,
-            _ => Color::Red,
-        }
+    pub fn push(&mut self, mhz: f64) {
+        self.graph.push(mhz);
     }
 
-    /// 게이지 모드로 렌더링
-    pub fn render_gauge(&self, frame: &mut Frame, area: Rect) {
-        let gauge = Gauge::default()
-            .block(
-                Block::default()
-                    .title(self.title.clone())
-                    .borders(Borders::ALL),
-            )
-            .gauge_style(Style::default().fg(self.color()))
-            .percent(self.current() as u16)
-            .label(format!("{:.1}%", self.current()));
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self
+    }
 
-        frame.render_widget(gauge, area);
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
     }
 
-    /// 그래프 모드로 렌더링
-    pub fn render_graph(&self, frame: &mut Frame, area: Rect) {
-        let data: Vec<(f64, f64)> = self
-            .history
-            .iter()
-            .enumerate()
-            .map(|(i, &v)| (i as f64, v))
-            .collect();
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+    }
 
-        let datasets = vec![Dataset::default()
-            .name(format!("{:.1}%", self.current()))
-            .marker(symbols::Marker::Braille)
-            .graph_type(GraphType::Line)
-            .style(Style::default().fg(self.color()))
-            .data(&data)];
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
 
-        let chart = Chart::new(datasets)
-            .block(
-                Block::default()
-                    .title(Span::styled(
-                        self.title.clone(),
-                        Style::default().fg(Color::Cyan),
-                    ))
-                    .borders(Borders::ALL),
-            )
-            .x_axis(Axis::default().bounds([0.0, HISTORY_SIZE as f64]))
-            .y_axis(
-                Axis::default()
-                    .bounds([0.0, 100.0])
-                    .labels(vec![Span::raw("0"), Span::raw("50"), Span::raw("100")]),
-            );
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+    }
 
-        frame.render_widget(chart, area);
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        self.graph.render(frame, area);
     }
 }
 
-impl Default for CoreGraph {
+impl Default for GpuClockGraph {
     fn default() -> Self {
-        Self::new("Core")
+        Self::new()
     }
 }
 
-/// CPU 사용량 전용 게이지
-pub struct CpuGauge {
-    gauge: UsageGauge,
+/// CPU 온도 그래프 (0 ~ 120°C)
+pub struct TemperatureGraph {
+    graph: UsageGraph,
+    /// 온도 센서 데이터를 받은 적이 있는지 여부
+    available: bool,
 }
 
-impl CpuGauge {
+impl TemperatureGraph {
     pub fn new() -> Self {
+        let mut graph = UsageGraph::new("CPU Temp");
+        graph.set_y_bounds(0.0, 120.0);
+        graph.set_y_labels("0", "60", "120");
+        graph.set_unit("°C");
+        graph.set_thresholds(80.0, 95.0);
         Self {
-            gauge: UsageGauge::new("CPU"),
+            graph,
+            available: false,
         }
     }
 
-    pub fn set_usage(&mut self, percent: f64) {
-        self.gauge.set_usage(percent);
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.graph.title = title.into();
+    }
+
+    /// 새 온도 추가 (°C), None이면 센서 데이터 없음
+    pub fn push(&mut self, celsius: Option<f64>) {
+        if let Some(celsius) = celsius {
+            self.graph.push(celsius);
+            self.available = true;
+        }
+    }
+
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self
+    }
+
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
+    }
+
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+    }
+
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect) {
-        self.gauge.render(frame, area);
+        if self.available {
+            self.graph.render(frame, area);
+            return;
+        }
+
+        let placeholder = Paragraph::new("Temp data unavailable")
+            .style(Style::default().fg(Color::Gray))
+            .centered()
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        self.graph.title.clone(),
+                        Style::default().fg(self.graph.theme.title).bold(),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.graph.theme.border)),
+            );
+        frame.render_widget(placeholder, area);
     }
 }
 
-impl Default for CpuGauge {
+impl Default for TemperatureGraph {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// GPU 사용량 전용 게이지
-pub struct GpuGauge {
-    gauge: UsageGauge,
-    vram_gauge: UsageGauge,
+/// VRChat 프레임레이트 그래프 (0 ~ 120 FPS, 45 미만 노랑, 30 미만 빨강)
+pub struct FpsGraph {
+    graph: UsageGraph,
 }
 
-impl GpuGauge {
+impl FpsGraph {
     pub fn new() -> Self {
-        Self {
-            gauge: UsageGauge::new("GPU"),
-            vram_gauge: UsageGauge::new("VRAM"),
-        }
+        let mut graph = UsageGraph::new("VRChat FPS");
+        graph.set_y_bounds(0.0, 120.0);
+        graph.set_y_labels("0", "60", "120");
+        graph.set_unit(" FPS");
+        graph.set_thresholds(30.0, 45.0);
+        graph.set_higher_is_better(true);
+        // 로그는 1초 간격으로 몰아서 읽으므로 공백 판정을 느슨하게
+        graph.set_expected_interval(Duration::from_secs(10));
+        Self { graph }
     }
 
-    pub fn set_usage(&mut self, percent: f64) {
-        self.gauge.set_usage(percent);
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self
     }
 
-    pub fn set_vram_usage(&mut self, percent: f64) {
-        self.vram_gauge.set_usage(percent);
+    pub fn push(&mut self, fps: f64) {
+        self.graph.push(fps);
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
-        self.gauge.render(frame, area);
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
     }
 
-    pub fn render_vram(&self, frame: &mut Frame, area: Rect) {
-        self.vram_gauge.render(frame, area);
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        self.graph.render(frame, area);
     }
 }
 
-impl Default for GpuGauge {
+impl Default for FpsGraph {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// 메모리 사용량 게이지
-pub struct MemoryGauge {
-    gauge: UsageGauge,
+/// CPU 소비 전력 그래프 (0 ~ TDP W)
+pub struct PowerUsageGraph {
+    graph: UsageGraph,
+}
+
+impl PowerUsageGraph {
+    pub fn new(tdp_watts: f64) -> Self {
+        let mut graph = UsageGraph::new("CPU Power");
+        graph.set_unit("W");
+        Self::apply_tdp(&mut graph, tdp_watts);
+        Self { graph }
+    }
+
+    /// y축 범위와 색상 기준값을 TDP에 맞춤 (50% / 75%)
+    fn apply_tdp(graph: &mut UsageGraph, tdp_watts: f64) {
+        let tdp = tdp_watts.max(1.0);
+        graph.set_y_bounds(0.0, tdp);
+        graph.set_y_labels("0", format!("{:.0}", tdp / 2.0), format!("{:.0}W", tdp));
+        graph.set_thresholds(tdp * 0.5, tdp * 0.75);
+    }
+
+    pub fn set_tdp(&mut self, tdp_watts: f64) {
+        Self::apply_tdp(&mut self.graph, tdp_watts);
+    }
+
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self
+    }
+
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
+    }
+
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+    }
+
+    /// 패키지 전력 추가, 범례에는 코어 전력도 표시
+    pub fn push(&mut self, package_watts: f64, core_watts: f64) {
+        self.graph.push(package_watts);
+        self.graph.set_label(Some(format!(
+            "{:.1}W (core {:.1}W)",
+            package_watts, core_watts
+        )));
+    }
+
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        self.graph.render(frame, area);
+    }
+}
+
+/// 초당 발생 횟수 그래프 (y축은 최근 최댓값의 1.2배로 자동 조정)
+pub struct RateGraph {
+    graph: UsageGraph,
+}
+
+impl RateGraph {
+    pub fn new(title: impl Into<String>) -> Self {
+        let mut graph = UsageGraph::new(title);
+        graph.set_unit("/s");
+        // 적정 범위가 정해져 있지 않으므로 색상 구분 없음
+        graph.set_thresholds(f64::INFINITY, f64::INFINITY);
+        let mut rate_graph = Self { graph };
+        rate_graph.rescale(1.0);
+        rate_graph
+    }
+
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self
+    }
+
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
+    }
+
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+    }
+
+    /// y축 범위를 [0, max_seen * 1.2]로 맞추고 레이블 갱신
+    fn rescale(&mut self, max_seen: f64) {
+        let top = max_seen.max(1.0) * 1.2;
+        self.graph.set_y_bounds(0.0, top);
+        self.graph
+            .set_y_labels("0", format_count(top / 2.0), format_count(top));
+    }
+
+    /// 새 값 추가 - push 전에 범위를 넓혀야 값이 잘리지 않음
+    pub fn push(&mut self, per_sec: f64) {
+        let max_seen = self
+            .graph
+            .history()
+            .iter()
+            .fold(per_sec.max(0.0), |acc, &v| acc.max(v));
+        self.rescale(max_seen);
+        self.graph.push(per_sec.max(0.0));
+        self.graph
+            .set_label(Some(format!("{}/s", format_count(per_sec))));
+    }
+
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        self.graph.render(frame, area);
+    }
+}
+
+/// 높이가 낮은 패널용 한 줄 제목 + 스파크라인 (0 ~ 100)
+pub struct SparklineGraph {
+    title: String,
+    history: RingBuffer<u64>,
+    current: f64,
+    /// 제목 옆 문구 (None이면 현재 값 %)
+    label: Option<String>,
+    theme: Theme,
+    low_threshold: f64,
+    high_threshold: f64,
+    /// 표시할 최근 샘플 수 (None이면 히스토리 전체)
+    visible_samples: Option<usize>,
+}
+
+impl SparklineGraph {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            history: RingBuffer::filled(0, HISTORY_SIZE),
+            current: 0.0,
+            label: None,
+            theme: Theme::default(),
+            low_threshold: 50.0,
+            high_threshold: 75.0,
+            visible_samples: None,
+        }
+    }
+
+    /// 히스토리 길이 설정 (기존 히스토리는 버림)
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.history = RingBuffer::filled(0, size.max(1));
+        self
+    }
+
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.visible_samples = Some(samples.max(1));
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.theme = theme.clone();
+    }
+
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        self.low_threshold = low;
+        self.high_threshold = high;
+    }
+
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
+    }
+
+    /// 새 데이터 추가 (Sparkline은 u64만 받으므로 반올림해서 저장)
+    pub fn push(&mut self, percent: f64) {
+        self.current = percent.clamp(0.0, 100.0);
+        self.history.push(self.current.round() as u64);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let visible = self.visible_samples.unwrap_or(usize::MAX);
+        let data = tail(self.history.as_slice(), visible);
+        let label = self
+            .label
+            .clone()
+            .unwrap_or_else(|| format!("{:.1}%", self.current));
+
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!("{} {}", self.title, label),
+                        Style::default().fg(self.theme.title).bold(),
+                    ))
+                    .borders(Borders::TOP)
+                    .border_style(Style::default().fg(self.theme.border)),
+            )
+            .data(data)
+            .max(100)
+            .style(Style::default().fg(self.theme.level_color(
+                self.current,
+                self.low_threshold,
+                self.high_threshold,
+            )));
+
+        frame.render_widget(sparkline, area);
+    }
+}
+
+/// 메모리 그래프
+pub struct MemoryGraph {
+    graph: UsageGraph,
+    /// 높이가 `COMPACT_HEIGHT` 미만일 때 대신 그리는 스파크라인
+    sparkline: SparklineGraph,
     used_gb: f64,
     total_gb: f64,
+    /// 캐시/버퍼/회수 가능 슬랩 히스토리 (% - `push_cache`를 호출한 경우에만 채워짐)
+    cache_history: RingBuffer<f64>,
+    /// 직전 샘플의 물리 메모리 사용량 (bytes)
+    last_used: Option<u64>,
+    /// 사용량이 줄지 않고 이어진 샘플 수와 그 시작 값 (사용량이 줄면 초기화)
+    growth_samples: usize,
+    growth_start: u64,
+    /// 누수 경고를 띄울 연속 증가 샘플 수 (0이면 사용 안 함)
+    leak_window: usize,
+    /// 같은 차트에 겹쳐 그릴 스왑 사용률 (`push_swap`을 호출한 경우에만 채워짐)
+    swap: SwapGraph,
+    /// 최근 커밋 정보 (Windows 전용)
+    #[cfg(target_os = "windows")]
+    commit: Option<WindowsMemoryInfo>,
+    /// 커밋 사용량 히스토리 (GB)
+    #[cfg(target_os = "windows")]
+    commit_history: RingBuffer<f64>,
 }
 
-impl MemoryGauge {
+impl MemoryGraph {
+    /// 차트를 그리기에는 너무 낮은 높이 (이 미만이면 스파크라인)
+    const COMPACT_HEIGHT: u16 = 5;
+
     pub fn new() -> Self {
         Self {
-            gauge: UsageGauge::new("Memory"),
+            graph: UsageGraph::new("Memory"),
+            sparkline: SparklineGraph::new("Memory"),
             used_gb: 0.0,
             total_gb: 0.0,
+            cache_history: RingBuffer::filled(0.0, HISTORY_SIZE),
+            last_used: None,
+            growth_samples: 0,
+            growth_start: 0,
+            leak_window: 0,
+            swap: SwapGraph::new(),
+            #[cfg(target_os = "windows")]
+            commit: None,
+            #[cfg(target_os = "windows")]
+            commit_history: RingBuffer::new(HISTORY_SIZE),
         }
     }
 
-    pub fn set_usage(&mut self, used_bytes: u64, total_bytes: u64) {
+    /// 커밋 사용량 추가 (메모리 push와 같은 주기로 호출)
+    #[cfg(target_os = "windows")]
+    pub fn push_commit(&mut self, info: &WindowsMemoryInfo) {
+        self.commit = Some(*info);
+        self.commit_history.push(info.commit_total as f64 / 1024.0 / 1024.0 / 1024.0);
+    }
+
+    pub fn push(&mut self, used_bytes: u64, total_bytes: u64) {
+        match self.last_used {
+            Some(last) if used_bytes >= last => self.growth_samples += 1,
+            _ => {
+                self.growth_samples = 1;
+                self.growth_start = used_bytes;
+            }
+        }
+        self.last_used = Some(used_bytes);
+
         self.used_gb = used_bytes as f64 / 1024.0 / 1024.0 / 1024.0;
         self.total_gb = total_bytes as f64 / 1024.0 / 1024.0 / 1024.0;
         let percent = if total_bytes > 0 {
@@ -472,33 +1256,1146 @@ impl MemoryGauge {
         } else {
             0.0
         };
-        self.gauge.set_usage(percent);
+        self.graph.push(percent);
+        self.sparkline.push(percent);
+        self.sparkline.set_label(Some(format!(
+            "{:.1}GB / {:.1}GB ({:.1}%)",
+            self.used_gb, self.total_gb, percent
+        )));
+    }
+
+    /// 최근 `window`개 샘플 동안 사용량이 한 번도 줄지 않고 늘었는지 (누수 의심)
+    ///
+    /// 사용량이 그대로인 경우는 제외하기 위해 구간 시작보다 실제로 커졌을 때만 true
+    pub fn check_monotonic_growth(&self, window: usize) -> bool {
+        window > 0
+            && self.growth_samples >= window
+            && self.last_used.is_some_and(|used| used > self.growth_start)
+    }
+
+    /// 제목에 누수 경고를 표시할 연속 증가 샘플 수 (0이면 표시 안 함)
+    pub fn set_leak_window(&mut self, window: usize) {
+        self.leak_window = window;
+    }
+
+    /// 누수가 의심되면 빨간 경고를 붙인 제목
+    fn title_line(&self, title: &'static str) -> Line<'static> {
+        let mut spans = vec![Span::styled(
+            title,
+            Style::default().fg(self.graph.theme.title).bold(),
+        )];
+        if self.check_monotonic_growth(self.leak_window) {
+            spans.push(Span::styled(
+                " ⚠ Potential memory leak",
+                Style::default().fg(Color::Red).bold(),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// 스왑 사용량 추가 (`push` 직후 같은 주기로 호출)
+    pub fn push_swap(&mut self, used_bytes: u64, total_bytes: u64) {
+        self.swap.push(used_bytes, total_bytes);
+    }
+
+    /// 캐시로 쓰이는 메모리 추가 (`push` 직후 같은 주기로 호출)
+    pub fn push_cache(&mut self, cache_bytes: u64, total_bytes: u64) {
+        let percent = if total_bytes > 0 {
+            cache_bytes as f64 / total_bytes as f64 * 100.0
+        } else {
+            0.0
+        };
+        self.cache_history.push(percent);
+    }
+
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self.sparkline = self.sparkline.with_history_size(size);
+        self.swap = self.swap.with_history_size(size);
+        self.cache_history = RingBuffer::filled(0.0, size.max(1));
+        #[cfg(target_os = "windows")]
+        {
+            self.commit_history = RingBuffer::new(size.max(1));
+        }
+        self
+    }
+
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
+        self.sparkline.set_visible_samples(samples);
+        self.swap.set_visible_samples(samples);
+    }
+
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+    }
+
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+        self.sparkline.apply_theme(theme);
+    }
+
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        self.graph.set_thresholds(low, high);
+        self.sparkline.set_thresholds(low, high);
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect) {
-        let gauge = Gauge::default()
+        if area.height < Self::COMPACT_HEIGHT {
+            self.sparkline.render(frame, area);
+            return;
+        }
+
+        #[cfg(target_os = "windows")]
+        if let Some(commit) = self.commit {
+            self.render_with_commit(frame, area, &commit);
+            return;
+        }
+
+        // 데이터를 공백 기준 연속 구간의 (x, y) 형태로 변환
+        let (segments, gaps) = split_segments(self.graph.visible_history());
+
+        let current = self.graph.get_current();
+        let color = self.graph.color_for(current);
+
+        let mut datasets = segment_datasets(
+            &segments,
+            format!("{:.1}GB / {:.1}GB ({:.1}%)", self.used_gb, self.total_gb, current),
+            color,
+        );
+        // 스왑이 없는 시스템에서는 스왑 데이터셋을 생략
+        let swap_data: Vec<(f64, f64)> = self
+            .swap
+            .graph
+            .visible_history()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !v.is_nan())
+            .map(|(i, &v)| (i as f64, v))
+            .collect();
+        if self.swap.total_gb > 0.0 {
+            // 점 마커의 산점도로 점선처럼 표시
+            datasets.push(
+                Dataset::default()
+                    .name(format!(
+                        "{:.1}GB / {:.1}GB swap ({:.1}%)",
+                        self.swap.used_gb,
+                        self.swap.total_gb,
+                        self.swap.graph.get_current()
+                    ))
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::Scatter)
+                    .style(Style::default().fg(Color::Magenta))
+                    .data(&swap_data),
+            );
+        }
+
+        let chart = Chart::new(datasets)
             .block(
                 Block::default()
-                    .title(format!(
-                        "Memory ({:.1} / {:.1} GB)",
-                        self.used_gb, self.total_gb
-                    ))
-                    .borders(Borders::ALL),
+                    .title(self.title_line("Memory"))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.graph.theme.border)),
             )
-            .gauge_style(Style::default().fg(match self.gauge.get_usage() as u32 {
-                0..=50 => Color::Green,
-                51..=75 => Color::Yellow,
-                _ => Color::Red,
-            }))
-            .percent(self.gauge.get_usage() as u16)
-            .label(format!("{:.1}%", self.gauge.get_usage()));
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, self.graph.visible_len() as f64]),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("RAM / Swap %")
+                    .bounds([0.0, 100.0])
+                    .labels(axis_labels(
+                        "0",
+                        "50",
+                        "100",
+                        self.graph.axis_label_style,
+                        self.graph.axis_label_bold_style,
+                    )),
+            );
 
-        frame.render_widget(gauge, area);
+        frame.render_widget(chart, area);
+        render_gap_markers(frame, area, &gaps, self.graph.visible_len(), 3);
+    }
+
+    /// 애플리케이션 사용량 위에 캐시를 쌓아 올린 영역 차트
+    ///
+    /// 사용량(`push`)은 캐시를 제외한 값이므로 위쪽 선은 사용량 + 캐시.
+    /// 막대형 데이터셋을 큰 것부터 그려 아래 영역이 위 영역을 덮도록 함
+    pub fn render_stacked(&self, frame: &mut Frame, area: Rect) {
+        if area.height < Self::COMPACT_HEIGHT {
+            self.sparkline.render(frame, area);
+            return;
+        }
+
+        let capacity = self.graph.visible_len();
+        let apps: Vec<(f64, f64)> = self
+            .graph
+            .visible_history()
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !v.is_nan())
+            .map(|(i, &v)| (i as f64, v))
+            .collect();
+        // 캐시 히스토리는 최신 값이 오른쪽 끝에 오도록 정렬
+        let cache_history = tail(self.cache_history.as_slice(), capacity);
+        let offset = capacity - cache_history.len();
+        let with_cache: Vec<(f64, f64)> = apps
+            .iter()
+            .map(|&(x, used)| {
+                let cache = (x as usize)
+                    .checked_sub(offset)
+                    .and_then(|i| cache_history.get(i))
+                    .copied()
+                    .unwrap_or(0.0);
+                (x, (used + cache).min(100.0))
+            })
+            .collect();
+
+        let current = self.graph.get_current();
+        let cache = self.cache_history.last().copied().unwrap_or(0.0);
+        let datasets = vec![
+            Dataset::default()
+                .name(format!("Cache {:.1}GB", cache / 100.0 * self.total_gb))
+                .marker(symbols::Marker::HalfBlock)
+                .graph_type(GraphType::Bar)
+                .style(Style::default().fg(Color::Blue))
+                .data(&with_cache),
+            Dataset::default()
+                .name(format!(
+                    "Apps {:.1}GB / {:.1}GB ({:.1}%)",
+                    self.used_gb, self.total_gb, current
+                ))
+                .marker(symbols::Marker::HalfBlock)
+                .graph_type(GraphType::Bar)
+                .style(Style::default().fg(self.graph.color_for(current)))
+                .data(&apps),
+        ];
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(self.title_line("Memory (stacked)"))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.graph.theme.border)),
+            )
+            .x_axis(Axis::default().bounds([0.0, capacity as f64]))
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 100.0])
+                    .labels(axis_labels(
+                        "0",
+                        "50",
+                        "100",
+                        self.graph.axis_label_style,
+                        self.graph.axis_label_bold_style,
+                    )),
+            );
+        frame.render_widget(chart, area);
+    }
+
+    /// 물리 메모리 사용량과 커밋 사용량을 GB 단위로 함께 표시
+    /// (y축은 물리 메모리와 커밋 한도 중 큰 값)
+    #[cfg(target_os = "windows")]
+    fn render_with_commit(&self, frame: &mut Frame, area: Rect, commit: &WindowsMemoryInfo) {
+        const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+        let limit_gb = commit.commit_limit as f64 / GB;
+        let max_gb = self.total_gb.max(limit_gb).max(1.0);
+
+        // 히스토리는 % 단위이므로 GB로 환산
+        let (mut segments, gaps) = split_segments(self.graph.visible_history());
+        for point in segments.iter_mut().flatten() {
+            point.1 = point.1 / 100.0 * self.total_gb;
+        }
+        // 커밋 히스토리는 최신 값이 오른쪽 끝에 오도록 정렬
+        let commit_history = tail(self.commit_history.as_slice(), self.graph.visible_len());
+        let offset = self.graph.visible_len() - commit_history.len();
+        let commit_points: Vec<(f64, f64)> = commit_history
+            .iter()
+            .enumerate()
+            .map(|(i, &gb)| ((offset + i) as f64, gb))
+            .collect();
+
+        let current = self.graph.get_current();
+        let mut datasets = segment_datasets(
+            &segments,
+            format!("{:.1}GB / {:.1}GB ({:.1}%)", self.used_gb, self.total_gb, current),
+            self.graph.color_for(current),
+        );
+        datasets.push(
+            Dataset::default()
+                .name(format!(
+                    "Commit {:.1}GB / {:.1}GB (peak {:.1}GB)",
+                    commit.commit_total as f64 / GB,
+                    limit_gb,
+                    commit.commit_peak as f64 / GB
+                ))
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Magenta))
+                .data(&commit_points),
+        );
+
+        let max_label = format!("{:.0}GB", max_gb);
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(self.title_line("Memory"))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.graph.theme.border)),
+            )
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, self.graph.visible_len() as f64]),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, max_gb])
+                    .labels(axis_labels(
+                        "0",
+                        format!("{:.0}", max_gb / 2.0),
+                        max_label.clone(),
+                        self.graph.axis_label_style,
+                        self.graph.axis_label_bold_style,
+                    )),
+            );
+
+        frame.render_widget(chart, area);
+        render_gap_markers(
+            frame,
+            area,
+            &gaps,
+            self.graph.visible_len(),
+            max_label.chars().count() as u16,
+        );
     }
 }
 
-impl Default for MemoryGauge {
+impl Default for MemoryGraph {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// 스왑 그래프
+pub struct SwapGraph {
+    graph: UsageGraph,
+    used_gb: f64,
+    total_gb: f64,
+}
+
+impl SwapGraph {
+    pub fn new() -> Self {
+        Self {
+            graph: UsageGraph::new("Swap"),
+            used_gb: 0.0,
+            total_gb: 0.0,
+        }
+    }
+
+    pub fn push(&mut self, used_bytes: u64, total_bytes: u64) {
+        self.used_gb = used_bytes as f64 / 1024.0 / 1024.0 / 1024.0;
+        self.total_gb = total_bytes as f64 / 1024.0 / 1024.0 / 1024.0;
+        let percent = if total_bytes > 0 {
+            (used_bytes as f64 / total_bytes as f64) * 100.0
+        } else {
+            0.0
+        };
+        self.graph.push(percent);
+    }
+
+    /// 히스토리 길이 설정
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.graph = self.graph.with_history_size(size);
+        self
+    }
+
+    /// 최근 `samples`개만 표시
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.graph.set_visible_samples(samples);
+    }
+
+    /// 최고값 표시 초기화
+    pub fn reset_peak(&mut self) {
+        self.graph.reset_peak();
+    }
+
+    pub fn recorded_history(&self) -> &[f64] {
+        self.graph.recorded_history()
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+    }
+
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        self.graph.set_thresholds(low, high);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let title = Span::styled("Swap", Style::default().fg(self.graph.theme.title).bold());
+        let border_style = Style::default().fg(self.graph.theme.border);
+
+        // 스왑이 없으면 빈 그래프 대신 안내 문구 표시
+        if self.total_gb == 0.0 {
+            let placeholder = Paragraph::new("No swap configured")
+                .style(Style::default().fg(Color::Gray))
+                .centered()
+                .block(
+                    Block::default()
+                        .title(title)
+                        .borders(Borders::ALL)
+                        .border_style(border_style),
+                );
+            frame.render_widget(placeholder, area);
+            return;
+        }
+
+        let (segments, gaps) = split_segments(self.graph.visible_history());
+
+        let current = self.graph.get_current();
+        let datasets = segment_datasets(
+            &segments,
+            format!("{:.1}GB / {:.1}GB swap", self.used_gb, self.total_gb),
+            self.graph.color_for(current),
+        );
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(border_style),
+            )
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, self.graph.visible_len() as f64]),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 100.0])
+                    .labels(axis_labels(
+                        "0",
+                        "50",
+                        "100",
+                        self.graph.axis_label_style,
+                        self.graph.axis_label_bold_style,
+                    )),
+            );
+
+        frame.render_widget(chart, area);
+        render_gap_markers(frame, area, &gaps, self.graph.visible_len(), 3);
+    }
+}
+
+impl Default for SwapGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 횟수를 k/M 접미사가 붙은 짧은 문자열로 변환
+pub fn format_count(value: f64) -> String {
+    let value = value.max(0.0);
+    if value >= 1_000_000.0 {
+        format!("{:.1}M", value / 1_000_000.0)
+    } else if value >= 1_000.0 {
+        format!("{:.1}k", value / 1_000.0)
+    } else {
+        format!("{:.0}", value)
+    }
+}
+
+/// 초당 바이트를 읽기 쉬운 단위 문자열로 변환
+pub fn format_rate(bytes_per_sec: f64) -> String {
+    const UNITS: [&str; 4] = ["B/s", "KB/s", "MB/s", "GB/s"];
+    let mut value = bytes_per_sec.max(0.0);
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// 초당 바이트 이중 시리즈 그래프 (네트워크 RX/TX, 디스크 읽기/쓰기 등)
+pub struct ThroughputGraph {
+    title: String,
+    in_history: RingBuffer<f64>,
+    out_history: RingBuffer<f64>,
+    /// 범례에 표시할 시리즈 이름
+    in_label: String,
+    out_label: String,
+    theme: Theme,
+    axis_label_style: Style,
+    axis_label_bold_style: Style,
+    /// 표시할 최근 샘플 수 (None이면 히스토리 전체)
+    visible_samples: Option<usize>,
+}
+
+impl ThroughputGraph {
+    /// `in_label`/`out_label`은 범례의 두 시리즈 이름 (예: "RX", "TX")
+    pub fn new(
+        title: impl Into<String>,
+        in_label: impl Into<String>,
+        out_label: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            in_history: RingBuffer::filled(0.0, HISTORY_SIZE),
+            out_history: RingBuffer::filled(0.0, HISTORY_SIZE),
+            in_label: in_label.into(),
+            out_label: out_label.into(),
+            theme: Theme::default(),
+            axis_label_style: Style::default(),
+            axis_label_bold_style: Style::default(),
+            visible_samples: None,
+        }
+    }
+
+    /// 히스토리 길이 설정 (기존 히스토리는 버림)
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.in_history = RingBuffer::filled(0.0, size.max(1));
+        self.out_history = RingBuffer::filled(0.0, size.max(1));
+        self
+    }
+
+    /// 최근 `samples`개만 표시 (히스토리보다 크면 전체)
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.visible_samples = Some(samples.max(1));
+    }
+
+    fn visible_len(&self) -> usize {
+        let capacity = self.in_history.capacity();
+        self.visible_samples.map_or(capacity, |samples| samples.min(capacity))
+    }
+
+    /// 테마의 색상과 축 레이블 스타일 적용
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.theme = theme.clone();
+        self.axis_label_style = theme.axis_label_style;
+        self.axis_label_bold_style = theme.axis_label_bold_style;
+    }
+
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
+    /// 새 데이터 추가 (초당 바이트)
+    pub fn push(&mut self, in_bytes_per_sec: f64, out_bytes_per_sec: f64) {
+        self.in_history.push(in_bytes_per_sec.max(0.0));
+        self.out_history.push(out_bytes_per_sec.max(0.0));
+    }
+
+    pub fn current_in(&self) -> f64 {
+        *self.in_history.last().unwrap_or(&0.0)
+    }
+
+    pub fn current_out(&self) -> f64 {
+        *self.out_history.last().unwrap_or(&0.0)
+    }
+
+    /// 그래프 렌더링 (y축은 표시 구간의 최댓값에 맞춰 자동 조정)
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let visible = self.visible_len();
+        let in_history = tail(self.in_history.as_slice(), visible);
+        let out_history = tail(self.out_history.as_slice(), visible);
+        let in_data: Vec<(f64, f64)> = in_history
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (i as f64, v))
+            .collect();
+        let out_data: Vec<(f64, f64)> = out_history
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (i as f64, v))
+            .collect();
+
+        // 최소 1KB/s 범위를 유지해 유휴 상태에서 그래프가 튀지 않도록 함
+        let peak = in_history
+            .iter()
+            .chain(out_history.iter())
+            .fold(1024.0_f64, |acc, &v| acc.max(v));
+
+        let datasets = vec![
+            Dataset::default()
+                .name(format!("{} {}", self.in_label, format_rate(self.current_in())))
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Green))
+                .data(&in_data),
+            Dataset::default()
+                .name(format!("{} {}", self.out_label, format_rate(self.current_out())))
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Magenta))
+                .data(&out_data),
+        ];
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        self.title.clone(),
+                        Style::default().fg(self.theme.title).bold(),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.border)),
+            )
+            .x_axis(Axis::default().bounds([0.0, visible as f64]))
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, peak])
+                    .labels(axis_labels(
+                        "0",
+                        format_rate(peak / 2.0),
+                        format_rate(peak),
+                        self.axis_label_style,
+                        self.axis_label_bold_style,
+                    )),
+            );
+
+        frame.render_widget(chart, area);
+    }
+}
+
+
+/// 개별 코어/항목용 그래프 컴포넌트 (재사용 가능)
+pub struct CoreGraph {
+    title: String,
+    history: RingBuffer<f64>,
+    theme: Theme,
+    axis_label_style: Style,
+    axis_label_bold_style: Style,
+    low_threshold: f64,
+    high_threshold: f64,
+    /// 현재 클럭 (MHz)
+    frequency: Option<u64>,
+    /// 클럭 히스토리 (MHz)
+    frequency_history: RingBuffer<f64>,
+    /// 지금까지 관측된 최대 클럭 (그래프 정규화 기준)
+    max_frequency: u64,
+    /// 표시할 최근 샘플 수 (None이면 히스토리 전체)
+    visible_samples: Option<usize>,
+}
+
+impl CoreGraph {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            history: RingBuffer::filled(0.0, HISTORY_SIZE),
+            theme: Theme::default(),
+            axis_label_style: Style::default(),
+            axis_label_bold_style: Style::default(),
+            low_threshold: 50.0,
+            high_threshold: 75.0,
+            frequency: None,
+            frequency_history: RingBuffer::filled(0.0, HISTORY_SIZE),
+            max_frequency: 0,
+            visible_samples: None,
+        }
+    }
+
+    /// 사용량/클럭 히스토리 길이 설정 (기존 히스토리는 버림)
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.history = RingBuffer::filled(0.0, size.max(1));
+        self.frequency_history = RingBuffer::filled(0.0, size.max(1));
+        self
+    }
+
+    /// 최근 `samples`개만 표시 (히스토리보다 크면 전체)
+    pub fn set_visible_samples(&mut self, samples: usize) {
+        self.visible_samples = Some(samples.max(1));
+    }
+
+    fn visible_len(&self) -> usize {
+        let capacity = self.history.capacity();
+        self.visible_samples.map_or(capacity, |samples| samples.min(capacity))
+    }
+
+    /// 현재 클럭 기록 (MHz)
+    pub fn set_frequency(&mut self, mhz: u64) {
+        self.frequency = Some(mhz);
+        self.max_frequency = self.max_frequency.max(mhz);
+        self.frequency_history.push(mhz as f64);
+    }
+
+    pub fn get_frequency(&self) -> Option<u64> {
+        self.frequency
+    }
+
+    /// 색상 변경 기준값 설정
+    pub fn set_thresholds(&mut self, low: f64, high: f64) {
+        self.low_threshold = low;
+        self.high_threshold = high;
+    }
+
+    /// 테마의 색상과 축 레이블 스타일 적용
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.theme = theme.clone();
+        self.axis_label_style = theme.axis_label_style;
+        self.axis_label_bold_style = theme.axis_label_bold_style;
+    }
+
+    /// 새 데이터 추가 (0.0 ~ 100.0)
+    pub fn push(&mut self, percent: f64) {
+        self.history.push(percent.clamp(0.0, 100.0));
+    }
+
+    /// 오래된 것 → 최신 순서의 사용량 히스토리 슬라이스
+    pub fn get_history(&self) -> &[f64] {
+        self.history.as_slice()
+    }
+
+    pub fn current(&self) -> f64 {
+        *self.history.last().unwrap_or(&0.0)
+    }
+
+    /// 히스토리 내 최솟값
+    pub fn min(&self) -> f64 {
+        self.history.iter().copied().fold(f64::INFINITY, f64::min)
+    }
+
+    /// 히스토리 내 최댓값
+    pub fn max(&self) -> f64 {
+        self.history.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    pub fn color(&self) -> Color {
+        self.theme
+            .level_color(self.current(), self.low_threshold, self.high_threshold)
+    }
+
+    /// 게이지 모드로 렌더링
+    pub fn render_gauge(&self, frame: &mut Frame, area: Rect) {
+        let gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .title(self.title.clone())
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.border)),
+            )
+            .gauge_style(Style::default().fg(self.color()))
+            .percent(self.current() as u16)
+            .label(match self.frequency {
+                Some(mhz) => format!("{:.1}% @ {}MHz", self.current(), mhz),
+                None => format!("{:.1}%", self.current()),
+            });
+
+        frame.render_widget(gauge, area);
+    }
+
+    /// 그래프 모드로 렌더링
+    pub fn render_graph(&self, frame: &mut Frame, area: Rect) {
+        let visible = self.visible_len();
+        let data: Vec<(f64, f64)> = tail(self.history.as_slice(), visible)
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (i as f64, v))
+            .collect();
+        // 클럭은 최대 관측값 대비 비율로 같은 0~100 축에 표시
+        let frequency_data: Vec<(f64, f64)> = if self.max_frequency > 0 {
+            tail(self.frequency_history.as_slice(), visible)
+                .iter()
+                .enumerate()
+                .map(|(i, &mhz)| (i as f64, mhz / self.max_frequency as f64 * 100.0))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut datasets = vec![Dataset::default()
+            .name(format!("{:.1}%", self.current()))
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(self.color()))
+            .data(&data)];
+        if let Some(mhz) = self.frequency {
+            // 점 마커의 산점도로 점선처럼 표시
+            datasets.push(
+                Dataset::default()
+                    .name(format!("{}MHz", mhz))
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::Scatter)
+                    .style(Style::default().fg(Color::Cyan))
+                    .data(&frequency_data),
+            );
+        }
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        self.title.clone(),
+                        Style::default().fg(self.theme.title),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.border)),
+            )
+            .x_axis(Axis::default().bounds([0.0, visible as f64]))
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 100.0])
+                    .labels(axis_labels(
+                        "0",
+                        "50",
+                        "100",
+                        self.axis_label_style,
+                        self.axis_label_bold_style,
+                    )),
+            );
+
+        frame.render_widget(chart, area);
+    }
+
+    /// 스파크라인 모드로 렌더링 (코어가 많을 때용 한 줄 제목 + 막대)
+    pub fn render_sparkline(&self, frame: &mut Frame, area: Rect) {
+        let data: Vec<u64> = tail(self.history.as_slice(), self.visible_len())
+            .iter()
+            .map(|&v| v.round() as u64)
+            .collect();
+
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!("{} {:.1}%", self.title, self.current()),
+                        Style::default().fg(self.theme.title),
+                    ))
+                    .borders(Borders::TOP)
+                    .border_style(Style::default().fg(self.theme.border)),
+            )
+            .data(&data)
+            .max(100)
+            .style(Style::default().fg(self.color()));
+
+        frame.render_widget(sparkline, area);
+    }
+}
+
+impl Default for CoreGraph {
+    fn default() -> Self {
+        Self::new("Core")
+    }
+}
+
+/// 코어 하나의 클럭 히스토리와 하드웨어 클럭 범위
+pub struct CoreFrequencyGraph {
+    title: String,
+    /// 클럭 히스토리 (MHz)
+    history: RingBuffer<f64>,
+    /// sysfs에서 읽은 최소/최대 클럭 (없으면 관측값으로 대체)
+    min_mhz: Option<u64>,
+    max_mhz: Option<u64>,
+    /// 지금까지 관측된 최대 클럭
+    observed_max: u64,
+    theme: Theme,
+}
+
+impl CoreFrequencyGraph {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            history: RingBuffer::filled(0.0, HISTORY_SIZE),
+            min_mhz: None,
+            max_mhz: None,
+            observed_max: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    /// 클럭 히스토리 길이 설정 (기존 히스토리는 버림)
+    pub fn with_history_size(mut self, size: usize) -> Self {
+        self.history = RingBuffer::filled(0.0, size.max(1));
+        self
+    }
+
+    /// 하드웨어 클럭 범위 설정
+    pub fn with_bounds(mut self, min_mhz: u64, max_mhz: u64) -> Self {
+        self.min_mhz = Some(min_mhz);
+        self.max_mhz = Some(max_mhz);
+        self
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.theme = theme.clone();
+    }
+
+    /// 현재 클럭 기록 (MHz)
+    pub fn push(&mut self, mhz: u64) {
+        self.observed_max = self.observed_max.max(mhz);
+        self.history.push(mhz as f64);
+    }
+
+    pub fn current(&self) -> u64 {
+        self.history.last().map_or(0, |&mhz| mhz as u64)
+    }
+
+    /// 최소 클럭 (범위를 모르면 0)
+    pub fn min_mhz(&self) -> u64 {
+        self.min_mhz.unwrap_or(0)
+    }
+
+    /// 최대 클럭 (범위를 모르면 관측 최댓값, 부스트로 넘으면 관측값)
+    pub fn max_mhz(&self) -> u64 {
+        self.max_mhz.unwrap_or(0).max(self.observed_max).max(1)
+    }
+
+    /// 한 줄 막대로 렌더링 - 최대 클럭을 배경으로, 최소 클럭까지 얇은 막대, 현재 클럭은 초록색
+    pub fn render_bar(&self, frame: &mut Frame, area: Rect, selected: bool) {
+        let label = format!(
+            "{:<8} {:>5} MHz ({}-{}) ",
+            self.title,
+            self.current(),
+            self.min_mhz(),
+            self.max_mhz()
+        );
+        let label_style = if selected {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default().fg(self.theme.title)
+        };
+        let bar_width = area.width.saturating_sub(label.chars().count() as u16) as usize;
+        let max = self.max_mhz() as f64;
+        let cells = |mhz: u64| ((mhz as f64 / max * bar_width as f64).round() as usize).min(bar_width);
+        let (current_cells, min_cells) = (cells(self.current()), cells(self.min_mhz()));
+
+        let mut spans = vec![Span::styled(label, label_style)];
+        spans.extend((0..bar_width).map(|i| {
+            if i < current_cells {
+                Span::styled("█", Style::default().fg(Color::Green))
+            } else if i < min_cells {
+                Span::styled("▂", Style::default().fg(Color::Gray).bg(Color::DarkGray))
+            } else {
+                Span::styled(" ", Style::default().bg(Color::DarkGray))
+            }
+        }));
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    /// 클럭 히스토리 그래프 (y축은 0 ~ 최대 클럭)
+    pub fn render_graph(&self, frame: &mut Frame, area: Rect) {
+        let data: Vec<(f64, f64)> = self
+            .history
+            .iter()
+            .enumerate()
+            .map(|(i, &mhz)| (i as f64, mhz))
+            .collect();
+        let max = self.max_mhz() as f64;
+        let chart = Chart::new(vec![Dataset::default()
+            .name(format!("{}MHz", self.current()))
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Green))
+            .data(&data)])
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    format!("{} - Frequency", self.title),
+                    Style::default().fg(self.theme.title),
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(self.theme.border)),
+        )
+        .x_axis(Axis::default().bounds([0.0, self.history.capacity() as f64]))
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, max])
+                .labels(axis_labels(
+                    "0",
+                    format!("{}", self.min_mhz()),
+                    format!("{}MHz", self.max_mhz()),
+                    self.theme.axis_label_style,
+                    self.theme.axis_label_bold_style,
+                )),
+        );
+        frame.render_widget(chart, area);
+    }
+}
+
+impl Default for CoreFrequencyGraph {
+    fn default() -> Self {
+        Self::new("Core")
+    }
+}
+
+/// 온도 게이지 (0 ~ 100°C, 70°C 이상 노란색, 90°C 이상 빨간색)
+pub struct TemperatureGauge {
+    gauge: UsageGauge,
+    celsius: Option<f64>,
+}
+
+impl TemperatureGauge {
+    pub fn new(title: impl Into<String>) -> Self {
+        let mut gauge = Self {
+            gauge: UsageGauge::new(title),
+            celsius: None,
+        };
+        gauge.set_temperature(None);
+        gauge
+    }
+
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.gauge.apply_theme(theme);
+        self.gauge.set_color(self.color());
+    }
+
+    /// 현재 온도 설정 (°C), None이면 센서 데이터 없음
+    pub fn set_temperature(&mut self, celsius: Option<f64>) {
+        self.celsius = celsius;
+        // 막대 길이는 0 ~ 100°C 기준, 색상은 사용률 기준값 대신 온도 구간으로
+        self.gauge.set_usage(celsius.unwrap_or(0.0));
+        self.gauge.set_color(self.color());
+    }
+
+    pub fn temperature(&self) -> Option<f64> {
+        self.celsius
+    }
+
+    fn color(&self) -> Color {
+        let theme = &self.gauge.theme;
+        match self.celsius {
+            Some(t) if t >= 90.0 => theme.high,
+            Some(t) if t >= 70.0 => theme.mid,
+            Some(_) => theme.low,
+            None => Color::DarkGray,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let label = match self.celsius {
+            Some(t) => format!("{:.1}°C", t),
+            None => "N/A".to_string(),
+        };
+        self.gauge.render_with_label(frame, area, &label);
+    }
+}
+
+impl Default for TemperatureGauge {
+    fn default() -> Self {
+        Self::new("Temp")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph_peak_tracks_maximum_until_reset() {
+        let mut graph = UsageGraph::new("CPU");
+        for value in [10.0, 90.0, 30.0] {
+            graph.push(value);
+        }
+        assert_eq!(graph.peak(), Some(90.0));
+
+        graph.reset_peak();
+        assert_eq!(graph.peak(), None);
+        graph.push(20.0);
+        assert_eq!(graph.peak(), Some(20.0));
+    }
+
+    #[test]
+    fn memory_leak_check_needs_sustained_growth_and_resets_on_drop() {
+        let mut graph = MemoryGraph::new();
+        for used in [100, 100, 100, 100] {
+            graph.push(used, 1000);
+        }
+        assert!(!graph.check_monotonic_growth(3), "flat usage is not a leak");
+
+        for used in [110, 120, 130] {
+            graph.push(used, 1000);
+        }
+        assert!(graph.check_monotonic_growth(3));
+
+        graph.push(90, 1000);
+        graph.push(95, 1000);
+        assert!(!graph.check_monotonic_growth(3));
+    }
+
+    #[test]
+    fn core_graph_history_slice_stays_in_order_after_wrapping() {
+        let mut graph = CoreGraph::new("CPU 0").with_history_size(3);
+        for value in [10.0, 20.0, 30.0, 40.0, 50.0] {
+            graph.push(value);
+        }
+        assert_eq!(graph.get_history(), &[30.0, 40.0, 50.0]);
+    }
+
+    #[test]
+    fn recorded_history_skips_prefill_and_hidden_samples() {
+        let mut graph = UsageGraph::new("CPU").with_history_size(10);
+        assert!(graph.recorded_history().is_empty());
+
+        graph.push(10.0);
+        graph.push(20.0);
+        graph.push_gap();
+        graph.push(30.0);
+        assert_eq!(graph.history().len(), 10);
+        assert_eq!(graph.recorded_history().len(), 4);
+
+        graph.set_visible_samples(2);
+        let recorded = graph.recorded_history();
+        assert!(recorded[0].is_nan());
+        assert_eq!(recorded[1], 30.0);
+    }
+
+    #[test]
+    fn axis_label_style_is_applied_to_rendered_labels() {
+        use ratatui::{Terminal, backend::TestBackend, style::Modifier};
+
+        let mut graph = UsageGraph::new("CPU");
+        graph.apply_theme(&Theme::default());
+        graph.set_axis_label_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::ITALIC),
+        );
+        graph.push(10.0);
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+        terminal.draw(|frame| graph.render(frame, frame.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+        // 레이블 첫 글자 셀
+        let cell_of = |label: &str| {
+            let width = buffer.area.width;
+            (0..buffer.area.height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .find(|&(x, y)| {
+                    label.chars().enumerate().all(|(i, c)| {
+                        x + (i as u16) < width
+                            && buffer[(x + i as u16, y)].symbol() == c.to_string()
+                    })
+                })
+                .map(|position| buffer[position].clone())
+                .unwrap()
+        };
+
+        let mid = cell_of("50");
+        assert_eq!(mid.fg, Color::Cyan);
+        assert!(mid.modifier.contains(Modifier::ITALIC));
+        assert!(!mid.modifier.contains(Modifier::BOLD));
+
+        let high = cell_of("100");
+        assert_eq!(high.fg, Color::Cyan);
+        assert!(high.modifier.contains(Modifier::ITALIC | Modifier::BOLD));
+    }
+
+    #[test]
+    fn throughput_graph_visible_samples_are_capped_by_history() {
+        let mut graph = ThroughputGraph::new("Network", "RX", "TX").with_history_size(300);
+        assert_eq!(graph.visible_len(), 300);
+
+        graph.set_visible_samples(60);
+        assert_eq!(graph.visible_len(), 60);
+        graph.set_visible_samples(3600);
+        assert_eq!(graph.visible_len(), 300);
+    }
+}
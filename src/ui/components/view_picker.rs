@@ -0,0 +1,103 @@
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// 모달 최대 크기
+const PICKER_WIDTH: u16 = 36;
+const PICKER_MAX_HEIGHT: u16 = 20;
+
+/// 나란히 표시할 두 번째 뷰를 고르는 모달
+pub struct ViewPickerOverlay {
+    /// (뷰 인덱스, 제목)
+    entries: Vec<(usize, String)>,
+    selected: usize,
+    open: bool,
+}
+
+impl ViewPickerOverlay {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            selected: 0,
+            open: false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// 고를 수 있는 뷰 목록으로 모달 열기
+    pub fn open(&mut self, entries: Vec<(usize, String)>) {
+        self.entries = entries;
+        self.selected = 0;
+        self.open = true;
+    }
+
+    /// 키 입력 처리 - Enter로 고르면 뷰 인덱스 반환, Esc나 `\`는 취소
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<usize> {
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.entries.len().saturating_sub(1));
+            }
+            KeyCode::Enter => {
+                self.open = false;
+                return self.entries.get(self.selected).map(|(index, _)| *index);
+            }
+            KeyCode::Esc | KeyCode::Char('\\') => self.open = false,
+            _ => {}
+        }
+        None
+    }
+
+    /// 화면 가운데에 배경을 지우고 렌더링
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|(_, title)| ListItem::new(Line::from(title.as_str())))
+            .collect();
+
+        // 테두리 2줄 포함
+        let width = PICKER_WIDTH.min(area.width);
+        let height = (self.entries.len() as u16 + 2)
+            .min(PICKER_MAX_HEIGHT)
+            .min(area.height);
+        let picker_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .title(" Split with ")
+                    .title_bottom(" Enter: open  Esc: cancel ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            );
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_widget(Clear, picker_area);
+        frame.render_stateful_widget(list, picker_area, &mut state);
+    }
+}
+
+impl Default for ViewPickerOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,35 +1,118 @@
 use std::{
     io::{self, stdout},
+    path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
 use ratatui::{
-    backend::CrosstermBackend,
+    backend::{Backend, CrosstermBackend},
     crossterm::{
-        event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+        event::{
+            self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+            MouseEvent, MouseEventKind,
+        },
         execute,
-        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+        terminal::{
+            disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen, SetTitle,
+        },
     },
-    layout::Rect,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame, Terminal,
 };
 
-use crate::{queues, ui::views::vrchat_page::VrchatPageView};
+use crate::{
+    config::{self, Config, LayoutPreset},
+    controllers::{
+        alert_manager::{Alert, AlertLevel, AlertManager},
+        export::ExportManager,
+        log_exporter::{self, SharedSessionEvents},
+        metrics_collector::{
+            MetricsCollector, SnapshotReader, SystemSnapshot, DEFAULT_COLLECT_INTERVAL,
+        },
+        record_replay::{Recorder, Replayer},
+        snapshot,
+    },
+    integration::{prometheus::PrometheusExporter, vrc_osc::OscReceiver},
+    queues::app_context::AppContext,
+    ui::{
+        components::{
+            CommandPalette, CommandRegistry, FilterBar, HelpOverlay, LayoutPickerAction,
+            LayoutPickerOverlay, TabBar, ViewPickerOverlay,
+        },
+        views::vrchat_page::VrchatPageView,
+    },
+    util::screenshot,
+};
+
+/// 메트릭 알림 검사 간격
+const ALERT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+const ALERT_POPUP_WIDTH: u16 = 40;
+const ALERT_POPUP_HEIGHT: u16 = 5;
+/// 타이틀 상태 메시지 표시 시간
+const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(3);
+/// 화면 아래 토스트 알림 표시 시간
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+/// 세션 요약용 스냅샷 보관 간격과 최대 개수 (24시간)
+const SUMMARY_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_SUMMARY_SAMPLES: usize = 24 * 60 * 60;
+/// 명령 팔레트에서 고를 수 있는 tick 간격 (ms)
+const TICK_RATE_PRESETS_MS: [u64; 4] = [16, 50, 100, 200];
+/// 레이아웃 이름 입력창 폭
+const LAYOUT_NAME_INPUT_WIDTH: u16 = 40;
+/// 화면 맨 위 탭 바 높이
+const TAB_BAR_HEIGHT: u16 = 1;
 
 use super::views::{
+    config_editor::ConfigEditorView,
     cpu_cores::CpuCoresView,
+    cpu_freq::CpuFrequencyView,
+    custom_metrics::CustomMetricsView,
+    debug::{DebugView, SharedAppMetrics},
+    disk_health::DiskHealthView,
+    disk_monitor::DiskMonitorView,
+    disk_tree::DiskUsageTreeView,
+    docker::DockerView,
+    grouped_cpu::GroupedCpuView,
+    histogram::HistogramView,
+    irq::IrqView,
+    memory_breakdown::MemoryBreakdownView,
+    memory_detail::MemoryPressureView,
+    network::NetworkView,
+    network_connections::NetworkConnectionsView,
+    network_latency::NetworkLatencyView,
+    network_monitor::NetworkMonitorView,
+    power::PowerView,
+    process_list::ProcessListView,
+    process_tree::ProcessTreeView,
+    session_stats::{SessionStatsView, SharedDrawProfiler},
     status::StatusView,
+    syslog::SyslogView,
     system_monitor::SystemMonitorView,
-    TickingComponent, ViewComponent,
+    thermal::ThermalView,
+    BackgroundRefreshPolicy, TickingComponent, ViewComponent,
 };
 
-#[derive(Clone)]
-pub enum ViewerMessage {
-}
-
 #[derive(Clone)]
 pub enum ViewerCommand {
+    /// 등록 이름(또는 표시 이름)으로 뷰 전환
+    SwitchView(String),
     Quit,
+    /// 경고 알림 표시
+    Alert(String),
+    /// 새 설정을 모든 뷰에 적용
+    ReloadConfig(Box<Config>),
+}
+
+/// 화면 배치 방식 (`\` 키로 전환)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// 현재 뷰 하나만 표시
+    #[default]
+    Single,
+    /// 왼쪽에 키 입력을 받는 현재 뷰, 오른쪽에 두 번째 뷰
+    SideBySide { primary: usize, secondary: usize },
 }
 
 /// 앱 상태를 관리하는 구조체
@@ -38,54 +121,203 @@ pub struct App {
     current_view: usize,
     /// tick이 필요한 뷰들
     ticking_views: Vec<Box<dyn TickingViewTrait>>,
+    /// 뷰별 마지막 tick 시각 (`ticking_views`와 같은 순서, 아직 tick하지 않았으면 None)
+    last_tick_per_view: Vec<Option<Instant>>,
     /// 종료 플래그
     should_quit: bool,
     /// 화면 클리어 필요 플래그
     needs_clear: bool,
+    /// 현재 적용된 설정
+    config: Config,
+    ctx: AppContext,
+    /// 뷰별 draw 시간 기록
+    draw_profiler: SharedDrawProfiler,
+    alert_manager: AlertManager,
+    /// 알림 팝업 표시 여부 (Space로 닫음)
+    show_alert: bool,
+    /// 알림 검사/내보내기용 스냅샷 읽기 핸들
+    metrics_reader: SnapshotReader,
+    last_alert_check: Instant,
+    /// 임계값 초과 상태 (넘어서는 순간에만 알림)
+    cpu_over_threshold: bool,
+    memory_over_threshold: bool,
+    /// 모든 뷰가 공유하는 시스템 메트릭 수집기
+    metrics: MetricsCollector,
+    /// VRChat OSC 수신기
+    osc_receiver: OscReceiver,
+    /// Prometheus 메트릭 서버 (설정에서 끈 경우 None)
+    prometheus: Option<PrometheusExporter>,
+    exporter: ExportManager,
+    /// `--record`로 지정한 녹화 파일 기록기
+    recorder: Option<Recorder>,
+    /// `--replay` 재생기 (있으면 실시간 수집 대신 녹화 파일을 발행)
+    replayer: Option<Replayer>,
+    /// 터미널 타이틀에 표시할 상태 메시지와 표시 시작 시각
+    status_message: Option<(String, Instant)>,
+    /// 단축키 도움말 표시 여부 (? 키로 토글)
+    show_help: bool,
+    /// run_loop가 기록하는 앱 내부 지표
+    app_metrics: SharedAppMetrics,
+    debug_view: DebugView,
+    /// 디버그 뷰 표시 여부 (! 키로 토글)
+    show_debug: bool,
+    /// 뷰 갱신 일시정지 여부 (P 키로 토글)
+    paused: bool,
+    /// 다음 프레임을 그린 뒤 스크린샷 저장 (Ctrl+S)
+    screenshot_requested: bool,
+    /// 화면 오른쪽 아래에 잠시 표시할 메시지와 표시 시작 시각
+    toast: Option<(String, Instant)>,
+    started_at: Instant,
+    /// 세션 요약용 스냅샷 (`export_summary`가 켜져 있을 때만 기록)
+    summary_history: Vec<SystemSnapshot>,
+    last_summary_sample: Option<Instant>,
+    /// VrchatPageView가 기록하는 VRChat 로그 이벤트
+    session_events: SharedSessionEvents,
+    /// 현재 뷰에 마지막으로 알린 터미널 크기 (다르면 on_resize 호출)
+    last_area: Rect,
+    layout_mode: LayoutMode,
+    /// 나란히 볼 두 번째 뷰 선택 모달
+    view_picker: ViewPickerOverlay,
+    /// `:`로 여는 명령 팔레트와 열 때 만든 명령 목록
+    command_palette: CommandPalette,
+    commands: CommandRegistry,
+    /// run_loop에 적용할 새 tick 간격 (명령 팔레트에서 변경)
+    tick_rate_request: Option<Duration>,
+    /// 저장된 뷰 배치 선택 모달 (L 키)
+    layout_picker: LayoutPickerOverlay,
+    /// 현재 배치를 저장할 이름 입력창 (명령 팔레트의 "save layout as")
+    layout_name_input: Option<FilterBar>,
 }
 
 /// ViewComponent + TickingComponent를 함께 처리하기 위한 trait
 trait TickingViewTrait {
     fn draw_with_area(&self, frame: &mut Frame, area: Rect);
+    fn name(&self) -> &str;
+    fn title(&self) -> &str;
+    fn min_dimensions(&self) -> (u16, u16);
     fn on_tick(&mut self);
-    fn handle_key(&mut self, key: KeyCode) -> bool;
+    fn handle_key(&mut self, key: KeyEvent) -> bool;
+    fn on_config_changed(&mut self, config: &Config);
+    fn on_resize(&mut self, new_area: Rect);
+    fn on_focus(&mut self);
+    fn on_blur(&mut self);
+    fn handle_mouse(&mut self, event: MouseEvent) -> bool;
+    /// 등록 시 지정한 조회용 이름
+    fn key(&self) -> Option<&'static str>;
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)];
     fn needs_tick(&self) -> bool;
+    /// 원하는 tick 간격 (ms)
+    fn preferred_tick_ms(&self) -> u64 {
+        200
+    }
+    /// 화면에 보이지 않을 때도 tick을 받을지
+    fn background_refresh_policy(&self) -> BackgroundRefreshPolicy {
+        BackgroundRefreshPolicy::OnlyWhenVisible
+    }
 }
 
 /// TickingViewTrait 구현체 (tick 있는 뷰)
 struct TickingViewHolder<T: ViewComponent + TickingComponent> {
     inner: T,
+    key: Option<&'static str>,
 }
 
 impl<T: ViewComponent + TickingComponent> TickingViewTrait for TickingViewHolder<T> {
     fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
         self.inner.draw_with_area(frame, area);
     }
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+    fn title(&self) -> &str {
+        self.inner.title()
+    }
+    fn min_dimensions(&self) -> (u16, u16) {
+        self.inner.min_dimensions()
+    }
     fn on_tick(&mut self) {
         self.inner.on_tick();
     }
-    fn handle_key(&mut self, key: KeyCode) -> bool {
-        self.inner.handle_key(key)
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.inner.handle_key_event(key)
+    }
+    fn on_config_changed(&mut self, config: &Config) {
+        self.inner.on_config_changed(config);
+    }
+    fn on_resize(&mut self, new_area: Rect) {
+        self.inner.on_resize(new_area);
+    }
+    fn on_focus(&mut self) {
+        self.inner.on_focus();
+    }
+    fn on_blur(&mut self) {
+        self.inner.on_blur();
+    }
+    fn handle_mouse(&mut self, event: MouseEvent) -> bool {
+        self.inner.handle_mouse(event)
+    }
+    fn key(&self) -> Option<&'static str> {
+        self.key
+    }
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        self.inner.key_bindings()
     }
     fn needs_tick(&self) -> bool {
         true
     }
+    fn preferred_tick_ms(&self) -> u64 {
+        self.inner.preferred_tick_ms()
+    }
+    fn background_refresh_policy(&self) -> BackgroundRefreshPolicy {
+        self.inner.background_refresh_policy()
+    }
 }
 
 /// ViewHolder (tick 없는 뷰)
 struct ViewHolder<T: ViewComponent> {
     inner: T,
+    key: Option<&'static str>,
 }
 
 impl<T: ViewComponent> TickingViewTrait for ViewHolder<T> {
     fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
         self.inner.draw_with_area(frame, area);
     }
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+    fn title(&self) -> &str {
+        self.inner.title()
+    }
+    fn min_dimensions(&self) -> (u16, u16) {
+        self.inner.min_dimensions()
+    }
     fn on_tick(&mut self) {
         // tick 불필요
     }
-    fn handle_key(&mut self, key: KeyCode) -> bool {
-        self.inner.handle_key(key)
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.inner.handle_key_event(key)
+    }
+    fn on_config_changed(&mut self, config: &Config) {
+        self.inner.on_config_changed(config);
+    }
+    fn on_resize(&mut self, new_area: Rect) {
+        self.inner.on_resize(new_area);
+    }
+    fn on_focus(&mut self) {
+        self.inner.on_focus();
+    }
+    fn on_blur(&mut self) {
+        self.inner.on_blur();
+    }
+    fn handle_mouse(&mut self, event: MouseEvent) -> bool {
+        self.inner.handle_mouse(event)
+    }
+    fn key(&self) -> Option<&'static str> {
+        self.key
+    }
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        self.inner.key_bindings()
     }
     fn needs_tick(&self) -> bool {
         false
@@ -93,54 +325,460 @@ impl<T: ViewComponent> TickingViewTrait for ViewHolder<T> {
 }
 
 impl App {
-    pub fn new() -> Self {
+    /// 컨텍스트의 설정과 채널로 앱 생성
+    pub fn new(ctx: AppContext) -> Self {
+        let config = &ctx.config;
+        let metrics = MetricsCollector::start(DEFAULT_COLLECT_INTERVAL, config.gpu_enabled);
+        let app_metrics = SharedAppMetrics::default();
         let mut app = App {
             current_view: 0,
             ticking_views: Vec::new(),
+            last_tick_per_view: Vec::new(),
             should_quit: false,
             needs_clear: true,
+            config: config.clone(),
+            ctx: ctx.clone(),
+            draw_profiler: SharedDrawProfiler::default(),
+            alert_manager: AlertManager::new(),
+            show_alert: false,
+            metrics_reader: metrics.reader(),
+            last_alert_check: Instant::now(),
+            cpu_over_threshold: false,
+            memory_over_threshold: false,
+            metrics,
+            osc_receiver: OscReceiver::start(config.osc_receive_port),
+            prometheus: None,
+            exporter: ExportManager::new(config.export_path.clone()),
+            recorder: None,
+            replayer: None,
+            status_message: None,
+            show_help: false,
+            debug_view: DebugView::with_config(config, app_metrics.clone()),
+            app_metrics,
+            show_debug: false,
+            paused: false,
+            screenshot_requested: false,
+            toast: None,
+            started_at: Instant::now(),
+            summary_history: Vec::new(),
+            last_summary_sample: None,
+            session_events: SharedSessionEvents::default(),
+            last_area: Rect::default(),
+            layout_mode: LayoutMode::Single,
+            view_picker: ViewPickerOverlay::new(),
+            command_palette: CommandPalette::new(),
+            commands: CommandRegistry::new(),
+            tick_rate_request: None,
+            layout_picker: LayoutPickerOverlay::new(),
+            layout_name_input: None,
         };
 
-        // 기본 뷰 등록
-        app.register_ticking_view(StatusView::new());
-        app.register_ticking_view(SystemMonitorView::new());
-        app.register_ticking_view(CpuCoresView::new());
-        app.register_view(VrchatPageView::new());
+        // 포트를 열지 못하면 경고만 표시하고 내보내기 생략
+        if config.prometheus_enabled {
+            let exporter = PrometheusExporter::start(config.prometheus_port, app.metrics.snapshot());
+            if let Some(error) = exporter.error() {
+                app.push_alert(Alert::new(error.to_string(), AlertLevel::Warning));
+            }
+            app.prometheus = Some(exporter);
+        }
 
+        // 기본 뷰 등록
+        let snapshot = app.metrics.snapshot();
+        app.register_ticking_view_with_name("status", StatusView::new(snapshot.clone()));
+        let system_monitor = SystemMonitorView::with_config(config, snapshot.clone())
+            .with_commands(ctx.command_sender());
+        let time_range = system_monitor.time_range();
+        app.register_ticking_view_with_name("system_monitor", system_monitor);
+        app.register_ticking_view_with_name(
+            "cpu_cores",
+            CpuCoresView::with_config(config, snapshot.clone()).with_time_range(time_range),
+        );
+        app.register_ticking_view_with_name(
+            "grouped_cpu",
+            GroupedCpuView::with_config(config, snapshot.clone()),
+        );
+        app.register_ticking_view_with_name(
+            "cpu_freq",
+            CpuFrequencyView::with_config(config, snapshot.clone()),
+        );
+        app.register_ticking_view_with_name("power", PowerView::with_config(config));
+        app.register_ticking_view_with_name(
+            "memory_breakdown",
+            MemoryBreakdownView::new(snapshot.clone()),
+        );
+        app.register_ticking_view_with_name(
+            "memory_pressure",
+            MemoryPressureView::new(snapshot.clone()),
+        );
+        app.register_ticking_view_with_name(
+            "histogram",
+            HistogramView::with_config(config, snapshot.clone()),
+        );
+        app.register_ticking_view_with_name("network", NetworkView::with_config(config));
+        app.register_ticking_view_with_name(
+            "network_monitor",
+            NetworkMonitorView::with_config(config, snapshot.clone()),
+        );
+        app.register_ticking_view_with_name(
+            "network_latency",
+            NetworkLatencyView::with_config(config),
+        );
+        app.register_ticking_view_with_name("connections", NetworkConnectionsView::new());
+        app.register_ticking_view_with_name("disk_monitor", DiskMonitorView::with_config(config));
+        app.register_ticking_view_with_name("disk_health", DiskHealthView::with_config(config));
+        app.register_ticking_view_with_name("disk_tree", DiskUsageTreeView::with_config(config));
+        app.register_ticking_view_with_name("processes", ProcessListView::new(snapshot.clone()));
+        app.register_ticking_view_with_name("process_tree", ProcessTreeView::new(snapshot.clone()));
+        app.register_ticking_view_with_name("syslog", SyslogView::new());
+        app.register_ticking_view_with_name("irq", IrqView::new());
+        app.register_ticking_view_with_name("custom_metrics", CustomMetricsView::with_config(config));
+        app.register_ticking_view_with_name("docker", DockerView::with_config(config));
+        app.register_ticking_view_with_name("thermal", ThermalView::with_config(config));
+        app.register_ticking_view_with_name(
+            "vrchat",
+            VrchatPageView::with_config(config, app.osc_receiver.state())
+                .with_session_events(app.session_events.clone()),
+        );
+        app.register_view_with_name(
+            "config_editor",
+            ConfigEditorView::new(config.clone(), ctx.command_sender()),
+        );
+        app.register_ticking_view_with_name(
+            "session_stats",
+            SessionStatsView::new(app.draw_profiler.clone(), snapshot),
+        );
 
+        if !config.initial_view.is_empty() {
+            app.switch_to_view_by_name(&config.initial_view);
+        }
         app
     }
 
-    /// Tick 기능이 있는 뷰 등록
-    pub fn register_ticking_view<T: ViewComponent + TickingComponent + 'static>(&mut self, view: T) {
-        self.ticking_views.push(Box::new(TickingViewHolder { inner: view }));
+    /// 조회용 이름과 함께 Tick 기능이 있는 뷰 등록
+    pub fn register_ticking_view_with_name<T: ViewComponent + TickingComponent + 'static>(
+        &mut self,
+        name: &'static str,
+        view: T,
+    ) {
+        self.draw_profiler.borrow_mut().register(view.name());
+        self.ticking_views.push(Box::new(TickingViewHolder {
+            inner: view,
+            key: Some(name),
+        }));
+        self.last_tick_per_view.push(None);
+    }
+
+    /// 조회용 이름과 함께 Tick 기능이 없는 뷰 등록
+    pub fn register_view_with_name<T: ViewComponent + 'static>(
+        &mut self,
+        name: &'static str,
+        view: T,
+    ) {
+        self.draw_profiler.borrow_mut().register(view.name());
+        self.ticking_views.push(Box::new(ViewHolder {
+            inner: view,
+            key: Some(name),
+        }));
+        self.last_tick_per_view.push(None);
+    }
+
+    /// 현재 적용된 설정
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// run_loop가 프레임/tick 지표를 기록할 공유 핸들
+    pub fn app_metrics(&self) -> SharedAppMetrics {
+        self.app_metrics.clone()
+    }
+
+    /// 새 설정을 저장하고 모든 뷰에 전달
+    pub fn reload_config(&mut self, config: Config) {
+        // 레이아웃 목록과 저장 경로는 앱이 관리하므로 유지
+        let layouts = std::mem::take(&mut self.config.layouts);
+        let path = self.config.path.take();
+        self.config = Config {
+            layouts,
+            path,
+            ..config
+        };
+        for view in &mut self.ticking_views {
+            view.on_config_changed(&self.config);
+        }
+        self.debug_view.on_config_changed(&self.config);
+        self.needs_clear = true;
+    }
+
+    /// 채널로 들어온 명령을 모두 처리
+    pub fn process_commands(&mut self) {
+        for command in self.ctx.channels.drain_commands() {
+            match command {
+                ViewerCommand::SwitchView(name) => {
+                    self.switch_to_view_by_name(&name);
+                }
+                ViewerCommand::Quit => self.should_quit = true,
+                ViewerCommand::Alert(message) => {
+                    self.push_alert(Alert::new(message, AlertLevel::Warning))
+                }
+                ViewerCommand::ReloadConfig(config) => self.reload_config(*config),
+            }
+        }
+    }
+
+    /// 등록 이름 또는 표시 이름으로 뷰 찾기 (대소문자 무시)
+    fn view_index(&self, name: &str) -> Option<usize> {
+        self.ticking_views.iter().position(|view| {
+            view.key().is_some_and(|key| key.eq_ignore_ascii_case(name))
+                || view.name().eq_ignore_ascii_case(name)
+        })
     }
 
-    /// Tick 기능이 없는 뷰 등록
-    pub fn register_view<T: ViewComponent + 'static>(&mut self, view: T) {
-        self.ticking_views.push(Box::new(ViewHolder { inner: view }));
+    /// 등록 이름 또는 표시 이름으로 뷰 전환 (대소문자 무시) - 없는 이름이면 false
+    pub fn switch_to_view_by_name(&mut self, name: &str) -> bool {
+        let Some(index) = self.view_index(name) else {
+            return false;
+        };
+        self.set_current_view(index);
+        true
+    }
+
+    /// 뷰 전환 후 화면 클리어 예약 (전환 횟수는 디버그 뷰에 표시)
+    fn set_current_view(&mut self, index: usize) {
+        // 나란히 보기 중 두 번째 뷰로 전환하면 두 뷰의 자리를 바꿈
+        if let LayoutMode::SideBySide { primary, secondary } = self.layout_mode {
+            let secondary = if index == secondary { primary } else { secondary };
+            self.layout_mode = LayoutMode::SideBySide {
+                primary: index,
+                secondary,
+            };
+        }
+        if index != self.current_view {
+            self.ticking_views[self.current_view].on_blur();
+            self.ticking_views[index].on_focus();
+        }
+        self.current_view = index;
+        self.needs_clear = true;
+        // 새 뷰는 숨어 있는 동안의 크기 변경을 모르므로 다음 draw에서 다시 알림
+        self.last_area = Rect::default();
+        if let Ok(mut metrics) = self.app_metrics.lock() {
+            metrics.view_switches += 1;
+        }
+    }
+
+    /// 현재 뷰의 등록 키 (다음 실행 때 `switch_to_view_by_name`으로 복원)
+    pub fn current_view_key(&self) -> String {
+        self.view_key(self.current_view)
     }
 
     /// 다음 뷰로 전환
     pub fn next_view(&mut self) {
         if !self.ticking_views.is_empty() {
-            self.current_view = (self.current_view + 1) % self.ticking_views.len();
-            self.needs_clear = true;
+            self.set_current_view((self.current_view + 1) % self.ticking_views.len());
+        }
+    }
+
+    /// 두 뷰를 좌우로 나란히 표시 (`primary`가 현재 뷰가 됨) - 범위를 벗어나거나 같은 뷰면 false
+    pub fn split_view(&mut self, primary: usize, secondary: usize) -> bool {
+        if primary >= self.ticking_views.len()
+            || secondary >= self.ticking_views.len()
+            || primary == secondary
+        {
+            return false;
+        }
+        self.layout_mode = LayoutMode::SideBySide { primary, secondary };
+        self.set_current_view(primary);
+        true
+    }
+
+    /// 현재 뷰 하나만 표시하는 배치로 복귀
+    pub fn unsplit_view(&mut self) {
+        self.layout_mode = LayoutMode::Single;
+        self.needs_clear = true;
+        self.last_area = Rect::default();
+    }
+
+    /// `\` 키 - 나란히 보기 중이면 해제, 아니면 두 번째 뷰 선택 모달 열기
+    fn toggle_split(&mut self) {
+        if self.layout_mode != LayoutMode::Single {
+            self.unsplit_view();
+            return;
+        }
+        let entries = self
+            .ticking_views
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| index != self.current_view)
+            .map(|(index, view)| (index, view.title().to_string()))
+            .collect();
+        self.view_picker.open(entries);
+    }
+
+    /// 뷰를 설정 파일에 저장할 이름 (등록 이름이 없으면 표시 이름)
+    fn view_key(&self, index: usize) -> String {
+        self.ticking_views
+            .get(index)
+            .map(|view| view.key().unwrap_or(view.name()).to_string())
+            .unwrap_or_default()
+    }
+
+    /// 현재 배치를 프리셋으로 변환
+    fn current_layout_preset(&self, name: String) -> LayoutPreset {
+        let (layout_mode, primary, secondary) = match self.layout_mode {
+            LayoutMode::Single => (config::layout::LayoutMode::Single, self.current_view, None),
+            LayoutMode::SideBySide { primary, secondary } => (
+                config::layout::LayoutMode::SideBySide,
+                primary,
+                Some(self.view_key(secondary)),
+            ),
+        };
+        LayoutPreset {
+            name,
+            layout_mode,
+            primary_view: self.view_key(primary),
+            secondary_view: secondary,
+        }
+    }
+
+    /// 프리셋 배치 적용 - 주 뷰를 찾지 못하면 false (두 번째 뷰가 없으면 한 화면으로)
+    pub fn apply_layout_preset(&mut self, preset: &LayoutPreset) -> bool {
+        let Some(primary) = self.view_index(&preset.primary_view) else {
+            return false;
+        };
+        let secondary = match preset.layout_mode {
+            config::layout::LayoutMode::Single => None,
+            config::layout::LayoutMode::SideBySide => preset
+                .secondary_view
+                .as_deref()
+                .and_then(|name| self.view_index(name)),
+        };
+        self.unsplit_view();
+        match secondary {
+            Some(secondary) if self.split_view(primary, secondary) => {}
+            _ => self.set_current_view(primary),
+        }
+        true
+    }
+
+    /// 현재 배치를 이름을 붙여 설정 파일에 추가
+    pub fn save_layout_as(&mut self, name: &str) {
+        let preset = self.current_layout_preset(name.to_string());
+        let result = match self.config.path {
+            Some(ref path) => config::layout::append_preset(path, &preset),
+            None => Err(io::Error::other("no config file path").into()),
+        };
+        match result {
+            Ok(()) => {
+                self.set_status(format!("Saved layout \"{}\"", preset.name));
+                self.config.layouts.push(preset);
+            }
+            Err(e) => self.push_alert(Alert::new(
+                format!("Cannot save layout: {}", e),
+                AlertLevel::Warning,
+            )),
+        }
+    }
+
+    /// 프리셋을 목록과 설정 파일에서 삭제
+    fn delete_layout_preset(&mut self, index: usize) {
+        if index >= self.config.layouts.len() {
+            return;
+        }
+        let preset = self.config.layouts.remove(index);
+        if let Some(ref path) = self.config.path
+            && path.exists()
+            && let Err(e) = config::layout::remove_preset(path, &preset.name)
+        {
+            self.push_alert(Alert::new(
+                format!("Cannot delete layout: {}", e),
+                AlertLevel::Warning,
+            ));
+        }
+        self.layout_picker.set_presets(&self.config.layouts);
+    }
+
+    /// 이름 입력창 키 처리 - Enter는 저장, Esc는 취소
+    fn handle_layout_name_key(&mut self, key: KeyCode) {
+        let Some(ref mut input) = self.layout_name_input else {
+            return;
+        };
+        match key {
+            KeyCode::Enter => {
+                let name = input.text().trim().to_string();
+                self.layout_name_input = None;
+                if !name.is_empty() {
+                    self.save_layout_as(&name);
+                }
+            }
+            KeyCode::Esc => self.layout_name_input = None,
+            key => input.handle_key(key),
+        }
+    }
+
+    /// 화면 가운데에 이름 입력창 그리기
+    fn draw_layout_name_input(&self, frame: &mut Frame, area: Rect) {
+        let Some(ref input) = self.layout_name_input else {
+            return;
+        };
+        let width = LAYOUT_NAME_INPUT_WIDTH.min(area.width);
+        let height = 3.min(area.height);
+        let input_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        let block = Block::default()
+            .title(" Save layout as ")
+            .title_bottom(" Enter: save  Esc: cancel ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(input_area);
+        frame.render_widget(Clear, input_area);
+        frame.render_widget(block, input_area);
+        input.render(frame, inner);
+    }
+
+    /// 화면에 보이는 뷰와 각 뷰의 영역 (현재 뷰가 먼저)
+    fn visible_panes(&self, content_area: Rect) -> Vec<(usize, Rect)> {
+        match self.layout_mode {
+            LayoutMode::Single => vec![(self.current_view, content_area)],
+            LayoutMode::SideBySide { primary, secondary } => {
+                let [left, right] =
+                    Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                        .areas(content_area);
+                vec![(primary, left), (secondary, right)]
+            }
         }
     }
 
     /// 이전 뷰로 전환
     pub fn prev_view(&mut self) {
         if !self.ticking_views.is_empty() {
-            self.current_view = if self.current_view == 0 {
+            let index = if self.current_view == 0 {
                 self.ticking_views.len() - 1
             } else {
                 self.current_view - 1
             };
-            self.needs_clear = true;
+            self.set_current_view(index);
         }
     }
 
+    /// 등록된 뷰의 조회용 이름 목록 (이름 없이 등록된 뷰는 표시 이름)
+    pub fn view_names(&self) -> Vec<&str> {
+        self.ticking_views
+            .iter()
+            .map(|view| view.key().unwrap_or_else(|| view.name()))
+            .collect()
+    }
+
+    /// 현재 활성화된 뷰 이름
+    pub fn current_view_name(&self) -> &str {
+        self.ticking_views
+            .get(self.current_view)
+            .map(|view| view.name())
+            .unwrap_or("")
+    }
+
     /// 화면 클리어가 필요한지 확인하고 플래그 리셋
     pub fn take_needs_clear(&mut self) -> bool {
         let result = self.needs_clear;
@@ -149,131 +787,758 @@ impl App {
     }
 
     /// 현재 뷰 그리기
-    pub fn draw(&self, frame: &mut Frame) {
+    pub fn draw(&mut self, frame: &mut Frame) {
         let area = frame.area();
+        frame.render_widget(Block::default().style(self.config.theme.base_style()), area);
+
+        let [tab_area, content_area] =
+            Layout::vertical([Constraint::Length(TAB_BAR_HEIGHT), Constraint::Min(0)])
+                .areas(area);
+
+        let panes = self.visible_panes(content_area);
+        if area != self.last_area {
+            self.last_area = area;
+            for &(index, pane) in &panes {
+                if let Some(view) = self.ticking_views.get_mut(index) {
+                    view.on_resize(pane);
+                }
+            }
+        }
+
+        let secondary = match self.layout_mode {
+            LayoutMode::Single => None,
+            LayoutMode::SideBySide { secondary, .. } => Some(secondary),
+        };
+        let titles: Vec<&str> = self.ticking_views.iter().map(|view| view.title()).collect();
+        TabBar::new(&titles, self.current_view)
+            .secondary(secondary)
+            .theme(&self.config.theme)
+            .paused(self.paused)
+            .replay(
+                self.replayer
+                    .as_ref()
+                    .map(|replayer| (replayer.progress(), replayer.is_paused())),
+            )
+            .render(frame, tab_area);
+
+        if self.show_debug {
+            self.debug_view.draw_with_area(frame, content_area);
+        } else {
+            for (index, pane) in panes {
+                self.draw_view(frame, index, pane);
+            }
+        }
+
+        if self.show_alert {
+            self.draw_alert(frame, area);
+        }
+
+        self.draw_toast(frame, area);
+
+        if self.view_picker.is_open() {
+            self.view_picker.render(frame, area);
+        }
+
+        if self.layout_picker.is_open() {
+            self.layout_picker.render(frame, area);
+        }
+
+        self.draw_layout_name_input(frame, area);
+
+        if self.command_palette.is_open() {
+            self.command_palette.render(frame, area, &self.commands);
+        }
+
+        if self.show_help
+            && let Some(view) = self.ticking_views.get(self.current_view)
+        {
+            HelpOverlay::new(view.name(), view.key_bindings()).render(frame, area);
+        }
+    }
+
+    /// 뷰 하나를 영역에 그리기 (영역이 최소 크기보다 작으면 안내만 표시)
+    fn draw_view(&self, frame: &mut Frame, index: usize, area: Rect) {
+        let Some(view) = self.ticking_views.get(index) else {
+            return;
+        };
+        // 최소 크기는 탭 바를 포함한 터미널 기준이므로 뷰 영역에서는 탭 바 높이를 뺌
+        let (min_width, min_height) = view.min_dimensions();
+        let min_height = min_height.saturating_sub(TAB_BAR_HEIGHT);
+        if area.width < min_width || area.height < min_height {
+            Self::draw_too_small(frame, area, (min_width, min_height));
+            return;
+        }
+        let started = Instant::now();
+        view.draw_with_area(frame, area);
+        // 그리는 동안 SessionStatsView가 빌려가므로 측정 후에 기록
+        self.draw_profiler
+            .borrow_mut()
+            .record(view.name(), started.elapsed());
+    }
+
+    /// 최소 크기 안내 문구를 뷰 영역 가운데에 표시 (영역 크기 기준)
+    fn draw_too_small(frame: &mut Frame, area: Rect, (min_width, min_height): (u16, u16)) {
+        let message = Paragraph::new(format!(
+            "Terminal too small: need {}×{}, got {}×{}.",
+            min_width, min_height, area.width, area.height
+        ))
+        .style(Style::default().fg(Color::Red))
+        .centered()
+        .wrap(Wrap { trim: true });
+        let [message_area] = Layout::vertical([Constraint::Length(2)])
+            .flex(Flex::Center)
+            .areas(area);
+        frame.render_widget(message, message_area);
+    }
+
+    /// 최근 토스트 메시지를 오른쪽 아래에 한 줄로 표시
+    fn draw_toast(&self, frame: &mut Frame, area: Rect) {
+        let Some((ref message, shown_at)) = self.toast else {
+            return;
+        };
+        if shown_at.elapsed() >= TOAST_DURATION {
+            return;
+        }
+        let width = (message.chars().count() as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let toast_area = Rect::new(
+            area.right().saturating_sub(width),
+            area.bottom().saturating_sub(height),
+            width,
+            height,
+        );
+        let toast = Paragraph::new(message.as_str()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green)),
+        );
+        frame.render_widget(Clear, toast_area);
+        frame.render_widget(toast, toast_area);
+    }
+
+    /// 가장 최근 미확인 알림을 오른쪽 위 팝업으로 표시
+    fn draw_alert(&self, frame: &mut Frame, area: Rect) {
+        let Some(alert) = self.alert_manager.latest_unacknowledged() else {
+            return;
+        };
+        let color = match alert.level {
+            AlertLevel::Warning => Color::Yellow,
+            AlertLevel::Critical => Color::Red,
+        };
+
+        let width = ALERT_POPUP_WIDTH.min(area.width);
+        let height = ALERT_POPUP_HEIGHT.min(area.height);
+        let popup_area = Rect::new(area.right().saturating_sub(width), area.y, width, height);
+
+        let popup = Paragraph::new(alert.message.clone())
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .title(" Alert ")
+                    .title_bottom(" Space: dismiss ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(color)),
+            );
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(popup, popup_area);
+    }
+
+    /// 알림 추가 후 팝업 표시
+    pub fn push_alert(&mut self, alert: Alert) {
+        self.alert_manager.push(alert);
+        self.show_alert = true;
+    }
+
+    /// CPU/메모리 사용량이 설정된 임계값을 넘었는지 검사
+    fn check_alerts(&mut self, snapshot: &SystemSnapshot) {
+        if self.last_alert_check.elapsed() < ALERT_CHECK_INTERVAL {
+            return;
+        }
+        self.last_alert_check = Instant::now();
+
+        let cpu = snapshot.cpu_usage;
+        let memory = snapshot.memory_percent();
+
+        let cpu_over = cpu > self.config.cpu_alert_threshold;
+        if cpu_over && !self.cpu_over_threshold {
+            self.push_alert(Alert::new(
+                format!(
+                    "CPU usage {:.1}% exceeded {:.0}%",
+                    cpu, self.config.cpu_alert_threshold
+                ),
+                AlertLevel::Warning,
+            ));
+        }
+        self.cpu_over_threshold = cpu_over;
 
-        if let Some(view) = self.ticking_views.get(self.current_view) {
-            view.draw_with_area(frame, area);
+        let memory_over = memory > self.config.memory_alert_threshold;
+        if memory_over && !self.memory_over_threshold {
+            self.push_alert(Alert::new(
+                format!(
+                    "Memory usage {:.1}% exceeded {:.0}%",
+                    memory, self.config.memory_alert_threshold
+                ),
+                AlertLevel::Critical,
+            ));
         }
+        self.memory_over_threshold = memory_over;
+    }
+
+    /// tick을 받을 뷰 - 화면에 보이는 뷰와 숨어 있어도 갱신하는 AlwaysRefresh 뷰
+    fn ticked_view_indices(&self) -> Vec<usize> {
+        let visible: Vec<usize> = match self.layout_mode {
+            LayoutMode::Single => vec![self.current_view],
+            LayoutMode::SideBySide { primary, secondary } => vec![primary, secondary],
+        };
+        let background = self.ticking_views.iter().enumerate().filter(|(index, view)| {
+            !visible.contains(index)
+                && view.background_refresh_policy() == BackgroundRefreshPolicy::AlwaysRefresh
+        });
+        visible
+            .iter()
+            .copied()
+            .chain(background.map(|(index, _)| index))
+            .collect()
     }
 
-    /// tick 처리 (현재 보이는 뷰만 업데이트)
+    /// tick을 받을 뷰 중 가장 빠른 tick 간격 - run_loop는 최소 이 간격으로 App::on_tick을 호출
+    pub fn fastest_tick_interval(&self) -> Option<Duration> {
+        self.ticked_view_indices()
+            .into_iter()
+            .filter_map(|index| self.ticking_views.get(index))
+            .filter(|view| view.needs_tick())
+            .map(|view| Duration::from_millis(view.preferred_tick_ms()))
+            .min()
+    }
+
+    /// tick 처리 (현재 보이는 뷰와 AlwaysRefresh 뷰만, 그 뷰의 tick 간격이 지났을 때 업데이트)
     pub fn on_tick(&mut self) {
-        // 현재 뷰만 tick 처리 (성능 최적화)
-        if let Some(view) = self.ticking_views.get_mut(self.current_view) {
-            view.on_tick();
+        // 일시정지 중에는 뷰를 갱신하지 않음 (밀린 tick은 재생하지 않고 버림)
+        if !self.paused {
+            // 화면에 보이는 뷰만 각자의 간격으로 tick 처리 (성능 최적화)
+            // 단, AlwaysRefresh 뷰는 숨어 있어도 tick
+            for index in self.ticked_view_indices() {
+                let Some(view) = self.ticking_views.get_mut(index) else {
+                    continue;
+                };
+                let interval = Duration::from_millis(view.preferred_tick_ms());
+                let last_tick = &mut self.last_tick_per_view[index];
+                if last_tick.is_none_or(|t| t.elapsed() >= interval) {
+                    view.on_tick();
+                    *last_tick = Some(Instant::now());
+                }
+            }
+        }
+        // 프레임 시간 히스토리가 끊기지 않도록 디버그 뷰는 항상 tick
+        self.debug_view.on_tick();
+
+        if let Some(ref mut replayer) = self.replayer {
+            replayer.advance(&self.metrics.snapshot());
+        }
+
+        if let Some(snapshot) = self.metrics_reader.poll() {
+            self.check_alerts(&snapshot);
+            self.record_summary_sample(&snapshot);
+            if let Some(ref mut recorder) = self.recorder
+                && let Err(e) = recorder.write(&snapshot)
+            {
+                self.recorder = None;
+                self.push_alert(Alert::new(
+                    format!("Recording stopped: {}", e),
+                    AlertLevel::Warning,
+                ));
+            }
+            if let Err(e) = self.exporter.write_row(&snapshot) {
+                self.exporter.stop().ok();
+                self.push_alert(Alert::new(
+                    format!("CSV export stopped: {}", e),
+                    AlertLevel::Warning,
+                ));
+            }
+        }
+    }
+
+    /// 세션 요약용 스냅샷을 일정 간격으로 보관
+    fn record_summary_sample(&mut self, snapshot: &SystemSnapshot) {
+        if !self.config.export_summary
+            || self.summary_history.len() >= MAX_SUMMARY_SAMPLES
+            || self
+                .last_summary_sample
+                .is_some_and(|t| t.elapsed() < SUMMARY_SAMPLE_INTERVAL)
+        {
+            return;
+        }
+        self.last_summary_sample = Some(Instant::now());
+        self.summary_history.push(snapshot.clone());
+    }
+
+    /// 종료 직전 정리 - 설정에 따라 세션 요약을 저장하고 그 경로 반환
+    pub fn cleanup(&mut self) -> io::Result<Option<PathBuf>> {
+        if !self.config.export_summary {
+            return Ok(None);
+        }
+        let path = log_exporter::default_summary_path();
+        let session_events = self.session_events.borrow();
+        let vrchat_events = session_events
+            .watcher_active
+            .then_some(session_events.events.as_slice());
+        log_exporter::write_session_summary(
+            &path,
+            &self.summary_history,
+            self.started_at.elapsed(),
+            vrchat_events,
+        )?;
+        Ok(Some(path))
+    }
+
+    /// CSV 내보내기 켜기/끄기 (실패 시 알림)
+    pub fn toggle_export(&mut self) {
+        match self.exporter.toggle() {
+            Ok(true) => self.set_status(format!("Exporting to {}", self.exporter.path().display())),
+            Ok(false) => self.set_status("Export stopped"),
+            Err(e) => self.push_alert(Alert::new(
+                format!(
+                    "Cannot open {}: {}",
+                    self.exporter.path().display(),
+                    e
+                ),
+                AlertLevel::Warning,
+            )),
+        }
+    }
+
+    /// 수집한 스냅샷을 파일에 녹화 시작
+    pub fn start_recording(&mut self, path: &Path) -> io::Result<()> {
+        self.recorder = Some(Recorder::create(path)?);
+        Ok(())
+    }
+
+    /// 실시간 수집을 멈추고 녹화 파일 재생 시작
+    pub fn start_replay(&mut self, path: &Path) -> io::Result<()> {
+        let replayer = Replayer::load(path)?;
+        self.metrics.stop();
+        self.replayer = Some(replayer);
+        Ok(())
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// 뷰 갱신 일시정지/재개
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        self.set_status(if self.paused { "Paused" } else { "Resumed" });
+    }
+
+    /// run_loop의 tick 간격 변경 요청 (설정의 최소/최대 범위로 제한됨)
+    pub fn set_tick_rate(&mut self, tick_rate: Duration) {
+        self.tick_rate_request = Some(tick_rate);
+        self.set_status(format!("Tick rate {}ms", tick_rate.as_millis()));
+    }
+
+    /// tick 간격 변경 요청이 있었는지 확인하고 리셋
+    pub fn take_tick_rate_request(&mut self) -> Option<Duration> {
+        self.tick_rate_request.take()
+    }
+
+    /// 현재 등록된 뷰와 전역 동작으로 명령 목록 생성
+    fn command_registry(&self) -> CommandRegistry {
+        let mut registry = CommandRegistry::new();
+        // 전환/종료는 다른 명령과 같은 경로로 처리되도록 채널로 보냄
+        for view in &self.ticking_views {
+            let name = view.key().unwrap_or(view.name()).to_string();
+            registry.register(
+                format!("switch to {}", view.name().to_lowercase()),
+                move |app: &mut App| app.ctx.send(ViewerCommand::SwitchView(name.clone())),
+            );
+        }
+        registry.register("toggle pause", App::toggle_pause);
+        registry.register("export csv", App::toggle_export);
+        registry.register("toggle split view", App::toggle_split);
+        registry.register("save screenshot", |app: &mut App| app.screenshot_requested = true);
+        registry.register("toggle debug view", |app: &mut App| {
+            app.show_debug = !app.show_debug;
+            app.needs_clear = true;
+        });
+        registry.register("show help", |app: &mut App| app.show_help = true);
+        registry.register("save layout as", |app: &mut App| {
+            let mut input = FilterBar::new().with_label("Name");
+            input.focus();
+            app.layout_name_input = Some(input);
+        });
+        registry.register("load layout", |app: &mut App| {
+            app.layout_picker.open(&app.config.layouts)
+        });
+        for ms in TICK_RATE_PRESETS_MS {
+            registry.register(format!("set tick rate {}ms", ms), move |app: &mut App| {
+                app.set_tick_rate(Duration::from_millis(ms));
+            });
+        }
+        registry.register("quit", |app: &mut App| app.ctx.send(ViewerCommand::Quit));
+        registry
+    }
+
+    fn open_command_palette(&mut self) {
+        self.commands = self.command_registry();
+        self.command_palette.open(&self.commands);
+    }
+
+    /// 스크린샷 요청이 있었는지 확인하고 플래그 리셋
+    pub fn take_screenshot_request(&mut self) -> bool {
+        std::mem::take(&mut self.screenshot_requested)
+    }
+
+    /// 스크린샷 저장 결과를 토스트(성공) 또는 알림(실패)으로 표시
+    pub fn on_screenshot_saved(&mut self, result: io::Result<PathBuf>) {
+        match result {
+            Ok(path) => {
+                self.toast = Some((format!("Saved to {}", path.display()), Instant::now()));
+            }
+            Err(e) => self.push_alert(Alert::new(
+                format!("Screenshot failed: {}", e),
+                AlertLevel::Warning,
+            )),
+        }
+    }
+
+    /// 터미널 타이틀에 잠시 표시할 상태 메시지
+    fn set_status(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), Instant::now()));
+    }
+
+    /// 현재 뷰 이름과 최근 상태 메시지를 포함한 터미널 타이틀
+    pub fn window_title(&self) -> String {
+        match self.status_message {
+            Some((ref message, shown_at)) if shown_at.elapsed() < STATUS_MESSAGE_DURATION => {
+                format!("l_vrc_console - {} [{}]", self.current_view_name(), message)
+            }
+            _ => format!("l_vrc_console - {}", self.current_view_name()),
         }
     }
 
     /// 키 입력 처리
-    pub fn handle_key(&mut self, key: KeyCode) {
-        // 먼저 현재 뷰에 키 이벤트 전달
-        if let Some(view) = self.ticking_views.get_mut(self.current_view) {
-            if view.handle_key(key) {
-                return; // 뷰에서 이벤트를 소비함
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        // 도움말이 열려 있으면 ?/Esc로 닫기만 하고 나머지 키는 무시
+        if self.show_help {
+            if matches!(key.code, KeyCode::Char('?') | KeyCode::Esc) {
+                self.show_help = false;
+            }
+            return;
+        }
+
+        // 명령 팔레트가 열려 있으면 팔레트가 모든 키를 처리
+        if self.command_palette.is_open() {
+            // 명령이 App을 바꿀 수 있으므로 목록을 잠시 꺼내서 실행
+            let commands = std::mem::take(&mut self.commands);
+            if let Some(index) = self.command_palette.handle_key(key.code, &commands) {
+                commands.execute(index, self);
+            }
+            self.commands = commands;
+            return;
+        }
+
+        if self.layout_name_input.is_some() {
+            self.handle_layout_name_key(key.code);
+            return;
+        }
+
+        if self.layout_picker.is_open() {
+            match self.layout_picker.handle_key(key.code) {
+                Some(LayoutPickerAction::Apply(index)) => {
+                    if let Some(preset) = self.config.layouts.get(index).cloned()
+                        && !self.apply_layout_preset(&preset)
+                    {
+                        self.set_status(format!("View \"{}\" not found", preset.primary_view));
+                    }
+                }
+                Some(LayoutPickerAction::Delete(index)) => self.delete_layout_preset(index),
+                None => {}
+            }
+            return;
+        }
+
+        // 뷰 선택 모달이 열려 있으면 모달이 모든 키를 처리
+        if self.view_picker.is_open() {
+            if let Some(index) = self.view_picker.handle_key(key.code) {
+                self.split_view(self.current_view, index);
+            }
+            return;
+        }
+
+        // 스크린샷은 뷰의 S(정렬 등) 키와 겹치지 않도록 Ctrl+S로 먼저 처리
+        if key.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(key.code, KeyCode::Char('s') | KeyCode::Char('S'))
+        {
+            self.screenshot_requested = true;
+            return;
+        }
+
+        // 디버그 뷰는 어느 뷰에서든 !로 토글
+        if key.code == KeyCode::Char('!') {
+            self.show_debug = !self.show_debug;
+            self.needs_clear = true;
+            return;
+        }
+
+        // 디버그 뷰가 열려 있으면 Esc로 닫고 q로 종료하는 것 외에는 무시
+        if self.show_debug {
+            match key.code {
+                KeyCode::Esc => {
+                    self.show_debug = false;
+                    self.needs_clear = true;
+                }
+                KeyCode::Char('q') => self.should_quit = true,
+                _ => {}
             }
+            return;
+        }
+
+        // 알림 팝업이 떠 있으면 Space로 확인 처리
+        if self.show_alert && key.code == KeyCode::Char(' ') {
+            self.show_alert = self.alert_manager.acknowledge_latest();
+            return;
+        }
+
+        // 재생 중에는 Space로 재생/일시정지
+        if key.code == KeyCode::Char(' ')
+            && let Some(ref mut replayer) = self.replayer
+        {
+            replayer.toggle_pause();
+            let status = if replayer.is_paused() { "Replay paused" } else { "Replay resumed" };
+            self.set_status(status);
+            return;
+        }
+
+        // 먼저 현재 뷰에 키 이벤트 전달
+        if let Some(view) = self.ticking_views.get_mut(self.current_view)
+            && view.handle_key(key)
+        {
+            return; // 뷰에서 이벤트를 소비함
         }
 
         // 전역 키 처리
-        match key {
+        match key.code {
             KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('e') | KeyCode::Char('E') => self.toggle_export(),
+            KeyCode::Char('p') | KeyCode::Char('P') => self.toggle_pause(),
+            KeyCode::Char('?') => self.show_help = true,
+            KeyCode::Char('\\') => self.toggle_split(),
+            KeyCode::Char(':') => self.open_command_palette(),
+            KeyCode::Char('l') | KeyCode::Char('L') => self.layout_picker.open(&self.config.layouts),
             KeyCode::Tab | KeyCode::Right => self.next_view(),
             KeyCode::BackTab | KeyCode::Left => self.prev_view(),
             _ => {}
         }
     }
 
+    /// 마우스 입력 처리 - 현재 뷰가 소비하지 않은 휠 입력은 뷰 전환
+    pub fn handle_mouse(&mut self, event: MouseEvent) {
+        if let Some(view) = self.ticking_views.get_mut(self.current_view)
+            && view.handle_mouse(event)
+        {
+            return;
+        }
+
+        match event.kind {
+            MouseEventKind::ScrollDown => self.next_view(),
+            MouseEventKind::ScrollUp => self.prev_view(),
+            _ => {}
+        }
+    }
+
     pub fn should_quit(&self) -> bool {
         self.should_quit
     }
 }
 
-/// 터미널 UI 실행
-pub fn show_ui() -> Result<(), io::Error> {
-    // 터미널 초기화
-    enable_raw_mode()?;
-    let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-    let channels = crate::queues::view_command::get_viewer_channels();
+/// 프레임 시간에 맞춰 tick 간격을 조절하는 동적 tick rate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TickRate {
+    current: Duration,
+    min_ms: u64,
+    max_ms: u64,
+    target_frame_ms: u64,
+}
+
+impl TickRate {
+    /// 설정의 최소/최대 간격과 목표 프레임 시간으로 생성
+    pub fn new(initial: Duration, config: &Config) -> Self {
+        let min_ms = config.min_tick_ms;
+        let max_ms = config.max_tick_ms.max(min_ms);
+        Self {
+            current: Duration::from_millis((initial.as_millis() as u64).clamp(min_ms, max_ms)),
+            min_ms,
+            max_ms,
+            target_frame_ms: config.target_frame_ms(),
+        }
+    }
+
+    pub fn current(&self) -> Duration {
+        self.current
+    }
+
+    /// 직전 프레임 시간에 따라 tick 간격 조절
+    pub fn adjust(&mut self, frame_time: Duration) {
+        let frame_ms = frame_time.as_millis() as u64;
+        let tick_ms = self.current.as_millis() as u64;
+
+        if frame_ms > self.target_frame_ms + 10 {
+            // 프레임이 느리면 tick rate 증가 (부하 감소)
+            self.current = Duration::from_millis((tick_ms + 10).min(self.max_ms));
+        } else if frame_ms < self.target_frame_ms.saturating_sub(5) {
+            // 프레임이 빠르면 tick rate 감소 (반응성 향상)
+            self.current = Duration::from_millis(tick_ms.saturating_sub(5).max(self.min_ms));
+        }
+    }
+}
+
+/// 입력 이벤트와 터미널 타이틀 처리 (테스트에서는 가짜 구현으로 대체)
+pub trait TerminalIo {
+    /// timeout 동안 이벤트를 기다림 - 없으면 None
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>>;
+    fn set_title(&mut self, title: &str) -> io::Result<()>;
+}
+
+/// crossterm 기반 실제 터미널 입출력
+struct CrosstermIo;
+
+impl TerminalIo for CrosstermIo {
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        if event::poll(timeout)? {
+            event::read().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        execute!(stdout(), SetTitle(title))
+    }
+}
 
-    // 동적 tick rate 설정
-    const MIN_TICK_MS: u64 = 16;   // ~60fps 최대
-    const MAX_TICK_MS: u64 = 200;  // 최소 5fps
-    const TARGET_FRAME_MS: u64 = 33; // 목표 ~30fps
+/// 백엔드 오류를 io::Error로 변환
+fn backend_error<E: std::error::Error + Send + Sync + 'static>(error: E) -> io::Error {
+    io::Error::other(error)
+}
 
-    let mut tick_rate = Duration::from_millis(50);
+/// 메인 루프 - 앱이 종료를 요청할 때까지 그리기, 입력, tick 처리
+pub fn run_loop<B, I>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    terminal_io: &mut I,
+    tick_rate: Duration,
+) -> io::Result<()>
+where
+    B: Backend,
+    B::Error: Send + Sync + 'static,
+    I: TerminalIo,
+{
+    let mut tick_rate = TickRate::new(tick_rate, app.config());
     let mut last_tick = Instant::now();
-    let mut last_frame_time = Duration::from_millis(0);
-    let mut app = App::new();
+    let mut window_title = String::new();
+    let app_metrics = app.app_metrics();
 
-    // 메인 루프
     loop {
-        // 이벤트 처리
-        let rx = channels.rx_command.lock().unwrap();
-        if let Ok(message) = rx.try_recv() {
-            match message {
-                ViewerCommand::Quit => {
-                    app.should_quit = true;
-                }
-            }
-        }
+        // 명령 처리
+        app.process_commands();
 
         // 프레임 시작 시간
         let frame_start = Instant::now();
 
         // 뷰 전환 시 화면 클리어
         if app.take_needs_clear() {
-            terminal.clear()?;
+            terminal.clear().map_err(backend_error)?;
+            if let Ok(mut metrics) = app_metrics.lock() {
+                metrics.last_clear_tick = Some(metrics.total_ticks);
+            }
+        }
+
+        // 뷰 이름이나 상태 메시지가 바뀌었을 때만 터미널 타이틀 갱신
+        let title = app.window_title();
+        if title != window_title {
+            terminal_io.set_title(&title)?;
+            window_title = title;
         }
 
         // 화면 그리기
-        terminal.draw(|frame| {
-            app.draw(frame);
-        })?;
+        let completed = terminal
+            .draw(|frame| {
+                app.draw(frame);
+            })
+            .map_err(backend_error)?;
+
+        // 방금 그린 버퍼를 그대로 저장 (토스트는 다음 프레임부터 표시)
+        if app.take_screenshot_request() {
+            let result = screenshot::save_screenshot(completed.buffer);
+            app.on_screenshot_saved(result);
+        }
 
-        // 이벤트 처리
-        let timeout = tick_rate
+        let frame_ms = frame_start.elapsed().as_secs_f64() * 1000.0;
+        let size = terminal.size().map_err(backend_error)?;
+        if let Ok(mut metrics) = app_metrics.lock() {
+            metrics.last_frame_ms = frame_ms;
+            metrics.tick_rate_ms = tick_rate.current().as_millis() as u64;
+            metrics.terminal_size = (size.width, size.height);
+        }
+
+        // 이벤트 처리 - tick 받는 가장 빠른 뷰보다 느리게 돌지 않도록 제한
+        // (뷰 전환이나 레이아웃 변경으로 tick 받는 뷰가 바뀌므로 매번 다시 계산)
+        let fastest_view_tick = app.fastest_tick_interval().unwrap_or(Duration::MAX);
+        let loop_tick = tick_rate.current().min(fastest_view_tick);
+        let timeout = loop_tick
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
 
-        if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                // 키가 눌렸을 때만 처리 (Release, Repeat 무시)
-                if key.kind == event::KeyEventKind::Press {
-                    app.handle_key(key.code);
+        match terminal_io.poll_event(timeout)? {
+            // 키가 눌렸을 때만 처리 (Release, Repeat 무시)
+            Some(Event::Key(key)) if key.kind == event::KeyEventKind::Press => {
+                app.handle_key(key);
+                if let Some(rate) = app.take_tick_rate_request() {
+                    tick_rate = TickRate::new(rate, app.config());
                 }
             }
+            Some(Event::Mouse(mouse)) => app.handle_mouse(mouse),
+            _ => {}
         }
 
         // tick 처리
-        if last_tick.elapsed() >= tick_rate {
+        let since_tick = last_tick.elapsed();
+        if since_tick >= loop_tick {
+            if let Ok(mut metrics) = app_metrics.lock() {
+                metrics.total_ticks += 1;
+                // 간격의 몇 배만큼 밀렸는지로 건너뛴 tick 수 추정
+                let intervals = since_tick.as_millis() / loop_tick.as_millis().max(1);
+                metrics.missed_ticks += intervals.saturating_sub(1) as u64;
+            }
             app.on_tick();
             last_tick = Instant::now();
         }
 
         // 종료 조건
         if app.should_quit() {
-            break;
+            return Ok(());
         }
 
         // 동적 tick rate 조절
-        last_frame_time = frame_start.elapsed();
-        let frame_ms = last_frame_time.as_millis() as u64;
-
-        if frame_ms > TARGET_FRAME_MS + 10 {
-            // 프레임이 느리면 tick rate 증가 (부하 감소)
-            let new_tick = tick_rate.as_millis() as u64 + 10;
-            tick_rate = Duration::from_millis(new_tick.min(MAX_TICK_MS));
-        } else if frame_ms < TARGET_FRAME_MS - 5 {
-            // 프레임이 빠르면 tick rate 감소 (반응성 향상)
-            let new_tick = tick_rate.as_millis() as u64 - 5;
-            tick_rate = Duration::from_millis(new_tick.max(MIN_TICK_MS));
-        }
+        tick_rate.adjust(frame_start.elapsed());
     }
+}
+
+/// 터미널 UI 실행
+pub fn show_ui(mut app: App) -> Result<(), io::Error> {
+    // 터미널 초기화
+    enable_raw_mode()?;
+    let mut stdout = stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
 
-    // 터미널 복원
+    let tick_rate = Duration::from_millis(app.config().tick_rate_ms);
+    let result = run_loop(&mut terminal, &mut app, &mut CrosstermIo, tick_rate);
+    let summary = app.cleanup();
+
+    // 터미널 복원 (루프가 실패해도 항상 실행)
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -282,5 +1547,447 @@ pub fn show_ui() -> Result<(), io::Error> {
     )?;
     terminal.show_cursor()?;
 
-    Ok(())
-}
\ No newline at end of file
+    match summary {
+        Ok(Some(path)) => println!("세션 요약 저장: {}", path.display()),
+        Ok(None) => {}
+        Err(e) => eprintln!("세션 요약 저장 실패: {}", e),
+    }
+
+    // 정상 종료 시에만 마지막 뷰 저장 (다음 실행 때 복원)
+    if result.is_ok()
+        && let Some(path) = snapshot::default_snapshot_path()
+        && let Err(e) = snapshot::save_snapshot(&path, &app.current_view_key(), app.is_paused())
+    {
+        eprintln!("스냅샷 저장 실패 ({}): {}", path.display(), e);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    #[test]
+    fn quit_command_sets_should_quit() {
+        let ctx = AppContext::default();
+        let mut app = App::new(ctx.clone());
+        assert!(!app.should_quit());
+
+        ctx.send(ViewerCommand::Quit);
+        app.process_commands();
+
+        assert!(app.should_quit());
+    }
+
+    #[test]
+    fn switch_view_command_changes_current_view() {
+        let ctx = AppContext::default();
+        let mut app = App::new(ctx.clone());
+
+        ctx.send(ViewerCommand::SwitchView("system_monitor".to_string()));
+        app.process_commands();
+
+        assert_eq!(app.current_view, 1);
+    }
+
+    #[test]
+    fn switch_to_view_by_name_matches_key_or_display_name() {
+        let mut app = App::new(AppContext::default());
+
+        assert!(app.switch_to_view_by_name("cpu_cores"));
+        assert_eq!(app.current_view_name(), "CPU Cores");
+        assert!(app.switch_to_view_by_name("system monitor"));
+        assert_eq!(app.current_view, 1);
+        assert!(!app.switch_to_view_by_name("missing"));
+        assert_eq!(app.current_view, 1);
+    }
+
+    /// 미리 정한 이벤트를 순서대로 돌려주는 가짜 터미널 (이벤트가 떨어지면 오류로 루프 중단)
+    struct ScriptedIo {
+        events: std::collections::VecDeque<Event>,
+    }
+
+    impl ScriptedIo {
+        fn new(keys: &[KeyCode]) -> Self {
+            Self {
+                events: keys
+                    .iter()
+                    .map(|&code| Event::Key(KeyEvent::from(code)))
+                    .collect(),
+            }
+        }
+    }
+
+    impl TerminalIo for ScriptedIo {
+        fn poll_event(&mut self, _timeout: Duration) -> io::Result<Option<Event>> {
+            self.events
+                .pop_front()
+                .map(Some)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "no more events"))
+        }
+
+        fn set_title(&mut self, _title: &str) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn test_terminal() -> Terminal<ratatui::backend::TestBackend> {
+        Terminal::new(ratatui::backend::TestBackend::new(120, 40)).unwrap()
+    }
+
+    #[test]
+    fn run_loop_exits_on_q() {
+        let mut app = App::new(AppContext::default());
+        let mut io = ScriptedIo::new(&[KeyCode::Char('q')]);
+
+        run_loop(&mut test_terminal(), &mut app, &mut io, Duration::from_millis(50)).unwrap();
+
+        assert!(app.should_quit());
+    }
+
+    #[test]
+    fn run_loop_tab_switches_views() {
+        let mut app = App::new(AppContext::default());
+        let mut io = ScriptedIo::new(&[KeyCode::Tab, KeyCode::Tab, KeyCode::Char('q')]);
+
+        run_loop(&mut test_terminal(), &mut app, &mut io, Duration::from_millis(50)).unwrap();
+
+        assert_eq!(app.current_view, 2);
+    }
+
+    #[test]
+    fn bang_toggles_debug_view_and_view_switches_are_counted() {
+        let mut app = App::new(AppContext::default());
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('!')));
+        assert!(app.show_debug);
+        // 디버그 뷰가 열려 있는 동안에는 뷰 전환 키 무시
+        app.handle_key(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(app.current_view, 0);
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert!(!app.show_debug);
+        assert!(!app.should_quit());
+
+        app.next_view();
+        app.prev_view();
+        assert_eq!(app.app_metrics().lock().unwrap().view_switches, 2);
+    }
+
+    #[test]
+    fn p_toggles_pause_and_resumes() {
+        let mut app = App::new(AppContext::default());
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        assert!(app.is_paused());
+        app.on_tick();
+        app.handle_key(KeyEvent::from(KeyCode::Char('P')));
+        assert!(!app.is_paused());
+    }
+
+    #[test]
+    fn p_pauses_even_when_network_view_is_active() {
+        let mut app = App::new(AppContext::default());
+        assert!(app.switch_to_view_by_name("network"));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('P')));
+        assert!(app.is_paused());
+    }
+
+    #[test]
+    fn l_opens_layout_picker_even_when_network_view_is_active() {
+        let mut app = App::new(AppContext::default());
+        assert!(app.switch_to_view_by_name("network"));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('L')));
+        assert!(app.layout_picker.is_open());
+    }
+
+    #[test]
+    fn backslash_picks_a_secondary_view_and_toggles_split() {
+        let mut app = App::new(AppContext::default());
+        let key = |code| KeyEvent::from(code);
+
+        app.handle_key(key(KeyCode::Char('\\')));
+        app.handle_key(key(KeyCode::Down));
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.layout_mode, LayoutMode::SideBySide { primary: 0, secondary: 2 });
+
+        // 두 번째 뷰로 전환하면 자리를 바꿈
+        app.next_view();
+        app.next_view();
+        assert_eq!(app.layout_mode, LayoutMode::SideBySide { primary: 2, secondary: 1 });
+
+        let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(200, 40)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        // " Status │ System │ ..." - 두 번째 뷰(System) 탭도 강조됨
+        let tab = &terminal.backend().buffer()[(10, 0)];
+        assert_eq!(tab.symbol(), "S");
+        assert!(tab.modifier.contains(ratatui::style::Modifier::UNDERLINED));
+
+        app.handle_key(key(KeyCode::Char('\\')));
+        assert_eq!(app.layout_mode, LayoutMode::Single);
+        assert_eq!(app.current_view, 2);
+    }
+
+    #[test]
+    fn colon_opens_command_palette_and_runs_the_best_match() {
+        let mut app = App::new(AppContext::default());
+        let key = |code| KeyEvent::from(code);
+        app.handle_key(key(KeyCode::Char(':')));
+        for c in "pause".chars() {
+            app.handle_key(key(KeyCode::Char(c)));
+        }
+        app.handle_key(key(KeyCode::Enter));
+        assert!(app.is_paused());
+        assert!(!app.should_quit(), "typed keys must not reach global shortcuts");
+
+        app.handle_key(key(KeyCode::Char(':')));
+        app.handle_key(key(KeyCode::Esc));
+        assert!(!app.should_quit());
+    }
+
+    #[test]
+    fn layout_presets_round_trip_through_the_config_file() {
+        let path = std::env::temp_dir().join(format!("l_vrc_console_layouts_{}.toml", std::process::id()));
+        std::fs::write(&path, "history_size = 90\n").unwrap();
+        let config = Config {
+            path: Some(path.clone()),
+            ..Config::default()
+        };
+        let mut app = App::new(AppContext::new(config));
+
+        app.split_view(1, 2);
+        app.save_layout_as("work");
+        app.unsplit_view();
+        app.set_current_view(0);
+
+        let saved = Config::load(&path).unwrap();
+        assert_eq!(saved.history_size, 90);
+        assert_eq!(saved.layouts, app.config().layouts);
+        assert_eq!(saved.layouts[0].primary_view, "system_monitor");
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('L')));
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.layout_mode, LayoutMode::SideBySide { primary: 1, secondary: 2 });
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('L')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('x')));
+        let saved = Config::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert!(saved.layouts.is_empty());
+        assert_eq!(saved.history_size, 90);
+    }
+
+    /// tick 횟수만 세는 테스트용 뷰
+    struct CountingView {
+        ticks: Rc<Cell<u32>>,
+        tick_ms: u64,
+    }
+
+    impl ViewComponent for CountingView {
+        fn draw_with_area(&self, _frame: &mut Frame, _area: Rect) {}
+        fn name(&self) -> &str {
+            "Counting"
+        }
+    }
+
+    impl TickingComponent for CountingView {
+        fn on_tick(&mut self) {
+            self.ticks.set(self.ticks.get() + 1);
+        }
+        fn preferred_tick_ms(&self) -> u64 {
+            self.tick_ms
+        }
+    }
+
+    #[test]
+    fn views_are_ticked_at_their_own_rate() {
+        let mut app = App::new(AppContext::default());
+        let slow = Rc::new(Cell::new(0));
+        let fast = Rc::new(Cell::new(0));
+        app.register_ticking_view_with_name("slow", CountingView { ticks: slow.clone(), tick_ms: 60_000 });
+        app.register_ticking_view_with_name("fast", CountingView { ticks: fast.clone(), tick_ms: 0 });
+        let count = app.ticking_views.len();
+
+        app.set_current_view(count - 2);
+        for _ in 0..5 {
+            app.on_tick();
+        }
+        assert_eq!(slow.get(), 1);
+
+        app.set_current_view(count - 1);
+        for _ in 0..5 {
+            app.on_tick();
+        }
+        assert_eq!(fast.get(), 5);
+        assert_eq!(app.fastest_tick_interval(), Some(Duration::ZERO));
+
+        // 숨은 뷰의 tick 간격은 루프 속도에 영향을 주지 않음
+        app.set_current_view(count - 2);
+        assert_ne!(app.fastest_tick_interval(), Some(Duration::ZERO));
+    }
+
+    /// 숨어 있어도 tick을 받는 테스트용 뷰
+    struct BackgroundView {
+        ticks: Rc<Cell<u32>>,
+    }
+
+    impl ViewComponent for BackgroundView {
+        fn draw_with_area(&self, _frame: &mut Frame, _area: Rect) {}
+        fn name(&self) -> &str {
+            "Background"
+        }
+    }
+
+    impl TickingComponent for BackgroundView {
+        fn on_tick(&mut self) {
+            self.ticks.set(self.ticks.get() + 1);
+        }
+        fn preferred_tick_ms(&self) -> u64 {
+            0
+        }
+        fn background_refresh_policy(&self) -> BackgroundRefreshPolicy {
+            BackgroundRefreshPolicy::AlwaysRefresh
+        }
+    }
+
+    #[test]
+    fn hidden_views_are_ticked_only_with_always_refresh() {
+        let mut app = App::new(AppContext::default());
+        let hidden = Rc::new(Cell::new(0));
+        let background = Rc::new(Cell::new(0));
+        app.register_ticking_view_with_name("hidden", CountingView { ticks: hidden.clone(), tick_ms: 0 });
+        app.register_ticking_view_with_name("background", BackgroundView { ticks: background.clone() });
+
+        for _ in 0..3 {
+            app.on_tick();
+        }
+        assert_eq!(app.current_view, 0);
+        assert_eq!(hidden.get(), 0);
+        assert_eq!(background.get(), 3);
+    }
+
+    /// on_resize 호출 횟수만 세는 테스트용 뷰
+    struct ResizeView {
+        resizes: Rc<Cell<u32>>,
+    }
+
+    impl ViewComponent for ResizeView {
+        fn draw_with_area(&self, _frame: &mut Frame, _area: Rect) {}
+        fn name(&self) -> &str {
+            "Resize"
+        }
+        fn min_dimensions(&self) -> (u16, u16) {
+            (0, 0)
+        }
+        fn on_resize(&mut self, _new_area: Rect) {
+            self.resizes.set(self.resizes.get() + 1);
+        }
+    }
+
+    #[test]
+    fn active_view_is_notified_only_when_terminal_size_changes() {
+        let mut app = App::new(AppContext::default());
+        let resizes = Rc::new(Cell::new(0));
+        app.register_view_with_name("resize", ResizeView { resizes: resizes.clone() });
+        app.set_current_view(app.ticking_views.len() - 1);
+
+        let mut small = Terminal::new(ratatui::backend::TestBackend::new(40, 10)).unwrap();
+        let mut large = Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+        small.draw(|frame| app.draw(frame)).unwrap();
+        small.draw(|frame| app.draw(frame)).unwrap();
+        assert_eq!(resizes.get(), 1);
+
+        large.draw(|frame| app.draw(frame)).unwrap();
+        assert_eq!(resizes.get(), 2);
+    }
+
+    /// on_focus/on_blur 호출을 기록하는 테스트용 뷰
+    struct FocusView {
+        events: Rc<std::cell::RefCell<Vec<&'static str>>>,
+    }
+
+    impl ViewComponent for FocusView {
+        fn draw_with_area(&self, _frame: &mut Frame, _area: Rect) {}
+        fn name(&self) -> &str {
+            "Focus"
+        }
+        fn on_focus(&mut self) {
+            self.events.borrow_mut().push("focus");
+        }
+        fn on_blur(&mut self) {
+            self.events.borrow_mut().push("blur");
+        }
+    }
+
+    #[test]
+    fn too_small_notice_reports_the_pane_size() {
+        let mut app = App::new(AppContext::default());
+        let events = Rc::new(std::cell::RefCell::new(Vec::new()));
+        app.register_view_with_name("focus", FocusView { events });
+        app.set_current_view(app.ticking_views.len() - 1);
+
+        let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(70, 20)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+            .map(|position| buffer[position].symbol())
+            .collect();
+        assert!(text.contains("need 80×23, got 70×19."), "{}", text);
+    }
+
+    #[test]
+    fn switching_views_blurs_the_old_view_and_focuses_the_new_one() {
+        let mut app = App::new(AppContext::default());
+        let events = Rc::new(std::cell::RefCell::new(Vec::new()));
+        app.register_view_with_name("focus", FocusView { events: events.clone() });
+        let last = app.ticking_views.len() - 1;
+
+        app.prev_view();
+        assert_eq!(app.current_view, last);
+        app.set_current_view(last);
+        app.next_view();
+        assert_eq!(*events.borrow(), vec!["focus", "blur"]);
+    }
+
+    #[test]
+    fn tick_rate_follows_frame_time() {
+        let config = Config::default();
+        let target = Duration::from_millis(config.target_frame_ms());
+        let mut tick_rate = TickRate::new(Duration::from_millis(50), &config);
+
+        // 느린 프레임 → 10ms씩 증가, 최대값에서 멈춤
+        tick_rate.adjust(target + Duration::from_millis(20));
+        assert_eq!(tick_rate.current(), Duration::from_millis(60));
+        for _ in 0..100 {
+            tick_rate.adjust(target + Duration::from_millis(20));
+        }
+        assert_eq!(tick_rate.current(), Duration::from_millis(config.max_tick_ms));
+
+        // 목표 근처 프레임 → 유지
+        tick_rate.adjust(target);
+        assert_eq!(tick_rate.current(), Duration::from_millis(config.max_tick_ms));
+
+        // 빠른 프레임 → 5ms씩 감소, 최소값에서 멈춤
+        for _ in 0..100 {
+            tick_rate.adjust(Duration::ZERO);
+        }
+        assert_eq!(tick_rate.current(), Duration::from_millis(config.min_tick_ms));
+    }
+
+    #[test]
+    fn apps_do_not_share_channels() {
+        let first = AppContext::default();
+        let second = AppContext::default();
+        let mut app = App::new(second);
+
+        first.send(ViewerCommand::Quit);
+        app.process_commands();
+
+        assert!(!app.should_quit());
+    }
+}
@@ -0,0 +1,252 @@
+use crossbeam_channel::Sender;
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Row, Table},
+    Frame,
+};
+
+use crate::config::Config;
+use crate::ui::viewer::ViewerCommand;
+
+/// 되돌리기 기록 최대 개수
+const MAX_UNDO_HISTORY: usize = 20;
+
+/// 편집 가능한 설정 항목
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ConfigField {
+    SomeSetting,
+    NetworkInterface,
+    ShowCpuTemp,
+}
+
+impl ConfigField {
+    const ALL: [ConfigField; 3] = [
+        ConfigField::SomeSetting,
+        ConfigField::NetworkInterface,
+        ConfigField::ShowCpuTemp,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            ConfigField::SomeSetting => "Some Setting",
+            ConfigField::NetworkInterface => "Network Interface",
+            ConfigField::ShowCpuTemp => "Show CPU Temp",
+        }
+    }
+}
+
+/// 설정 편집 뷰 (Ctrl+Z 되돌리기, Ctrl+Y / Ctrl+Shift+Z 다시 실행)
+pub struct ConfigEditorView {
+    config: Config,
+    selected: usize,
+    /// 문자열 항목 편집 중인 입력 버퍼
+    edit_buffer: Option<String>,
+    undo_stack: Vec<Config>,
+    redo_stack: Vec<Config>,
+    /// 변경된 설정을 앱에 알리는 채널
+    commands: Sender<ViewerCommand>,
+}
+
+impl ConfigEditorView {
+    pub fn new(config: Config, commands: Sender<ViewerCommand>) -> Self {
+        Self {
+            commands,
+            config,
+            selected: 0,
+            edit_buffer: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    fn selected_field(&self) -> ConfigField {
+        ConfigField::ALL[self.selected]
+    }
+
+    fn value_of(&self, field: ConfigField) -> String {
+        match field {
+            ConfigField::SomeSetting => self.config.some_setting.to_string(),
+            ConfigField::NetworkInterface => self
+                .config
+                .network_interface
+                .clone()
+                .unwrap_or_else(|| "(auto)".to_string()),
+            ConfigField::ShowCpuTemp => self.config.show_cpu_temp.to_string(),
+        }
+    }
+
+    /// 변경 전 상태를 기록하고 새 설정을 적용
+    fn apply_change(&mut self, change: impl FnOnce(&mut Config)) {
+        let previous = self.config.clone();
+        change(&mut self.config);
+
+        self.undo_stack.push(previous);
+        if self.undo_stack.len() > MAX_UNDO_HISTORY {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+        self.broadcast();
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.undo_stack.pop() else {
+            return false;
+        };
+        self.redo_stack
+            .push(std::mem::replace(&mut self.config, previous));
+        self.broadcast();
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.redo_stack.pop() else {
+            return false;
+        };
+        self.undo_stack
+            .push(std::mem::replace(&mut self.config, next));
+        self.broadcast();
+        true
+    }
+
+    /// 변경된 설정을 다른 뷰에 전달
+    fn broadcast(&self) {
+        let _ = self
+            .commands
+            .send(ViewerCommand::ReloadConfig(Box::new(self.config.clone())));
+    }
+
+    fn activate_selected(&mut self) {
+        match self.selected_field() {
+            ConfigField::SomeSetting => self.apply_change(|c| c.some_setting = !c.some_setting),
+            ConfigField::ShowCpuTemp => self.apply_change(|c| c.show_cpu_temp = !c.show_cpu_temp),
+            ConfigField::NetworkInterface => {
+                self.edit_buffer = Some(self.config.network_interface.clone().unwrap_or_default());
+            }
+        }
+    }
+
+    fn handle_edit_key(&mut self, key: KeyCode) {
+        let Some(ref mut buffer) = self.edit_buffer else {
+            return;
+        };
+        match key {
+            KeyCode::Char(c) => buffer.push(c),
+            KeyCode::Backspace => {
+                buffer.pop();
+            }
+            KeyCode::Enter => {
+                let value = self.edit_buffer.take().unwrap_or_default();
+                let value = (!value.trim().is_empty()).then(|| value.trim().to_string());
+                if value != self.config.network_interface {
+                    self.apply_change(|c| c.network_interface = value);
+                }
+            }
+            KeyCode::Esc => self.edit_buffer = None,
+            _ => {}
+        }
+    }
+}
+
+impl Default for ConfigEditorView {
+    fn default() -> Self {
+        // 수신자가 없으므로 설정 변경은 이 뷰 안에서만 유지됨
+        Self::new(Config::default(), crossbeam_channel::unbounded().0)
+    }
+}
+
+impl super::ViewComponent for ConfigEditorView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let rows = ConfigField::ALL.iter().enumerate().map(|(i, field)| {
+            let value = match (&self.edit_buffer, i == self.selected) {
+                (Some(buffer), true) => format!("{}_", buffer),
+                _ => self.value_of(*field),
+            };
+            let row = Row::new(vec![
+                Span::styled(field.label(), Style::default().fg(Color::Gray)),
+                Span::styled(value, Style::default().fg(Color::White)),
+            ]);
+            if i == self.selected {
+                row.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                row
+            }
+        });
+
+        let table = Table::new(rows, [Constraint::Length(20), Constraint::Fill(1)]).block(
+            Block::default()
+                .title(" Config Editor ")
+                .title_bottom(format!(
+                    " Enter: edit  Ctrl+Z: undo ({})  Ctrl+Y: redo ({}) ",
+                    self.undo_stack.len(),
+                    self.redo_stack.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        frame.render_widget(table, area);
+    }
+
+    fn name(&self) -> &str {
+        "Config Editor"
+    }
+
+    fn title(&self) -> &str {
+        "Config"
+    }
+
+    /// 설정 항목 목록 + 편집 줄
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 16)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑ / ↓", "Select field"),
+            ("Enter / Space", "Edit field"),
+            ("Ctrl+Z", "Undo"),
+            ("Ctrl+Y", "Redo"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        if self.edit_buffer.is_some() {
+            self.handle_edit_key(key);
+            return true;
+        }
+
+        match key {
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(ConfigField::ALL.len() - 1);
+                true
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                self.activate_selected();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        if self.edit_buffer.is_none() && key.modifiers.contains(KeyModifiers::CONTROL) {
+            let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+            return match key.code {
+                KeyCode::Char('z') if !shift => self.undo(),
+                KeyCode::Char('Z') | KeyCode::Char('z') | KeyCode::Char('y') => self.redo(),
+                _ => false,
+            };
+        }
+        self.handle_key(key.code)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.config = config.clone();
+    }
+}
@@ -1,57 +1,294 @@
+use std::{
+    cell::Cell,
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
+
 use ratatui::{
-    crossterm::event::KeyCode,
-    layout::{Constraint, Direction, Layout, Rect},
-    widgets::{Block, Borders, Paragraph},
+    crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
+    layout::{Constraint, Direction, Layout, Position, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
-use sysinfo::System;
+use sysinfo::Components;
 
+use crate::config::{Config, Theme};
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader};
+use crate::integration::cpu_topology::CpuTopology;
 use crate::ui::components::usage_gauge::CoreGraph;
+use crate::ui::components::{CoreHeatmap, SharedTimeRange, StatsFooter};
+
+const TOOLTIP_WIDTH: u16 = 28;
+const TOOLTIP_HEIGHT: u16 = 7;
+const TOOLTIP_DISMISS_DELAY: Duration = Duration::from_secs(1);
+/// 스파크라인 한 칸 높이 (제목 1줄 + 막대 2줄)
+const SPARKLINE_HEIGHT: u16 = 3;
+/// 타이틀 높이 (테두리 2줄 + 설명 1줄 + 전체 코어 통계 1줄)
+const TITLE_HEIGHT: u16 = 4;
+/// 게이지/그래프 한 칸의 최소 폭 (이보다 좁아지면 열 수를 줄임)
+const GAUGE_MIN_WIDTH: u16 = 20;
+const GRAPH_MIN_WIDTH: u16 = 40;
+/// 넓은 터미널에서도 이 이상 열을 늘리지 않음
+const MAX_GAUGE_COLUMNS: usize = 8;
+const MAX_GRAPH_COLUMNS: usize = 4;
+
+/// NUMA 노드 하나 (또는 평면 레이아웃 전체)에 해당하는 코어 묶음
+struct CoreGroup {
+    /// 블록 제목 (평면 레이아웃이면 None)
+    title: Option<String>,
+    /// 테두리를 포함한 전체 영역
+    outer: Rect,
+    /// 코어가 그려질 영역 (블록 테두리 안쪽)
+    area: Rect,
+    /// 표시 순서의 코어 인덱스
+    indices: Vec<usize>,
+}
+
+/// 코어 표시 모드 (G 키로 순환)
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CoreDisplayMode {
+    Gauge,
+    Graph,
+    Sparkline,
+    Heatmap,
+}
+
+impl CoreDisplayMode {
+    fn next(self) -> Self {
+        match self {
+            CoreDisplayMode::Gauge => CoreDisplayMode::Graph,
+            CoreDisplayMode::Graph => CoreDisplayMode::Sparkline,
+            CoreDisplayMode::Sparkline => CoreDisplayMode::Heatmap,
+            CoreDisplayMode::Heatmap => CoreDisplayMode::Gauge,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            CoreDisplayMode::Gauge => "Gauge",
+            CoreDisplayMode::Graph => "Graph",
+            CoreDisplayMode::Sparkline => "Sparkline",
+            CoreDisplayMode::Heatmap => "Heatmap",
+        }
+    }
+}
 
 /// CPU 멀티코어 모니터 뷰
 pub struct CpuCoresView {
-    system: System,
+    metrics: SnapshotReader,
+    /// 새 코어 그래프 생성 시 적용할 테마, 색상 기준값, 히스토리 길이
+    theme: Theme,
+    thresholds: (f64, f64),
+    history_size: usize,
+    components: Components,
+    /// NUMA 노드가 둘 이상일 때만 Some (노드별 블록으로 묶어서 표시)
+    topology: Option<CpuTopology>,
     cores: Vec<CoreGraph>,
-    show_graph: bool, // true: 그래프, false: 게이지
+    display_mode: CoreDisplayMode,
+    /// 사용량 내림차순 정렬 여부 (S 키로 토글)
+    sort_by_usage: bool,
+    /// 마지막으로 그려진 영역 (마우스 히트 테스트용)
+    last_area: Cell<Rect>,
+    /// 마우스가 올라간 코어 인덱스와 커서 위치
+    hovered_core: Option<(usize, Position)>,
+    /// 커서가 벗어난 뒤 툴팁을 숨길 시각
+    tooltip_dismiss_at: Option<Instant>,
+    /// 클릭으로 전체 화면 확대된 코어 인덱스
+    zoomed_core: Option<usize>,
+    /// 그래프 표시 범위 (시스템 모니터의 시간 범위 선택기와 공유)
+    time_range: Option<SharedTimeRange>,
+    /// 게이지/그래프 모드의 열 수 (터미널 폭에 맞춰 on_resize에서 갱신)
+    gauge_columns: usize,
+    graph_columns: usize,
 }
 
 impl CpuCoresView {
     pub fn new() -> Self {
-        let mut system = System::new_all();
-        system.refresh_all();
+        Self::with_config(&Config::default(), SharedSnapshot::default())
+    }
 
-        let core_count = system.cpus().len();
-        let cores = (0..core_count)
-            .map(|i| CoreGraph::new(format!("Core {}", i)))
-            .collect();
+    pub fn with_config(config: &Config, metrics: SharedSnapshot) -> Self {
+        // 단일 노드면 묶을 필요가 없으므로 기존 평면 레이아웃 사용
+        let topology = CpuTopology::detect().filter(|t| t.nodes().len() > 1);
+        Self::with_topology(config, metrics, topology)
+    }
+
+    /// 토폴로지를 지정하여 생성 (None이면 평면 레이아웃)
+    pub fn with_topology(
+        config: &Config,
+        metrics: SharedSnapshot,
+        topology: Option<CpuTopology>,
+    ) -> Self {
+        let metrics = SnapshotReader::new(metrics);
+        let core_count = metrics.current().cores.len();
+
+        let mut view = Self {
+            metrics,
+            theme: config.theme.clone(),
+            thresholds: (config.low_threshold as f64, config.high_threshold as f64),
+            history_size: config.history_size,
+            components: Components::new_with_refreshed_list(),
+            topology,
+            display_mode: CoreDisplayMode::Gauge,
+            sort_by_usage: false,
+            last_area: Cell::new(Rect::default()),
+            hovered_core: None,
+            tooltip_dismiss_at: None,
+            zoomed_core: None,
+            cores: Vec::new(),
+            time_range: None,
+            gauge_columns: 4,
+            graph_columns: 2,
+        };
+        view.ensure_cores(core_count);
+        view
+    }
 
-        Self {
-            system,
-            cores,
-            show_graph: false,
+    /// 그래프 표시 범위를 공유 핸들에 맞춤
+    pub fn with_time_range(mut self, time_range: SharedTimeRange) -> Self {
+        self.time_range = Some(time_range);
+        self
+    }
+
+    /// 코어 그래프 수를 스냅샷의 코어 수에 맞춤
+    fn ensure_cores(&mut self, count: usize) {
+        while self.cores.len() < count {
+            let mut core = CoreGraph::new(format!("Core {}", self.cores.len()))
+                .with_history_size(self.history_size);
+            core.apply_theme(&self.theme);
+            core.set_thresholds(self.thresholds.0, self.thresholds.1);
+            self.cores.push(core);
         }
     }
 
     fn refresh(&mut self) {
-        self.system.refresh_cpu_all();
+        if let Some(snapshot) = self.metrics.poll() {
+            self.ensure_cores(snapshot.cores.len());
+            for (core, data) in self.cores.iter_mut().zip(&snapshot.cores) {
+                core.push(data.usage);
+                core.set_frequency(data.frequency_mhz);
+            }
+        }
 
-        for (i, cpu) in self.system.cpus().iter().enumerate() {
-            if let Some(core) = self.cores.get_mut(i) {
-                core.push(cpu.cpu_usage() as f64);
+        // 시스템 모니터에서 고른 시간 범위 (코어별 히스토리보다 길면 전체 표시)
+        if let Some(ref time_range) = self.time_range {
+            let samples = time_range.load(Ordering::Relaxed);
+            for core in &mut self.cores {
+                core.set_visible_samples(samples);
             }
         }
+
+        // 툴팁이 떠 있을 때만 온도 센서 갱신
+        if self.hovered_core.is_some() {
+            self.components.refresh(false);
+        }
+
+        if let Some(dismiss_at) = self.tooltip_dismiss_at
+            && Instant::now() >= dismiss_at
+        {
+            self.hovered_core = None;
+            self.tooltip_dismiss_at = None;
+        }
     }
 
-    /// 게이지 모드로 렌더링
-    fn render_gauges(&self, frame: &mut Frame, area: Rect) {
-        let core_count = self.cores.len();
+    /// 타이틀을 제외한 코어 표시 영역
+    fn content_area(area: Rect) -> Rect {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(TITLE_HEIGHT), Constraint::Min(0)])
+            .split(area)[1]
+    }
+
+    /// 코어 묶음별 영역 (토폴로지가 있으면 NUMA 노드마다 하나)
+    fn core_groups(&self, area: Rect, sorted_indices: &[usize]) -> Vec<CoreGroup> {
+        let Some(ref topology) = self.topology else {
+            return vec![CoreGroup {
+                title: None,
+                outer: area,
+                area,
+                indices: sorted_indices.to_vec(),
+            }];
+        };
+
+        let groups: Vec<(String, Vec<usize>)> = topology
+            .nodes()
+            .iter()
+            .map(|node| {
+                let packages: Vec<String> =
+                    node.packages().iter().map(|p| p.to_string()).collect();
+                let title = format!(
+                    " NUMA Node {} (package {}) ",
+                    node.id,
+                    packages.join(", ")
+                );
+                let indices = sorted_indices
+                    .iter()
+                    .copied()
+                    .filter(|&idx| node.contains(idx))
+                    .collect();
+                (title, indices)
+            })
+            .collect();
+
+        // 게이지는 고정 높이, 나머지 모드는 코어 수 비율로 분배
+        let total: usize = groups.iter().map(|(_, indices)| indices.len()).sum();
+        let constraints: Vec<Constraint> = groups
+            .iter()
+            .map(|(_, indices)| match self.display_mode {
+                CoreDisplayMode::Gauge => {
+                    Constraint::Length(indices.len().div_ceil(self.gauge_columns) as u16 * 3 + 2)
+                }
+                _ => Constraint::Ratio(indices.len() as u32, total.max(1) as u32),
+            })
+            .collect();
+        let areas = Layout::vertical(constraints).split(area);
+
+        groups
+            .into_iter()
+            .zip(areas.iter())
+            .map(|((title, indices), node_area)| CoreGroup {
+                title: Some(title),
+                outer: *node_area,
+                area: Block::default().borders(Borders::ALL).inner(*node_area),
+                indices,
+            })
+            .collect()
+    }
+
+    /// 묶음 테두리와 제목 렌더링
+    fn render_group_blocks(&self, frame: &mut Frame, groups: &[CoreGroup]) {
+        for group in groups {
+            if let Some(ref title) = group.title {
+                let block = Block::default()
+                    .title(title.as_str())
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.border));
+                frame.render_widget(block, group.outer);
+            }
+        }
+    }
+
+    /// 게이지 모드에서 각 코어 인덱스와 영역 (표시 순서)
+    fn gauge_cells(&self, content: Rect) -> Vec<(usize, Rect)> {
+        self.core_groups(content, &self.sorted_indices())
+            .into_iter()
+            .flat_map(|group| {
+                let rects = Self::gauge_rects(group.area, group.indices.len(), self.gauge_columns);
+                group.indices.into_iter().zip(rects)
+            })
+            .collect()
+    }
+
+    /// 게이지 모드에서 각 코어가 차지하는 영역 목록
+    pub fn gauge_rects(area: Rect, core_count: usize, columns: usize) -> Vec<Rect> {
         if core_count == 0 {
-            return;
+            return Vec::new();
         }
 
         // 코어 수에 따라 레이아웃 결정
-        let rows = (core_count as f32 / 4.0).ceil() as usize;
-        let cols = 4.min(core_count);
+        let rows = core_count.div_ceil(columns);
+        let cols = columns.min(core_count);
 
         let row_constraints: Vec<Constraint> = (0..rows)
             .map(|_| Constraint::Length(3))
@@ -63,6 +300,7 @@ impl CpuCoresView {
             .constraints(row_constraints)
             .split(area);
 
+        let mut rects = Vec::with_capacity(core_count);
         for row in 0..rows {
             let col_constraints: Vec<Constraint> =
                 (0..cols).map(|_| Constraint::Ratio(1, cols as u32)).collect();
@@ -73,24 +311,109 @@ impl CpuCoresView {
                 .split(row_chunks[row]);
 
             for col in 0..cols {
-                let idx = row * cols + col;
-                if let Some(core) = self.cores.get(idx) {
-                    core.render_gauge(frame, col_chunks[col]);
+                if row * cols + col < core_count {
+                    rects.push(col_chunks[col]);
                 }
             }
         }
+        rects
+    }
+
+    /// 화면에 표시할 코어 인덱스 순서 (정렬 모드면 현재 사용량 내림차순)
+    fn sorted_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.cores.len()).collect();
+        if self.sort_by_usage {
+            indices.sort_by(|&a, &b| self.cores[b].current().total_cmp(&self.cores[a].current()));
+        }
+        indices
+    }
+
+    /// 게이지 모드로 렌더링
+    fn render_gauges(&self, frame: &mut Frame, area: Rect, sorted_indices: &[usize]) {
+        let rects = Self::gauge_rects(area, sorted_indices.len(), self.gauge_columns);
+        for (&idx, rect) in sorted_indices.iter().zip(rects) {
+            self.cores[idx].render_gauge(frame, rect);
+        }
+    }
+
+    /// 코어 온도 조회 (센서 라벨에 "Core N"이 포함된 경우)
+    fn core_temperature(&self, idx: usize) -> Option<f32> {
+        let label = format!("Core {}", idx);
+        self.components
+            .list()
+            .iter()
+            .find(|c| c.label().ends_with(&label))
+            .and_then(|c| c.temperature())
+    }
+
+    /// 마우스가 올라간 코어의 툴팁 렌더링 (터미널 가장자리를 넘지 않도록 배치)
+    fn render_tooltip(&self, frame: &mut Frame, bounds: Rect) {
+        let Some((idx, cursor)) = self.hovered_core else {
+            return;
+        };
+        let Some(core) = self.cores.get(idx) else {
+            return;
+        };
+        let Some(gauge) = self
+            .gauge_cells(Self::content_area(bounds))
+            .into_iter()
+            .find(|&(i, _)| i == idx)
+            .map(|(_, rect)| rect)
+        else {
+            return;
+        };
+
+        let frequency = core
+            .get_frequency()
+            .map(|mhz| format!("{} MHz", mhz))
+            .unwrap_or_else(|| "N/A".to_string());
+        let temperature = self
+            .core_temperature(idx)
+            .map(|t| format!("{:.1}°C", t))
+            .unwrap_or_else(|| "N/A".to_string());
+
+        let width = TOOLTIP_WIDTH.min(bounds.width);
+        let height = TOOLTIP_HEIGHT.min(bounds.height);
+        let x = cursor
+            .x
+            .min(bounds.right().saturating_sub(width))
+            .max(bounds.x);
+        // 게이지 위에 공간이 없으면 아래쪽에 표시
+        let y = if gauge.y >= bounds.y + height {
+            gauge.y - height
+        } else {
+            gauge.bottom().min(bounds.bottom().saturating_sub(height))
+        };
+        let tooltip_area = Rect::new(x, y, width, height);
+
+        let text = format!(
+            "Current: {:.1}%\nFreq:    {}\nTemp:    {}\nMin/Max: {:.1}% / {:.1}%",
+            core.current(),
+            frequency,
+            temperature,
+            core.min(),
+            core.max(),
+        );
+        let tooltip = Paragraph::new(text).block(
+            Block::default()
+                .title(format!(" Core {} ", idx))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+
+        frame.render_widget(Clear, tooltip_area);
+        frame.render_widget(tooltip, tooltip_area);
     }
 
     /// 그래프 모드로 렌더링
-    fn render_graphs(&self, frame: &mut Frame, area: Rect) {
-        let core_count = self.cores.len();
+    fn render_graphs(&self, frame: &mut Frame, area: Rect, sorted_indices: &[usize]) {
+        let core_count = sorted_indices.len();
         if core_count == 0 {
             return;
         }
 
-        // 2열 레이아웃
-        let cols = 2;
-        let rows = (core_count as f32 / cols as f32).ceil() as usize;
+        let cols = self.graph_columns;
+        let rows = core_count.div_ceil(cols);
 
         let row_constraints: Vec<Constraint> = (0..rows)
             .map(|_| Constraint::Ratio(1, rows as u32))
@@ -111,16 +434,63 @@ impl CpuCoresView {
                 .split(row_chunks[row]);
 
             for col in 0..cols {
-                let idx = row * cols + col;
-                if let Some(core) = self.cores.get(idx) {
-                    core.render_graph(frame, col_chunks[col]);
+                if let Some(&idx) = sorted_indices.get(row * cols + col) {
+                    self.cores[idx].render_graph(frame, col_chunks[col]);
+                }
+            }
+        }
+    }
+
+    /// 스파크라인 모드로 렌더링 (영역 높이에 맞춰 열 수 결정)
+    fn render_sparklines(&self, frame: &mut Frame, area: Rect, sorted_indices: &[usize]) {
+        let core_count = sorted_indices.len();
+        if core_count == 0 {
+            return;
+        }
+
+        let rows = ((area.height / SPARKLINE_HEIGHT) as usize).max(1);
+        let cols = core_count.div_ceil(rows);
+        let col_chunks = Layout::horizontal(vec![Constraint::Ratio(1, cols as u32); cols]).split(area);
+
+        for (col, col_area) in col_chunks.iter().enumerate() {
+            let row_chunks =
+                Layout::vertical(vec![Constraint::Length(SPARKLINE_HEIGHT); rows]).split(*col_area);
+            for (row, row_area) in row_chunks.iter().enumerate() {
+                if let Some(&idx) = sorted_indices.get(col * rows + row) {
+                    self.cores[idx].render_sparkline(frame, *row_area);
                 }
             }
         }
     }
 
+    /// 히트맵 모드로 렌더링 (영역 폭에 맞춰 한 줄에 최대한 많은 코어)
+    fn render_heatmap(&self, frame: &mut Frame, area: Rect, sorted_indices: &[usize]) {
+        let usages: Vec<f64> = sorted_indices
+            .iter()
+            .map(|&idx| self.cores[idx].current())
+            .collect();
+        CoreHeatmap::new(&usages)
+            .theme(&self.theme)
+            .thresholds(self.thresholds.0, self.thresholds.1)
+            .render(frame, area);
+    }
+
+    /// 커서 위치의 코어 인덱스 (게이지 모드 기준)
+    fn core_at(&self, cursor: Position) -> Option<usize> {
+        let content = Self::content_area(self.last_area.get());
+        self.gauge_cells(content)
+            .into_iter()
+            .find(|(_, rect)| rect.contains(cursor))
+            .map(|(idx, _)| idx)
+    }
+
+    /// 게이지 → 그래프 → 스파크라인 → 히트맵 순으로 모드 전환
     pub fn toggle_mode(&mut self) {
-        self.show_graph = !self.show_graph;
+        self.display_mode = self.display_mode.next();
+    }
+
+    pub fn toggle_sort(&mut self) {
+        self.sort_by_usage = !self.sort_by_usage;
     }
 }
 
@@ -132,43 +502,168 @@ impl Default for CpuCoresView {
 
 impl super::ViewComponent for CpuCoresView {
     fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        self.last_area.set(area);
+
         // 전체 레이아웃: 타이틀 + 코어들
         let main_chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .constraints([Constraint::Length(TITLE_HEIGHT), Constraint::Min(0)])
             .split(area);
 
         // 타이틀
-        let mode = if self.show_graph { "Graph" } else { "Gauge" };
+        let mode = self.display_mode.label();
+        let order = if self.sort_by_usage { "Usage" } else { "Index" };
         let title = Paragraph::new(format!(
-            "CPU Cores Monitor ({} cores) [G: toggle mode - {}] [Tab: switch view]",
+            "CPU Cores Monitor ({} cores) [G: toggle mode - {}] [S: sort - {}] [Click: zoom core] [Tab: switch view]",
             self.cores.len(),
-            mode
-        ))
-        .block(Block::default().borders(Borders::ALL));
-        frame.render_widget(title, main_chunks[0]);
-
-        // 모드에 따라 렌더링
-        if self.show_graph {
-            self.render_graphs(frame, main_chunks[1]);
-        } else {
-            self.render_gauges(frame, main_chunks[1]);
+            mode,
+            order
+        ));
+        let title_block = Block::default().borders(Borders::ALL);
+        let title_inner = title_block.inner(main_chunks[0]);
+        frame.render_widget(title_block, main_chunks[0]);
+        let [title_line, stats_line] =
+            Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(title_inner);
+        frame.render_widget(title, title_line);
+
+        // 모든 코어의 히스토리를 합친 통계
+        let history: Vec<f64> = self
+            .cores
+            .iter()
+            .flat_map(|core| core.get_history().iter().copied())
+            .collect();
+        StatsFooter::new(&history).render(frame, stats_line);
+
+        // 표시 순서 (self.cores는 항상 원래 인덱스 순서를 유지)
+        let sorted_indices = self.sorted_indices();
+
+        // 확대된 코어가 있으면 그 코어의 그래프만 표시
+        if let Some(core) = self.zoomed_core.and_then(|idx| self.cores.get(idx)) {
+            core.render_graph(frame, main_chunks[1]);
+            return;
+        }
+
+        // 모드에 따라 렌더링 (NUMA 노드가 여러 개면 노드별 블록 안에)
+        let groups = self.core_groups(main_chunks[1], &sorted_indices);
+        self.render_group_blocks(frame, &groups);
+        for group in &groups {
+            match self.display_mode {
+                CoreDisplayMode::Gauge => self.render_gauges(frame, group.area, &group.indices),
+                CoreDisplayMode::Graph => self.render_graphs(frame, group.area, &group.indices),
+                CoreDisplayMode::Sparkline => {
+                    self.render_sparklines(frame, group.area, &group.indices)
+                }
+                CoreDisplayMode::Heatmap => self.render_heatmap(frame, group.area, &group.indices),
+            }
         }
+        if self.display_mode == CoreDisplayMode::Gauge {
+            self.render_tooltip(frame, area);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "CPU Cores"
+    }
+
+    fn title(&self) -> &str {
+        "CPU Cores"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 20)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("G", "Cycle display mode"),
+            ("S", "Toggle sort by usage"),
+            ("Click", "Zoom core / restore"),
+            ("Esc", "Leave zoom"),
+        ]
     }
 
     fn handle_key(&mut self, key: KeyCode) -> bool {
         match key {
+            KeyCode::Esc if self.zoomed_core.is_some() => {
+                self.zoomed_core = None;
+                true
+            }
             KeyCode::Char('g') | KeyCode::Char('G') => {
                 self.toggle_mode();
                 true // 이벤트 소비됨
             }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.toggle_sort();
+                true
+            }
             _ => false,
         }
     }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.theme = config.theme.clone();
+        self.thresholds = (config.low_threshold as f64, config.high_threshold as f64);
+        for core in &mut self.cores {
+            core.apply_theme(&self.theme);
+            core.set_thresholds(self.thresholds.0, self.thresholds.1);
+        }
+    }
+
+    fn on_resize(&mut self, new_area: Rect) {
+        let width = new_area.width;
+        self.gauge_columns = ((width / GAUGE_MIN_WIDTH) as usize).clamp(1, MAX_GAUGE_COLUMNS);
+        self.graph_columns = ((width / GRAPH_MIN_WIDTH) as usize).clamp(1, MAX_GRAPH_COLUMNS);
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> bool {
+        let cursor = Position::new(event.column, event.row);
+
+        // 확대 상태에서는 아무 곳이나 클릭하면 원래 화면으로
+        if event.kind == MouseEventKind::Down(MouseButton::Left) {
+            if self.zoomed_core.take().is_some() {
+                return true;
+            }
+            if self.display_mode == CoreDisplayMode::Gauge
+                && let Some(idx) = self.core_at(cursor)
+            {
+                self.zoomed_core = Some(idx);
+                self.hovered_core = None;
+                self.tooltip_dismiss_at = None;
+                return true;
+            }
+            return false;
+        }
+
+        if self.display_mode != CoreDisplayMode::Gauge
+            || self.zoomed_core.is_some()
+            || event.kind != MouseEventKind::Moved
+        {
+            return false;
+        }
+
+        match self.core_at(cursor) {
+            Some(idx) => {
+                if self.hovered_core.is_none() {
+                    self.components.refresh(false);
+                }
+                self.hovered_core = Some((idx, cursor));
+                self.tooltip_dismiss_at = None;
+            }
+            None if self.hovered_core.is_some() && self.tooltip_dismiss_at.is_none() => {
+                self.tooltip_dismiss_at = Some(Instant::now() + TOOLTIP_DISMISS_DELAY);
+            }
+            None => {}
+        }
+        true
+    }
 }
 
 impl super::TickingComponent for CpuCoresView {
     fn on_tick(&mut self) {
         self.refresh();
     }
+
+    fn preferred_tick_ms(&self) -> u64 {
+        50
+    }
 }
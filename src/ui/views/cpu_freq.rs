@@ -0,0 +1,168 @@
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::config::{Config, Theme};
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader};
+use crate::integration::cpu_freq::read_cpu_freq_bounds;
+use crate::ui::components::CoreFrequencyGraph;
+
+/// F 키로 여는 선택 코어 그래프 높이
+const GRAPH_HEIGHT: u16 = 12;
+
+/// 코어별 현재/최소/최대 클럭을 보여주는 뷰
+///
+/// 최소/최대 클럭은 sysfs `cpuinfo_{min,max}_freq`에서 읽고, 읽지 못하면 관측 최댓값을 기준으로 표시
+pub struct CpuFrequencyView {
+    metrics: SnapshotReader,
+    /// 새 코어 그래프 생성 시 적용할 테마와 히스토리 길이
+    theme: Theme,
+    history_size: usize,
+    cores: Vec<CoreFrequencyGraph>,
+    /// ↑↓로 고른 코어 (그래프와 강조 표시 대상)
+    selected: usize,
+    /// 선택 코어의 클럭 그래프 표시 여부 (F 키로 토글)
+    show_graph: bool,
+}
+
+impl CpuFrequencyView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default(), SharedSnapshot::default())
+    }
+
+    pub fn with_config(config: &Config, metrics: SharedSnapshot) -> Self {
+        let metrics = SnapshotReader::new(metrics);
+        let core_count = metrics.current().cores.len();
+        let mut view = Self {
+            metrics,
+            theme: config.theme.clone(),
+            history_size: config.history_size,
+            cores: Vec::new(),
+            selected: 0,
+            show_graph: false,
+        };
+        view.ensure_cores(core_count);
+        view
+    }
+
+    /// 코어 수를 스냅샷의 코어 수에 맞춤 (새 코어는 클럭 범위를 sysfs에서 읽음)
+    fn ensure_cores(&mut self, count: usize) {
+        while self.cores.len() < count {
+            let index = self.cores.len();
+            let mut core = CoreFrequencyGraph::new(format!("Core {}", index))
+                .with_history_size(self.history_size);
+            if let Some(bounds) = read_cpu_freq_bounds(index) {
+                core = core.with_bounds(bounds.min_mhz, bounds.max_mhz);
+            }
+            core.apply_theme(&self.theme);
+            self.cores.push(core);
+        }
+    }
+
+    /// 코어 막대 목록 (선택 코어가 보이도록 스크롤)
+    fn render_bars(&self, frame: &mut Frame, area: Rect) {
+        let rows = area.height as usize;
+        if rows == 0 {
+            return;
+        }
+        let first = self.selected.saturating_sub(rows - 1);
+        for (row, (index, core)) in self.cores.iter().enumerate().skip(first).take(rows).enumerate() {
+            let row_area = Rect::new(area.x, area.y + row as u16, area.width, 1);
+            core.render_bar(frame, row_area, index == self.selected);
+        }
+    }
+}
+
+impl Default for CpuFrequencyView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for CpuFrequencyView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let graph_height = if self.show_graph { GRAPH_HEIGHT } else { 0 };
+        let [title_area, bars_area, graph_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(graph_height),
+        ])
+        .areas(area);
+
+        let title = Paragraph::new(format!(
+            "CPU Frequency ({} cores) [↑↓: select core  F: frequency graph]",
+            self.cores.len()
+        ))
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        let block = Block::default().borders(Borders::ALL);
+        let bars_inner = block.inner(bars_area);
+        frame.render_widget(block, bars_area);
+        self.render_bars(frame, bars_inner);
+
+        if self.show_graph
+            && let Some(core) = self.cores.get(self.selected)
+        {
+            core.render_graph(frame, graph_area);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "CPU Frequency"
+    }
+
+    fn title(&self) -> &str {
+        "CPU Freq"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 20)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑ / ↓", "Select core"),
+            ("F", "Toggle frequency graph"),
+        ]
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.theme = config.theme.clone();
+        for core in &mut self.cores {
+            core.apply_theme(&self.theme);
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.cores.len().saturating_sub(1));
+                true
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.show_graph = !self.show_graph;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl super::TickingComponent for CpuFrequencyView {
+    fn on_tick(&mut self) {
+        if let Some(snapshot) = self.metrics.poll() {
+            self.ensure_cores(snapshot.cores.len());
+            for (core, data) in self.cores.iter_mut().zip(&snapshot.cores) {
+                core.push(data.frequency_mhz);
+            }
+        }
+    }
+}
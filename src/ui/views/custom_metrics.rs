@@ -0,0 +1,255 @@
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::config::{Config, Theme};
+#[cfg(unix)]
+use crate::integration::custom_metrics::CustomMetricReader;
+use crate::ui::components::usage_gauge::{format_count, UsageGraph};
+
+/// 이 시간 동안 값이 오지 않으면 그래프를 흐리게 표시
+const STALE_AFTER: Duration = Duration::from_secs(5);
+/// 그래프 한 칸 높이
+const GRAPH_HEIGHT: u16 = 8;
+/// 한 줄에 놓을 그래프 수
+const GRAPH_COLUMNS: usize = 2;
+
+/// 이름 하나에 해당하는 메트릭 그래프
+struct CustomMetric {
+    graph: UsageGraph,
+    last_seen: Instant,
+    /// 지금까지 받은 값의 범위 (y축 자동 조절용)
+    min: f64,
+    max: f64,
+}
+
+impl CustomMetric {
+    fn new(name: &str, value: f64, history_size: usize, theme: &Theme) -> Self {
+        let mut graph = UsageGraph::new(name.to_string()).with_history_size(history_size);
+        graph.apply_theme(theme);
+        graph.set_unit("");
+        let mut metric = Self {
+            graph,
+            last_seen: Instant::now(),
+            min: value,
+            max: value,
+        };
+        metric.rescale();
+        metric
+    }
+
+    fn push(&mut self, value: f64) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.rescale();
+        self.graph.push(value);
+        self.last_seen = Instant::now();
+    }
+
+    /// 받은 값이 모두 들어가도록 y축 범위 조절 (0은 항상 포함, 위쪽 10% 여유)
+    fn rescale(&mut self) {
+        let low = self.min.min(0.0);
+        let mut high = self.max.max(0.0) * 1.1;
+        if high <= low {
+            high = low + 1.0;
+        }
+        self.graph.set_y_bounds(low, high);
+        self.graph.set_y_labels(
+            format_count(low),
+            format_count((low + high) / 2.0),
+            format_count(high),
+        );
+    }
+
+    fn is_stale(&self) -> bool {
+        self.last_seen.elapsed() >= STALE_AFTER
+    }
+}
+
+/// Unix 소켓으로 받은 사용자 정의 메트릭(`custom_metrics_socket`)을 그래프 격자로 표시
+pub struct CustomMetricsView {
+    socket_path: Option<PathBuf>,
+    #[cfg(unix)]
+    reader: Option<CustomMetricReader>,
+    /// 이름순 메트릭
+    metrics: BTreeMap<String, CustomMetric>,
+    /// 맨 위에 보이는 그래프 줄
+    scroll: usize,
+    history_size: usize,
+    theme: Theme,
+}
+
+impl CustomMetricsView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성 (소켓 경로가 있으면 수신 시작)
+    pub fn with_config(config: &Config) -> Self {
+        Self {
+            socket_path: config.custom_metrics_socket.clone(),
+            #[cfg(unix)]
+            reader: config
+                .custom_metrics_socket
+                .as_ref()
+                .map(CustomMetricReader::start),
+            metrics: BTreeMap::new(),
+            scroll: 0,
+            history_size: config.history_size,
+            theme: config.theme.clone(),
+        }
+    }
+
+    /// 수신을 시작할 수 없는 경우 그 사유
+    fn status_message(&self) -> Option<String> {
+        if !cfg!(unix) {
+            return Some("Unsupported platform: custom metrics use a Unix socket".to_string());
+        }
+        let Some(ref path) = self.socket_path else {
+            return Some("No socket configured (set custom_metrics_socket in config)".to_string());
+        };
+        #[cfg(unix)]
+        if let Some(error) = self.reader.as_ref().and_then(|reader| reader.error()) {
+            return Some(error.to_string());
+        }
+        if self.metrics.is_empty() {
+            return Some(format!(
+                "Waiting for metrics on {} ({{\"name\":\"my_metric\",\"value\":42.0}} per line)",
+                path.display()
+            ));
+        }
+        None
+    }
+
+    fn row_count(&self) -> usize {
+        self.metrics.len().div_ceil(GRAPH_COLUMNS)
+    }
+
+    fn render_grid(&self, frame: &mut Frame, area: Rect) {
+        let visible_rows = (area.height / GRAPH_HEIGHT).max(1) as usize;
+        let scroll = self.scroll.min(self.row_count().saturating_sub(visible_rows));
+        let metrics: Vec<&CustomMetric> = self
+            .metrics
+            .values()
+            .skip(scroll * GRAPH_COLUMNS)
+            .take(visible_rows * GRAPH_COLUMNS)
+            .collect();
+
+        let rows = Layout::vertical(vec![Constraint::Length(GRAPH_HEIGHT); visible_rows]).split(area);
+        for (row, chunk) in rows.iter().zip(metrics.chunks(GRAPH_COLUMNS)) {
+            let cells =
+                Layout::horizontal(vec![Constraint::Fill(1); GRAPH_COLUMNS]).split(*row);
+            for (cell, metric) in cells.iter().zip(chunk) {
+                metric.graph.render(frame, *cell);
+                if metric.is_stale() {
+                    frame
+                        .buffer_mut()
+                        .set_style(*cell, Style::default().add_modifier(Modifier::DIM));
+                }
+            }
+        }
+    }
+}
+
+impl Default for CustomMetricsView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for CustomMetricsView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let [title_area, grid_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let title = Paragraph::new(format!(
+            "Custom Metrics ({}) [↑↓: scroll] [Tab: switch view]",
+            self.metrics.len()
+        ))
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        match self.status_message() {
+            Some(message) => {
+                let message = Paragraph::new(message)
+                    .style(Style::default().fg(Color::Gray))
+                    .centered()
+                    .block(Block::default().borders(Borders::ALL));
+                frame.render_widget(message, grid_area);
+            }
+            None => self.render_grid(frame, grid_area),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Custom Metrics"
+    }
+
+    fn title(&self) -> &str {
+        "Custom"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 12)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[("↑ / ↓", "Scroll graphs")]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => {
+                self.scroll = (self.scroll + 1).min(self.row_count().saturating_sub(1))
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        // 소켓 경로가 바뀐 경우에만 수신기를 다시 시작
+        if self.socket_path != config.custom_metrics_socket {
+            *self = Self::with_config(config);
+            return;
+        }
+        self.theme = config.theme.clone();
+        for metric in self.metrics.values_mut() {
+            metric.graph.apply_theme(&config.theme);
+        }
+    }
+}
+
+impl super::TickingComponent for CustomMetricsView {
+    fn on_tick(&mut self) {
+        #[cfg(unix)]
+        if let Some(ref reader) = self.reader {
+            for sample in reader.poll() {
+                match self.metrics.get_mut(&sample.name) {
+                    Some(metric) => metric.push(sample.value),
+                    None => {
+                        let mut metric = CustomMetric::new(
+                            &sample.name,
+                            sample.value,
+                            self.history_size,
+                            &self.theme,
+                        );
+                        metric.push(sample.value);
+                        self.metrics.insert(sample.name, metric);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::sync::{Arc, Mutex};
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Row, Table},
+    Frame,
+};
+
+use crate::config::Config;
+use crate::ui::components::usage_gauge::UsageGraph;
+
+/// 프레임 시간 그래프에 보관할 tick 수
+const FRAME_HISTORY_TICKS: usize = 60;
+/// 최근 화면 클리어로 표시할 tick 범위
+const RECENT_CLEAR_TICKS: u64 = 10;
+/// 프레임 시간 그래프 y축 최댓값 (ms)
+const MAX_FRAME_MS: f64 = 50.0;
+
+/// `run_loop`가 기록하는 앱 내부 지표
+#[derive(Clone, Debug, Default)]
+pub struct AppMetrics {
+    /// 직전 프레임 draw 시간 (ms)
+    pub last_frame_ms: f64,
+    /// 현재 동적 tick 간격 (ms)
+    pub tick_rate_ms: u64,
+    pub total_ticks: u64,
+    /// tick 간격의 두 배 이상 밀려 건너뛴 tick 수
+    pub missed_ticks: u64,
+    pub view_switches: u64,
+    /// 터미널 크기 (열, 행)
+    pub terminal_size: (u16, u16),
+    /// 마지막으로 화면을 클리어한 시점의 `total_ticks`
+    pub last_clear_tick: Option<u64>,
+}
+
+pub type SharedAppMetrics = Arc<Mutex<AppMetrics>>;
+
+/// 프레임 시간, tick 지터 등 앱 내부 지표 뷰 (! 키로 토글)
+pub struct DebugView {
+    shared: SharedAppMetrics,
+    /// 마지막 tick에 복사한 지표
+    metrics: AppMetrics,
+    frame_graph: UsageGraph,
+}
+
+impl DebugView {
+    pub fn new(shared: SharedAppMetrics) -> Self {
+        Self::with_config(&Config::default(), shared)
+    }
+
+    pub fn with_config(config: &Config, shared: SharedAppMetrics) -> Self {
+        // 목표 프레임 시간 이하는 초록, 두 배까지 노랑
+        let target_ms = config.target_frame_ms() as f64;
+        let mut frame_graph = UsageGraph::new("Frame Time")
+            .with_history_size(FRAME_HISTORY_TICKS)
+            .with_thresholds(target_ms, target_ms * 2.0);
+        frame_graph.apply_theme(&config.theme);
+        frame_graph.set_y_bounds(0.0, MAX_FRAME_MS);
+        frame_graph.set_y_labels("0", "25", "50 ms");
+        frame_graph.set_unit(" ms");
+
+        Self {
+            shared,
+            metrics: AppMetrics::default(),
+            frame_graph,
+        }
+    }
+
+    /// 최근 `RECENT_CLEAR_TICKS` tick 안에 화면 클리어가 있었는지
+    fn cleared_recently(&self) -> bool {
+        self.metrics
+            .last_clear_tick
+            .is_some_and(|tick| self.metrics.total_ticks.saturating_sub(tick) < RECENT_CLEAR_TICKS)
+    }
+
+    fn render_table(&self, frame: &mut Frame, area: Rect) {
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+        let metrics = &self.metrics;
+        let (cols, rows) = metrics.terminal_size;
+        let cleared = if self.cleared_recently() {
+            Span::styled("yes", Style::default().fg(Color::Yellow))
+        } else {
+            Span::raw("no")
+        };
+
+        let table_rows = vec![
+            Row::new(vec![
+                label("Last Frame"),
+                Span::raw(format!("{:.2} ms", metrics.last_frame_ms)),
+            ]),
+            Row::new(vec![
+                label("Tick Rate"),
+                Span::raw(format!("{} ms", metrics.tick_rate_ms)),
+            ]),
+            Row::new(vec![label("Total Ticks"), Span::raw(metrics.total_ticks.to_string())]),
+            Row::new(vec![
+                label("Missed Ticks"),
+                Span::raw(metrics.missed_ticks.to_string()),
+            ]),
+            Row::new(vec![
+                label("View Switches"),
+                Span::raw(metrics.view_switches.to_string()),
+            ]),
+            Row::new(vec![label("Terminal"), Span::raw(format!("{}×{}", cols, rows))]),
+            Row::new(vec![label("Recent Clear"), cleared]),
+        ];
+
+        let table = Table::new(table_rows, [Constraint::Length(16), Constraint::Fill(1)]).block(
+            Block::default()
+                .title(" App Metrics ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, area);
+    }
+}
+
+impl Default for DebugView {
+    fn default() -> Self {
+        Self::new(SharedAppMetrics::default())
+    }
+}
+
+impl super::ViewComponent for DebugView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(" Debug ")
+            .title_bottom(" !: close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [table_area, graph_area] =
+            Layout::horizontal([Constraint::Length(36), Constraint::Fill(1)]).areas(inner);
+        self.render_table(frame, table_area);
+        self.frame_graph.render(frame, graph_area);
+    }
+
+    fn name(&self) -> &str {
+        "Debug"
+    }
+
+    fn title(&self) -> &str {
+        "Debug"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (50, 16)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        let target_ms = config.target_frame_ms() as f64;
+        self.frame_graph.apply_theme(&config.theme);
+        self.frame_graph.set_thresholds(target_ms, target_ms * 2.0);
+    }
+}
+
+impl super::TickingComponent for DebugView {
+    fn on_tick(&mut self) {
+        if let Ok(shared) = self.shared.lock() {
+            self.metrics = shared.clone();
+        }
+        self.frame_graph.push(self.metrics.last_frame_ms);
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Gauge, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::config::{Config, Theme};
+use crate::integration::smart::{SmartReader, SmartReport};
+
+/// SMART 정보는 자주 바뀌지 않으므로 1분마다 다시 조회
+const SMART_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+fn format_optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| v.to_string())
+}
+
+/// smartctl 기반 디스크 건강 상태 뷰
+pub struct DiskHealthView {
+    reader: SmartReader,
+    /// smartctl 설치 여부
+    available: bool,
+    reports: Vec<SmartReport>,
+    /// 백그라운드 조회 결과 수신 (조회 중일 때만 Some)
+    pending: Option<Receiver<Vec<SmartReport>>>,
+    last_scan: Option<Instant>,
+    theme: Theme,
+}
+
+impl DiskHealthView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성
+    pub fn with_config(config: &Config) -> Self {
+        Self {
+            reader: SmartReader::new(),
+            available: SmartReader::is_available(),
+            reports: Vec::new(),
+            pending: None,
+            last_scan: None,
+            theme: config.theme.clone(),
+        }
+    }
+
+    /// smartctl 실행은 느리므로 별도 스레드에서 조회
+    fn refresh(&mut self) {
+        if let Some(ref receiver) = self.pending
+            && let Ok(reports) = receiver.try_recv()
+        {
+            self.reports = reports;
+            self.pending = None;
+        }
+
+        if !self.available
+            || self.pending.is_some()
+            || self
+                .last_scan
+                .is_some_and(|t| t.elapsed() < SMART_REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.last_scan = Some(Instant::now());
+
+        let (sender, receiver) = mpsc::channel();
+        let reader = self.reader.clone();
+        thread::spawn(move || {
+            let _ = sender.send(reader.read_all());
+        });
+        self.pending = Some(receiver);
+    }
+
+    /// 가장 나쁜 디스크의 건강도 (조회에 성공한 디스크 기준)
+    fn overall_health(&self) -> Option<f64> {
+        self.reports
+            .iter()
+            .filter(|report| report.error.is_none())
+            .map(SmartReport::health_percent)
+            .reduce(f64::min)
+    }
+
+    fn render_gauge(&self, frame: &mut Frame, area: Rect) {
+        let (percent, label) = match self.overall_health() {
+            Some(health) => (health, format!("{:.0}% (worst disk)", health)),
+            None if self.pending.is_some() => (0.0, "Scanning...".to_string()),
+            None => (0.0, "No SMART data".to_string()),
+        };
+        // 건강도는 높을수록 좋으므로 사용량 기준과 반대로 색상 선택
+        let color = if percent >= 90.0 {
+            self.theme.low
+        } else if percent >= 60.0 {
+            self.theme.mid
+        } else {
+            self.theme.high
+        };
+        let gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .title("Overall Health")
+                    .borders(Borders::ALL),
+            )
+            .gauge_style(Style::default().fg(color))
+            .percent(percent as u16)
+            .label(label);
+        frame.render_widget(gauge, area);
+    }
+
+    fn report_row(&self, report: &SmartReport) -> Row<'static> {
+        if let Some(ref error) = report.error {
+            return Row::new(vec![
+                Span::raw(report.device.clone()),
+                Span::styled(error.clone(), Style::default().fg(self.theme.high)),
+            ]);
+        }
+        let status = match report.passed {
+            Some(true) => Span::styled("PASSED", Style::default().fg(self.theme.low)),
+            Some(false) => Span::styled("FAILED", Style::default().fg(self.theme.high)),
+            None => Span::raw("-"),
+        };
+        Row::new(vec![
+            Span::raw(report.device.clone()),
+            Span::raw(report.model.clone().unwrap_or_else(|| "-".to_string())),
+            status,
+            Span::raw(format_optional(report.reallocated_sectors)),
+            Span::raw(format_optional(report.pending_sectors)),
+            Span::raw(format_optional(report.offline_uncorrectable)),
+            Span::raw(report.temperature_c.map_or_else(|| "-".to_string(), |t| format!("{}°C", t))),
+            Span::raw(format_optional(report.power_on_hours)),
+            Span::raw(format!("{:.0}%", report.health_percent())),
+        ])
+    }
+
+    fn render_table(&self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row> = self.reports.iter().map(|r| self.report_row(r)).collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(14),
+                Constraint::Fill(1),
+                Constraint::Length(7),
+                Constraint::Length(8),
+                Constraint::Length(8),
+                Constraint::Length(7),
+                Constraint::Length(6),
+                Constraint::Length(8),
+                Constraint::Length(7),
+            ],
+        )
+        .header(
+            Row::new(vec![
+                "Device", "Model", "Status", "Realloc", "Pending", "Uncorr", "Temp", "Hours",
+                "Health",
+            ])
+            .style(Style::default().fg(self.theme.title).bold()),
+        )
+        .block(
+            Block::default()
+                .title(format!(" Disks ({}) ", self.reader.devices().len()))
+                .borders(Borders::ALL),
+        );
+        frame.render_widget(table, area);
+    }
+}
+
+impl Default for DiskHealthView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for DiskHealthView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(" Disk Health (SMART) ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if !self.available {
+            let message = Paragraph::new(
+                "smartctl not found. Install smartmontools (e.g. `sudo apt install smartmontools`) \
+                 to see disk health.",
+            )
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: true })
+            .centered();
+            frame.render_widget(message, inner);
+            return;
+        }
+
+        let [gauge_area, table_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(inner);
+        self.render_gauge(frame, gauge_area);
+        self.render_table(frame, table_area);
+    }
+
+    fn name(&self) -> &str {
+        "Disk Health"
+    }
+
+    fn title(&self) -> &str {
+        "Disk Health"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (70, 16)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.theme = config.theme.clone();
+    }
+}
+
+impl super::TickingComponent for DiskHealthView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
@@ -0,0 +1,249 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use sysinfo::Disks;
+
+use crate::config::{Config, Theme};
+use crate::ui::components::usage_gauge::{ThroughputGraph, UsageGauge};
+
+/// 디스크 목록과 I/O 카운터를 다시 읽는 간격
+const DISK_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 바이트 크기를 GB/TB 문자열로 변환
+fn format_capacity(bytes: u64) -> String {
+    let gb = bytes as f64 / 1024.0 / 1024.0 / 1024.0;
+    if gb >= 1024.0 {
+        format!("{:.1}TB", gb / 1024.0)
+    } else {
+        format!("{:.1}GB", gb)
+    }
+}
+
+/// 디스크 한 개의 용량 정보
+struct DiskCapacity {
+    mount_point: String,
+    file_system: String,
+    total: u64,
+    gauge: UsageGauge,
+}
+
+/// 디스크 I/O 속도 그래프와 용량 표 (D 키로 전환)
+pub struct DiskMonitorView {
+    disks: Disks,
+    /// (디스크 이름, 읽기/쓰기 그래프) - 이름순 정렬
+    io_graphs: Vec<(String, ThroughputGraph)>,
+    capacities: Vec<DiskCapacity>,
+    /// true면 용량 표, false면 I/O 그래프
+    show_capacity: bool,
+    /// 새 디스크 그래프 생성 시 적용할 테마와 히스토리 길이
+    theme: Theme,
+    history_size: usize,
+    /// 마지막 갱신 시각 (I/O 속도 계산 구간)
+    last_refresh: Instant,
+}
+
+impl DiskMonitorView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성
+    pub fn with_config(config: &Config) -> Self {
+        let mut view = Self {
+            disks: Disks::new_with_refreshed_list(),
+            io_graphs: Vec::new(),
+            capacities: Vec::new(),
+            show_capacity: false,
+            theme: config.theme.clone(),
+            history_size: config.history_size,
+            last_refresh: Instant::now(),
+        };
+        // I/O 속도는 첫 구간이 지나야 계산되지만 용량은 바로 표시
+        view.refresh_capacities();
+        view
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.show_capacity = !self.show_capacity;
+    }
+
+    fn refresh(&mut self) {
+        let elapsed = self.last_refresh.elapsed();
+        if elapsed < DISK_REFRESH_INTERVAL {
+            return;
+        }
+        self.disks.refresh(true);
+        self.last_refresh = Instant::now();
+        let elapsed = elapsed.as_secs_f64();
+        if elapsed <= 0.0 {
+            return;
+        }
+
+        // 같은 장치가 여러 마운트 지점에 잡힐 수 있으므로 이름당 한 번만 집계
+        let mut seen = Vec::new();
+        for disk in self.disks.list() {
+            let name = disk.name().to_string_lossy().into_owned();
+            if seen.contains(&name) {
+                continue;
+            }
+            let usage = disk.usage();
+            let index = match self.io_graphs.binary_search_by(|(n, _)| n.cmp(&name)) {
+                Ok(index) => index,
+                Err(index) => {
+                    let mut graph = ThroughputGraph::new(name.clone(), "Read", "Write")
+                        .with_history_size(self.history_size);
+                    graph.apply_theme(&self.theme);
+                    self.io_graphs.insert(index, (name.clone(), graph));
+                    index
+                }
+            };
+            self.io_graphs[index].1.push(
+                usage.read_bytes as f64 / elapsed,
+                usage.written_bytes as f64 / elapsed,
+            );
+            seen.push(name);
+        }
+        self.io_graphs.retain(|(name, _)| seen.contains(name));
+        self.refresh_capacities();
+    }
+
+    /// 마운트 지점별 용량 게이지 다시 계산
+    fn refresh_capacities(&mut self) {
+        self.capacities = self
+            .disks
+            .list()
+            .iter()
+            .map(|disk| {
+                let total = disk.total_space();
+                let used = total.saturating_sub(disk.available_space());
+                // 디스크는 어느 정도 차 있는 것이 정상이므로 기준을 높게
+                let mut gauge = UsageGauge::new("").with_thresholds(75.0, 90.0);
+                gauge.apply_theme(&self.theme);
+                if total > 0 {
+                    gauge.set_usage(used as f64 / total as f64 * 100.0);
+                }
+                DiskCapacity {
+                    mount_point: disk.mount_point().to_string_lossy().into_owned(),
+                    file_system: disk.file_system().to_string_lossy().into_owned(),
+                    total,
+                    gauge,
+                }
+            })
+            .collect();
+    }
+
+    fn render_io_graphs(&self, frame: &mut Frame, area: Rect) {
+        if self.io_graphs.is_empty() {
+            let message = Paragraph::new("No disks").style(Style::default().fg(Color::Gray));
+            frame.render_widget(message, area);
+            return;
+        }
+
+        let rows = Layout::vertical(vec![
+            Constraint::Ratio(1, self.io_graphs.len() as u32);
+            self.io_graphs.len()
+        ])
+        .split(area);
+        for (row, (_, graph)) in rows.iter().zip(&self.io_graphs) {
+            graph.render(frame, *row);
+        }
+    }
+
+    fn render_capacity_table(&self, frame: &mut Frame, area: Rect) {
+        // 게이지 테두리 때문에 행당 3줄 사용
+        let rows = Layout::vertical(vec![Constraint::Length(3); self.capacities.len()])
+            .split(area);
+
+        for (row, capacity) in rows.iter().zip(&self.capacities) {
+            let [info_area, gauge_area] =
+                Layout::horizontal([Constraint::Length(40), Constraint::Fill(1)]).areas(*row);
+
+            let info = Paragraph::new(vec![
+                Line::from(Span::styled(
+                    capacity.mount_point.clone(),
+                    Style::default().fg(Color::White).bold(),
+                )),
+                Line::from(Span::styled(
+                    format!("{}  {}", capacity.file_system, format_capacity(capacity.total)),
+                    Style::default().fg(Color::Gray),
+                )),
+            ]);
+            frame.render_widget(info, info_area);
+            capacity.gauge.render(frame, gauge_area);
+        }
+    }
+}
+
+impl Default for DiskMonitorView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for DiskMonitorView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let mode = if self.show_capacity { "Capacity" } else { "I/O" };
+        let block = Block::default()
+            .title(format!(" Disk Monitor - {} ", mode))
+            .title_bottom(" D: toggle I/O / capacity ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.show_capacity {
+            self.render_capacity_table(frame, inner);
+        } else {
+            self.render_io_graphs(frame, inner);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Disk Monitor"
+    }
+
+    fn title(&self) -> &str {
+        "Disks"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 16)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("D", "Toggle I/O / capacity"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.toggle_mode();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.theme = config.theme.clone();
+        for (_, graph) in &mut self.io_graphs {
+            graph.apply_theme(&self.theme);
+        }
+    }
+}
+
+impl super::TickingComponent for DiskMonitorView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
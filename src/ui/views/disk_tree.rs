@@ -0,0 +1,410 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use walkdir::WalkDir;
+
+use crate::config::Config;
+use crate::ui::components::FilterBar;
+
+/// 스캔 중 표시할 스피너 문자
+const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+/// 잠금 횟수를 줄이기 위해 이만큼 모아서 트리에 반영
+const SCAN_BATCH: usize = 512;
+const PATH_DIALOG_WIDTH: u16 = 60;
+
+/// 바이트 크기를 읽기 쉬운 단위로 변환
+fn format_size(bytes: u64) -> String {
+    let kb = bytes as f64 / 1024.0;
+    if kb < 1.0 {
+        format!("{} B", bytes)
+    } else if kb < 1024.0 {
+        format!("{:.1} KB", kb)
+    } else if kb < 1024.0 * 1024.0 {
+        format!("{:.1} MB", kb / 1024.0)
+    } else {
+        format!("{:.1} GB", kb / 1024.0 / 1024.0)
+    }
+}
+
+/// 앞의 `~`를 홈 디렉터리로 치환
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), std::env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// 디렉터리 하나 (크기는 하위 파일 전체 합)
+struct DirNode {
+    name: String,
+    size: u64,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    expanded: bool,
+}
+
+/// 스캔하면서 채워지는 디렉터리 트리 (0번 노드가 루트)
+pub struct DirTree {
+    nodes: Vec<DirNode>,
+    index: HashMap<PathBuf, usize>,
+    scanning: bool,
+    /// 권한 부족 등으로 읽지 못한 항목 수
+    errors: u64,
+}
+
+pub type SharedDirTree = Arc<Mutex<DirTree>>;
+
+/// 백그라운드 스캔 스레드가 모아서 보내는 항목
+enum ScanEntry {
+    Dir(PathBuf),
+    /// (상위 디렉터리, 파일 크기)
+    File(PathBuf, u64),
+    Error,
+}
+
+impl DirTree {
+    fn new(root: &Path) -> Self {
+        let mut index = HashMap::new();
+        index.insert(root.to_path_buf(), 0);
+        Self {
+            nodes: vec![DirNode {
+                name: root.display().to_string(),
+                size: 0,
+                parent: None,
+                children: Vec::new(),
+                expanded: true,
+            }],
+            index,
+            scanning: true,
+            errors: 0,
+        }
+    }
+
+    fn apply(&mut self, entry: ScanEntry) {
+        match entry {
+            ScanEntry::Dir(path) => {
+                // walkdir는 디렉터리를 내용보다 먼저 돌려주므로 상위 노드가 항상 있음
+                let Some(&parent) = path.parent().and_then(|p| self.index.get(p)) else {
+                    return;
+                };
+                let id = self.nodes.len();
+                self.nodes.push(DirNode {
+                    name: path
+                        .file_name()
+                        .map_or_else(String::new, |n| n.to_string_lossy().into_owned()),
+                    size: 0,
+                    parent: Some(parent),
+                    children: Vec::new(),
+                    expanded: false,
+                });
+                self.nodes[parent].children.push(id);
+                self.index.insert(path, id);
+            }
+            ScanEntry::File(dir, size) => {
+                let mut current = self.index.get(&dir).copied();
+                while let Some(id) = current {
+                    self.nodes[id].size += size;
+                    current = self.nodes[id].parent;
+                }
+            }
+            ScanEntry::Error => self.errors += 1,
+        }
+    }
+
+    /// 펼쳐진 노드를 깊이 우선으로 나열한 (노드, 깊이) - 자식은 크기 내림차순
+    fn visible(&self) -> Vec<(usize, usize)> {
+        let mut rows = Vec::new();
+        let mut stack = vec![(0, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            rows.push((id, depth));
+            let node = &self.nodes[id];
+            if node.expanded {
+                let mut children = node.children.clone();
+                // 스택에서 꺼내는 순서가 반대이므로 오름차순으로 쌓음
+                children.sort_by_key(|&child| self.nodes[child].size);
+                stack.extend(children.into_iter().map(|child| (child, depth + 1)));
+            }
+        }
+        rows
+    }
+
+    fn toggle(&mut self, id: usize) {
+        if let Some(node) = self.nodes.get_mut(id) {
+            node.expanded = !node.expanded;
+        }
+    }
+}
+
+/// `root` 아래를 훑으며 트리 갱신 (stop이 켜지면 중단)
+fn scan(root: PathBuf, tree: SharedDirTree, stop: Arc<AtomicBool>) {
+    let flush = |batch: &mut Vec<ScanEntry>| {
+        if let Ok(mut tree) = tree.lock() {
+            for entry in batch.drain(..) {
+                tree.apply(entry);
+            }
+        }
+    };
+
+    let mut batch = Vec::with_capacity(SCAN_BATCH);
+    for entry in WalkDir::new(&root).min_depth(1) {
+        if stop.load(Ordering::Relaxed) {
+            return;
+        }
+        batch.push(match entry {
+            Ok(entry) if entry.file_type().is_dir() => ScanEntry::Dir(entry.into_path()),
+            Ok(entry) => match (entry.path().parent(), entry.metadata()) {
+                (Some(dir), Ok(metadata)) => ScanEntry::File(dir.to_path_buf(), metadata.len()),
+                _ => ScanEntry::Error,
+            },
+            Err(_) => ScanEntry::Error,
+        });
+        if batch.len() >= SCAN_BATCH {
+            flush(&mut batch);
+        }
+    }
+    flush(&mut batch);
+    if let Ok(mut tree) = tree.lock() {
+        tree.scanning = false;
+    }
+}
+
+/// 디렉터리별 사용량 트리 뷰 - Enter로 접기/펼치기, O로 시작 경로 변경
+pub struct DiskUsageTreeView {
+    root: PathBuf,
+    tree: SharedDirTree,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+    selected: usize,
+    ticks: usize,
+    /// 시작 경로 입력창 (O로 열림)
+    path_input: FilterBar,
+}
+
+impl DiskUsageTreeView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정의 `disk_tree_root`를 시작 경로로 생성 (스캔은 처음 표시될 때 시작)
+    pub fn with_config(config: &Config) -> Self {
+        let root = expand_home(&config.disk_tree_root);
+        Self {
+            tree: Arc::new(Mutex::new(DirTree::new(&root))),
+            root,
+            stop: Arc::new(AtomicBool::new(false)),
+            handle: None,
+            selected: 0,
+            ticks: 0,
+            path_input: FilterBar::new().with_label("Path"),
+        }
+    }
+
+    /// 진행 중인 스캔을 멈추고 새 경로로 다시 시작
+    fn start_scan(&mut self, root: PathBuf) {
+        self.stop_scan();
+
+        self.root = root.clone();
+        self.tree = Arc::new(Mutex::new(DirTree::new(&root)));
+        self.stop = Arc::new(AtomicBool::new(false));
+        self.selected = 0;
+
+        let tree = self.tree.clone();
+        let stop = self.stop.clone();
+        self.handle = Some(thread::spawn(move || scan(root, tree, stop)));
+    }
+
+    fn stop_scan(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+
+    /// 선택된 행의 노드 펼치기/접기
+    fn toggle_selected(&mut self) {
+        if let Ok(mut tree) = self.tree.lock()
+            && let Some(&(id, _)) = tree.visible().get(self.selected)
+        {
+            tree.toggle(id);
+        }
+    }
+
+    fn visible_count(&self) -> usize {
+        self.tree.lock().map_or(0, |tree| tree.visible().len())
+    }
+
+    fn render_tree(&self, frame: &mut Frame, area: Rect) {
+        let Ok(tree) = self.tree.lock() else {
+            return;
+        };
+
+        let items: Vec<ListItem> = tree
+            .visible()
+            .into_iter()
+            .map(|(id, depth)| {
+                let node = &tree.nodes[id];
+                let marker = if node.children.is_empty() {
+                    "  "
+                } else if node.expanded {
+                    "▾ "
+                } else {
+                    "▸ "
+                };
+                ListItem::new(format!(
+                    "{}{}{}  {}",
+                    "  ".repeat(depth),
+                    marker,
+                    node.name,
+                    format_size(node.size)
+                ))
+            })
+            .collect();
+
+        let status = if tree.scanning {
+            format!("[{}] scanning", SPINNER[self.ticks % SPINNER.len()])
+        } else {
+            "done".to_string()
+        };
+        let errors = if tree.errors > 0 {
+            format!(", {} unreadable", tree.errors)
+        } else {
+            String::new()
+        };
+
+        let list = List::new(items)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(
+                Block::default()
+                    .title(format!(
+                        " {} - {} ({}{}) ",
+                        self.root.display(),
+                        format_size(tree.nodes[0].size),
+                        status,
+                        errors
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn render_path_dialog(&self, frame: &mut Frame, area: Rect) {
+        let width = PATH_DIALOG_WIDTH.min(area.width);
+        let height = 3.min(area.height);
+        let dialog_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        let block = Block::default()
+            .title(" Root path (Enter: scan, Esc: cancel) ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        frame.render_widget(Clear, dialog_area);
+        self.path_input.render(frame, block.inner(dialog_area));
+        frame.render_widget(block, dialog_area);
+    }
+}
+
+impl Default for DiskUsageTreeView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for DiskUsageTreeView {
+    fn drop(&mut self) {
+        self.stop_scan();
+    }
+}
+
+impl super::ViewComponent for DiskUsageTreeView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let [title_area, tree_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let title = Paragraph::new(
+            "Disk Usage Tree [Enter: expand/collapse] [O: change root] [↑↓: select] [Tab: switch view]",
+        )
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        self.render_tree(frame, tree_area);
+        if self.path_input.is_focused() {
+            self.render_path_dialog(frame, area);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Disk Usage Tree"
+    }
+
+    fn title(&self) -> &str {
+        "Disk Tree"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 12)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑ / ↓", "Select directory"),
+            ("Enter", "Expand / collapse"),
+            ("O", "Change root path"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        // 경로 입력 중에는 모든 키를 입력창으로 (Enter: 적용, Esc: 취소)
+        if self.path_input.is_focused() {
+            let submitted = key == KeyCode::Enter;
+            self.path_input.handle_key(key);
+            if submitted && !self.path_input.is_empty() {
+                let root = expand_home(Path::new(self.path_input.text()));
+                self.start_scan(root);
+            }
+            return true;
+        }
+
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.visible_count().saturating_sub(1));
+            }
+            KeyCode::Enter => self.toggle_selected(),
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                self.path_input.set_text(self.root.display().to_string());
+                self.path_input.focus();
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl super::TickingComponent for DiskUsageTreeView {
+    fn on_tick(&mut self) {
+        if self.handle.is_none() {
+            self.start_scan(self.root.clone());
+        }
+        self.ticks = self.ticks.wrapping_add(1);
+        self.path_input.tick();
+    }
+}
@@ -0,0 +1,168 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Gauge, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::config::{Config, Theme};
+use crate::integration::docker::DockerMonitor;
+
+/// 바이트 크기를 읽기 쉬운 단위로 변환
+fn format_size(bytes: u64) -> String {
+    let mb = bytes as f64 / 1024.0 / 1024.0;
+    if mb < 1024.0 {
+        format!("{:.0} MB", mb)
+    } else {
+        format!("{:.1} GB", mb / 1024.0)
+    }
+}
+
+/// Docker 소켓으로 조회한 실행 중인 컨테이너별 CPU/메모리 사용량 표
+pub struct DockerView {
+    /// 처음 tick될 때 조회 스레드 시작
+    monitor: Option<DockerMonitor>,
+    theme: Theme,
+    thresholds: (f64, f64),
+}
+
+impl DockerView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성
+    pub fn with_config(config: &Config) -> Self {
+        Self {
+            monitor: None,
+            theme: config.theme.clone(),
+            thresholds: (config.low_threshold as f64, config.high_threshold as f64),
+        }
+    }
+
+    fn gauge(&self, percent: f64, label: String) -> Gauge<'static> {
+        let color = self
+            .theme
+            .level_color(percent, self.thresholds.0, self.thresholds.1);
+        Gauge::default()
+            .gauge_style(Style::default().fg(color))
+            .ratio((percent / 100.0).clamp(0.0, 1.0))
+            .label(label)
+    }
+
+    fn render_table(&self, frame: &mut Frame, area: Rect, monitor: &DockerMonitor) {
+        let containers = monitor.containers();
+        let block = Block::default()
+            .title(format!(" Running Containers ({}) ", containers.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue));
+        let inner = block.inner(area);
+
+        let capacity = inner.height.saturating_sub(1) as usize;
+        let rows: Vec<Row> = containers
+            .iter()
+            .take(capacity)
+            .map(|container| Row::new(vec![container.name.clone(), String::new(), String::new()]))
+            .collect();
+        let widths = [Constraint::Fill(1), Constraint::Fill(1), Constraint::Fill(1)];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Name", "CPU", "Memory"])
+                    .style(Style::default().fg(Color::Cyan).bold()),
+            )
+            .block(block);
+        frame.render_widget(table, area);
+
+        // 표 셀에는 위젯을 넣을 수 없으므로 CPU/메모리 열 위치에 행마다 게이지를 겹쳐 그림
+        let columns = Layout::horizontal(widths).spacing(1).split(inner);
+        for (i, container) in containers.iter().take(capacity).enumerate() {
+            let y = inner.y + 1 + i as u16;
+            let cpu_area = Rect::new(columns[1].x, y, columns[1].width, 1);
+            let mem_area = Rect::new(columns[2].x, y, columns[2].width, 1);
+            // 여러 코어를 쓰면 100%를 넘으므로 게이지는 가득 채우고 값은 그대로 표시
+            frame.render_widget(
+                self.gauge(container.cpu_pct, format!("{:.1}%", container.cpu_pct)),
+                cpu_area,
+            );
+            let mem_label = if container.mem_limit > 0 {
+                format!(
+                    "{} / {}",
+                    format_size(container.mem_used),
+                    format_size(container.mem_limit)
+                )
+            } else {
+                format_size(container.mem_used)
+            };
+            frame.render_widget(self.gauge(container.mem_percent(), mem_label), mem_area);
+        }
+    }
+
+    fn render_message(frame: &mut Frame, area: Rect, message: String) {
+        let message = Paragraph::new(message)
+            .style(Style::default().fg(Color::Gray))
+            .centered()
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(message, area);
+    }
+}
+
+impl Default for DockerView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for DockerView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let [title_area, table_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let title = Paragraph::new("Docker Containers (refreshed every 2s) [Tab: switch view]")
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        match self.monitor {
+            Some(ref monitor) if monitor.error().is_some() => Self::render_message(
+                frame,
+                table_area,
+                format!("Docker unavailable: {}", monitor.error().unwrap_or_default()),
+            ),
+            Some(ref monitor) if !monitor.has_received() => {
+                Self::render_message(frame, table_area, "Connecting to Docker...".to_string())
+            }
+            Some(ref monitor) if monitor.containers().is_empty() => {
+                Self::render_message(frame, table_area, "No running containers".to_string())
+            }
+            Some(ref monitor) => self.render_table(frame, table_area, monitor),
+            None => Self::render_message(frame, table_area, "Connecting to Docker...".to_string()),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Docker"
+    }
+
+    fn title(&self) -> &str {
+        "Docker"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 10)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.theme = config.theme.clone();
+        self.thresholds = (config.low_threshold as f64, config.high_threshold as f64);
+    }
+}
+
+impl super::TickingComponent for DockerView {
+    fn on_tick(&mut self) {
+        // 조회는 스레드가 2초마다 하므로 여기서는 결과만 가져옴
+        self.monitor.get_or_insert_with(DockerMonitor::start).poll();
+    }
+
+    fn preferred_tick_ms(&self) -> u64 {
+        500
+    }
+}
@@ -0,0 +1,190 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::config::{Config, Theme};
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader};
+use crate::integration::cpu_topology::PhysicalCore;
+use crate::ui::components::UsageGauge;
+use crate::ui::views::cpu_cores::CpuCoresView;
+
+/// 논리 CPU 게이지 하나의 최소 폭
+const GAUGE_MIN_WIDTH: u16 = 14;
+/// 물리 코어 블록 높이 (블록 테두리 2줄 + 게이지 3줄)
+const CORE_BLOCK_HEIGHT: u16 = 5;
+/// 토폴로지를 모를 때 평면 레이아웃의 열 수 (CpuCoresView 기본값과 같음)
+const FLAT_COLUMNS: usize = 4;
+
+/// 물리 코어별로 하이퍼스레딩 형제 논리 CPU를 묶어 보여주는 뷰
+///
+/// sysfs `thread_siblings_list`를 읽지 못하면 CpuCoresView와 같은 평면 레이아웃으로 표시
+pub struct GroupedCpuView {
+    metrics: SnapshotReader,
+    theme: Theme,
+    thresholds: (f64, f64),
+    /// 물리 코어 목록 (토폴로지를 모르면 None)
+    physical_cores: Option<Vec<PhysicalCore>>,
+    /// 논리 CPU 번호 순 게이지
+    gauges: Vec<UsageGauge>,
+}
+
+impl GroupedCpuView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default(), SharedSnapshot::default())
+    }
+
+    pub fn with_config(config: &Config, metrics: SharedSnapshot) -> Self {
+        Self::with_physical_cores(config, metrics, PhysicalCore::detect())
+    }
+
+    /// 물리 코어 구성을 지정하여 생성 (None이면 평면 레이아웃)
+    pub fn with_physical_cores(
+        config: &Config,
+        metrics: SharedSnapshot,
+        physical_cores: Option<Vec<PhysicalCore>>,
+    ) -> Self {
+        let metrics = SnapshotReader::new(metrics);
+        let cpu_count = metrics.current().cores.len();
+        let mut view = Self {
+            metrics,
+            theme: config.theme.clone(),
+            thresholds: (config.low_threshold as f64, config.high_threshold as f64),
+            physical_cores,
+            gauges: Vec::new(),
+        };
+        view.ensure_gauges(cpu_count);
+        view
+    }
+
+    /// 게이지 수를 스냅샷의 논리 CPU 수에 맞춤
+    fn ensure_gauges(&mut self, count: usize) {
+        while self.gauges.len() < count {
+            let mut gauge = UsageGauge::new(format!("CPU {}", self.gauges.len()))
+                .with_thresholds(self.thresholds.0, self.thresholds.1);
+            gauge.apply_theme(&self.theme);
+            self.gauges.push(gauge);
+        }
+    }
+
+    /// 물리 코어 블록 격자로 렌더링
+    fn render_grouped(&self, frame: &mut Frame, area: Rect, cores: &[PhysicalCore]) {
+        let threads = cores.iter().map(|core| core.threads.len()).max().unwrap_or(1);
+        let block_width = threads as u16 * GAUGE_MIN_WIDTH + 2;
+        let columns = ((area.width / block_width) as usize).clamp(1, cores.len().max(1));
+        let rows = cores.len().div_ceil(columns);
+
+        let row_areas = Layout::vertical(vec![Constraint::Length(CORE_BLOCK_HEIGHT); rows]).split(area);
+        for (row_area, row_cores) in row_areas.iter().zip(cores.chunks(columns)) {
+            let cells = Layout::horizontal(vec![Constraint::Ratio(1, columns as u32); columns]).split(*row_area);
+            for (cell, core) in cells.iter().zip(row_cores) {
+                self.render_physical_core(frame, *cell, core);
+            }
+        }
+    }
+
+    /// 물리 코어 하나 - 형제 논리 CPU 게이지를 나란히
+    fn render_physical_core(&self, frame: &mut Frame, area: Rect, core: &PhysicalCore) {
+        let gauges: Vec<&UsageGauge> = core
+            .threads
+            .iter()
+            .filter_map(|&cpu| self.gauges.get(cpu))
+            .collect();
+        // 물리 코어 부하는 가장 바쁜 스레드 기준 색상
+        let busiest = gauges.iter().map(|g| g.get_usage()).fold(0.0, f64::max);
+        let title = match (core.threads.first(), core.threads.last()) {
+            (Some(first), Some(last)) if first != last => {
+                format!(" Core {}/{} ({:.0}%) ", first, last, busiest)
+            }
+            (Some(first), _) => format!(" Core {} ({:.0}%) ", first, busiest),
+            _ => " Core ".to_string(),
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.border));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if gauges.is_empty() {
+            return;
+        }
+        let cells =
+            Layout::horizontal(vec![Constraint::Ratio(1, gauges.len() as u32); gauges.len()]).split(inner);
+        for (cell, gauge) in cells.iter().zip(gauges) {
+            gauge.render(frame, *cell);
+        }
+    }
+
+    /// 토폴로지를 모를 때 CpuCoresView 게이지 모드와 같은 평면 격자
+    fn render_flat(&self, frame: &mut Frame, area: Rect) {
+        let rects = CpuCoresView::gauge_rects(area, self.gauges.len(), FLAT_COLUMNS);
+        for (gauge, rect) in self.gauges.iter().zip(rects) {
+            gauge.render(frame, rect);
+        }
+    }
+}
+
+impl Default for GroupedCpuView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for GroupedCpuView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let [title_area, content_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let layout = match self.physical_cores {
+            Some(ref cores) => format!("{} physical cores", cores.len()),
+            None => "topology unavailable".to_string(),
+        };
+        let title = Paragraph::new(format!(
+            "CPU by Physical Core ({} logical CPUs, {}) [Tab: switch view]",
+            self.gauges.len(),
+            layout
+        ))
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        match self.physical_cores {
+            Some(ref cores) => self.render_grouped(frame, content_area, cores),
+            None => self.render_flat(frame, content_area),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "CPU by Physical Core"
+    }
+
+    fn title(&self) -> &str {
+        "CPU Groups"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 20)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.theme = config.theme.clone();
+        self.thresholds = (config.low_threshold as f64, config.high_threshold as f64);
+        for gauge in &mut self.gauges {
+            gauge.apply_theme(&self.theme);
+            gauge.set_thresholds(self.thresholds.0, self.thresholds.1);
+        }
+    }
+}
+
+impl super::TickingComponent for GroupedCpuView {
+    fn on_tick(&mut self) {
+        if let Some(snapshot) = self.metrics.poll() {
+            self.ensure_gauges(snapshot.cores.len());
+            for (gauge, core) in self.gauges.iter_mut().zip(&snapshot.cores) {
+                gauge.set_usage(core.usage);
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::config::Config;
+use crate::controllers::metrics_collector::{
+    SharedSnapshot, SnapshotReader, DEFAULT_COLLECT_INTERVAL,
+};
+use crate::ui::components::histogram::UsageHistogram;
+
+/// CPU/GPU/메모리 사용률 분포 뷰 (R 키로 초기화)
+pub struct HistogramView {
+    metrics: SnapshotReader,
+    cpu: UsageHistogram,
+    gpu: UsageHistogram,
+    memory: UsageHistogram,
+    window_secs: u64,
+}
+
+impl HistogramView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default(), SharedSnapshot::default())
+    }
+
+    pub fn with_config(config: &Config, metrics: SharedSnapshot) -> Self {
+        // 집계 구간(초)을 수집 간격 기준 샘플 수로 변환
+        let window = (config.histogram_window_secs as u128 * 1000
+            / DEFAULT_COLLECT_INTERVAL.as_millis()) as usize;
+
+        let histogram = |title: &str| {
+            let mut histogram = UsageHistogram::new(title, window);
+            histogram.apply_theme(&config.theme);
+            histogram
+        };
+
+        Self {
+            metrics: SnapshotReader::new(metrics),
+            cpu: histogram("CPU"),
+            gpu: histogram("GPU"),
+            memory: histogram("Memory"),
+            window_secs: config.histogram_window_secs,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.cpu.reset();
+        self.gpu.reset();
+        self.memory.reset();
+    }
+
+    fn refresh(&mut self) {
+        let Some(snapshot) = self.metrics.poll() else {
+            return;
+        };
+        self.cpu.push(snapshot.cpu_usage);
+        if let Some(gpu_usage) = snapshot.gpu_usage {
+            self.gpu.push(gpu_usage);
+        }
+        self.memory.push(snapshot.memory_percent());
+    }
+}
+
+impl Default for HistogramView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for HistogramView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let [title_area, chart_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let window = if self.window_secs == 0 {
+            "all samples".to_string()
+        } else {
+            format!("last {}s", self.window_secs)
+        };
+        let title = Paragraph::new(format!(
+            "Usage Distribution ({}) [R: reset] [Tab: switch view]",
+            window
+        ))
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        let [cpu_area, gpu_area, memory_area] =
+            Layout::horizontal([Constraint::Ratio(1, 3); 3]).areas(chart_area);
+        self.cpu.render(frame, cpu_area);
+        self.gpu.render(frame, gpu_area);
+        self.memory.render(frame, memory_area);
+    }
+
+    fn name(&self) -> &str {
+        "Histogram"
+    }
+
+    fn title(&self) -> &str {
+        "Histogram"
+    }
+
+    /// 세 개의 막대 차트를 세로로 배치
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 20)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[("R", "Reset histograms")]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.reset();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.cpu.apply_theme(&config.theme);
+        self.gpu.apply_theme(&config.theme);
+        self.memory.apply_theme(&config.theme);
+    }
+}
+
+impl super::TickingComponent for HistogramView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
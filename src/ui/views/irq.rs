@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Row, Sparkline, Table},
+    Frame,
+};
+
+use crate::integration::irq_reader::IrqReader;
+use crate::ui::components::usage_gauge::format_count;
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+/// 표에 표시할 최대 IRQ 수
+const TOP_IRQS: usize = 20;
+/// 추세 스파크라인 열을 표시하기 위한 최소 폭
+const TREND_MIN_WIDTH: u16 = 70;
+
+/// IRQ별 초당 발생 횟수 상위 목록 (Linux `/proc/interrupts`)
+///
+/// GPU, 저장장치, 네트워크 인터럽트 폭주로 인한 지연 원인 확인용
+pub struct IrqView {
+    reader: Option<IrqReader>,
+    last_refresh: Option<Instant>,
+}
+
+impl IrqView {
+    pub fn new() -> Self {
+        Self {
+            reader: IrqReader::detect(),
+            last_refresh: None,
+        }
+    }
+
+    fn render_table(&self, frame: &mut Frame, area: Rect, reader: &IrqReader) {
+        let block = Block::default()
+            .title(" Top IRQs by rate ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue));
+        let inner = block.inner(area);
+        let show_trend = area.width >= TREND_MIN_WIDTH;
+
+        let capacity = inner.height.saturating_sub(1) as usize;
+        let irqs: Vec<u32> = reader.top(TOP_IRQS).into_iter().take(capacity).collect();
+        let rows: Vec<Row> = irqs
+            .iter()
+            .map(|&irq| {
+                let description = reader
+                    .entries()
+                    .get(&irq)
+                    .map_or("", |entry| entry.description.as_str());
+                let rate = reader.rate(irq);
+                let color = if rate > 0.0 { Color::White } else { Color::DarkGray };
+                Row::new(vec![
+                    irq.to_string(),
+                    description.to_string(),
+                    format_count(rate),
+                    String::new(),
+                ])
+                .style(Style::default().fg(color))
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(5),
+            Constraint::Fill(1),
+            Constraint::Length(8),
+            Constraint::Length(if show_trend { 24 } else { 0 }),
+        ];
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["IRQ", "Device", "Rate/s", "Trend"])
+                    .style(Style::default().fg(Color::Cyan).bold()),
+            )
+            .block(block);
+        frame.render_widget(table, area);
+
+        if !show_trend {
+            return;
+        }
+        // 표 셀에는 위젯을 넣을 수 없으므로 추세 열 위치에 행마다 스파크라인을 겹쳐 그림
+        let columns = Layout::horizontal(widths).spacing(1).split(inner);
+        let trend = columns[3];
+        for (i, &irq) in irqs.iter().enumerate() {
+            let Some(history) = reader.history(irq) else {
+                continue;
+            };
+            let data: Vec<u64> = history.iter().copied().collect();
+            // 최신 값이 오른쪽 끝에 오도록 폭만큼만 사용
+            let data = &data[data.len().saturating_sub(trend.width as usize)..];
+            let row_area = Rect::new(trend.x, inner.y + 1 + i as u16, trend.width, 1);
+            frame.render_widget(
+                Sparkline::default()
+                    .data(data)
+                    .style(Style::default().fg(Color::Yellow)),
+                row_area,
+            );
+        }
+    }
+}
+
+impl Default for IrqView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for IrqView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let [title_area, table_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let title = Paragraph::new("Interrupts (per IRQ, sorted by rate) [Tab: switch view]")
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        match self.reader {
+            Some(ref reader) => self.render_table(frame, table_area, reader),
+            None => {
+                let message = Paragraph::new("Unavailable: cannot read /proc/interrupts (Linux only)")
+                    .style(Style::default().fg(Color::Gray))
+                    .block(Block::default().borders(Borders::ALL));
+                frame.render_widget(message, table_area);
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Interrupts"
+    }
+
+    fn title(&self) -> &str {
+        "IRQ"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (50, 12)
+    }
+}
+
+impl super::TickingComponent for IrqView {
+    fn on_tick(&mut self) {
+        if self
+            .last_refresh
+            .is_some_and(|t| t.elapsed() < REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.last_refresh = Some(Instant::now());
+        if let Some(ref mut reader) = self.reader {
+            reader.refresh();
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Borders, Row, Table};
+
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader, SystemSnapshot};
+#[cfg(target_os = "linux")]
+use crate::integration::meminfo::{MemInfo, MemInfoReader};
+
+/// KB 단위 크기를 읽기 쉬운 문자열로 변환 (정수면 소수점 생략)
+fn format_size_kb(kb: u64) -> String {
+    let (value, unit) = if kb >= 1024 * 1024 {
+        (kb as f64 / 1024.0 / 1024.0, "GB")
+    } else {
+        (kb as f64 / 1024.0, "MB")
+    };
+    if value.fract() == 0.0 {
+        format!("{}{}", value, unit)
+    } else {
+        format!("{:.1}{}", value, unit)
+    }
+}
+
+/// 메모리 구성 상세 뷰
+pub struct MemoryBreakdownView {
+    metrics: SnapshotReader,
+    snapshot: SystemSnapshot,
+    #[cfg(target_os = "linux")]
+    meminfo: Option<MemInfo>,
+}
+
+impl MemoryBreakdownView {
+    pub fn new(metrics: SharedSnapshot) -> Self {
+        let metrics = SnapshotReader::new(metrics);
+        let mut view = Self {
+            snapshot: metrics.current(),
+            metrics,
+            #[cfg(target_os = "linux")]
+            meminfo: None,
+        };
+        view.refresh();
+        view
+    }
+
+    fn refresh(&mut self) {
+        if let Some(snapshot) = self.metrics.poll() {
+            self.snapshot = snapshot;
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.meminfo = MemInfoReader::read().ok();
+        }
+    }
+
+    fn row(label: &str, value: String, color: Color) -> Row<'static> {
+        Row::new(vec![
+            Span::styled(label.to_string(), Style::default().fg(Color::Gray)),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    }
+
+    /// Linux 전용 행 (버퍼, 캐시, Huge pages)
+    #[cfg(target_os = "linux")]
+    fn linux_rows(&self) -> Vec<Row<'static>> {
+        let Some(ref meminfo) = self.meminfo else {
+            return Vec::new();
+        };
+
+        let mut rows = Vec::new();
+        if let Some(buffers) = meminfo.buffers_kb() {
+            rows.push(Self::row("Buffers", format_size_kb(buffers), Color::White));
+        }
+        if let Some(cached) = meminfo.cached_kb() {
+            rows.push(Self::row("Cached", format_size_kb(cached), Color::White));
+        }
+
+        // 모든 huge page가 사용 중이면 설정 문제 가능성이 있으므로 노란색 강조
+        if let Some(huge_pages) = meminfo.huge_pages() {
+            let row = Self::row(
+                "Huge Pages",
+                format!(
+                    "HugePages: {} / {} ({} / {})",
+                    huge_pages.used(),
+                    huge_pages.total,
+                    format_size_kb(huge_pages.used() * huge_pages.page_size_kb),
+                    format_size_kb(huge_pages.total * huge_pages.page_size_kb),
+                ),
+                Color::White,
+            );
+            rows.push(if huge_pages.is_exhausted() {
+                row.style(Style::default().fg(Color::Yellow))
+            } else {
+                row
+            });
+        }
+        rows
+    }
+}
+
+impl Default for MemoryBreakdownView {
+    fn default() -> Self {
+        Self::new(SharedSnapshot::default())
+    }
+}
+
+impl super::ViewComponent for MemoryBreakdownView {
+    fn draw_with_area(&self, frame: &mut ratatui::Frame, area: Rect) {
+        let block = Block::default()
+            .title(" Memory Breakdown ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let total_kb = self.snapshot.total_memory / 1024;
+        let used_kb = self.snapshot.used_memory / 1024;
+        let available_kb = self.snapshot.available_memory / 1024;
+        let swap_total_kb = self.snapshot.total_swap / 1024;
+        let swap_used_kb = self.snapshot.used_swap / 1024;
+
+        #[allow(unused_mut)]
+        let mut rows = vec![
+            Self::row("Total", format_size_kb(total_kb), Color::Magenta),
+            Self::row("Used", format_size_kb(used_kb), Color::Green),
+            Self::row("Available", format_size_kb(available_kb), Color::Cyan),
+            Self::row(
+                "Swap",
+                format!(
+                    "{} / {}",
+                    format_size_kb(swap_used_kb),
+                    format_size_kb(swap_total_kb)
+                ),
+                Color::White,
+            ),
+        ];
+
+        #[cfg(target_os = "linux")]
+        rows.extend(self.linux_rows());
+
+        let table = Table::new(rows, [Constraint::Length(20), Constraint::Fill(1)]).block(
+            Block::default()
+                .title(" 🧠 Memory ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, inner);
+    }
+
+    fn name(&self) -> &str {
+        "Memory Breakdown"
+    }
+
+    fn title(&self) -> &str {
+        "Memory"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 16)
+    }
+}
+
+impl super::TickingComponent for MemoryBreakdownView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
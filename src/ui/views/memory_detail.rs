@@ -0,0 +1,169 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Gauge},
+    Frame,
+};
+
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader};
+#[cfg(target_os = "linux")]
+use crate::integration::meminfo::MemInfoReader;
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// 전체 메모리 대비 한 항목 (바이트)
+struct MemorySegment {
+    label: &'static str,
+    bytes: u64,
+    color: Color,
+}
+
+/// 메모리 압박 뷰 - 사용/캐시/버퍼/여유 메모리를 전체 대비 비율로 표시
+pub struct MemoryPressureView {
+    metrics: SnapshotReader,
+    total_bytes: u64,
+    segments: Vec<MemorySegment>,
+}
+
+impl MemoryPressureView {
+    pub fn new(metrics: SharedSnapshot) -> Self {
+        let mut view = Self {
+            metrics: SnapshotReader::new(metrics),
+            total_bytes: 0,
+            segments: Vec::new(),
+        };
+        view.refresh();
+        view
+    }
+
+    /// `/proc/meminfo` 기준 사용 = 전체 - 여유 - 버퍼 - 캐시 (free 명령과 동일)
+    #[cfg(target_os = "linux")]
+    fn refresh(&mut self) {
+        let Ok(meminfo) = MemInfoReader::read() else {
+            self.refresh_from_snapshot();
+            return;
+        };
+        let total = meminfo.total_kb().unwrap_or(0);
+        let free = meminfo.free_kb().unwrap_or(0);
+        let buffers = meminfo.buffers_kb().unwrap_or(0);
+        let cache = meminfo.cached_kb().unwrap_or(0) + meminfo.s_reclaimable_kb().unwrap_or(0);
+        let used = total.saturating_sub(free + buffers + cache);
+
+        self.total_bytes = total * 1024;
+        self.segments = vec![
+            MemorySegment {
+                label: "Used",
+                bytes: used * 1024,
+                color: Color::Red,
+            },
+            MemorySegment {
+                label: "Cache",
+                bytes: cache * 1024,
+                color: Color::Yellow,
+            },
+            MemorySegment {
+                label: "Buffers",
+                bytes: buffers * 1024,
+                color: Color::Blue,
+            },
+            MemorySegment {
+                label: "Free",
+                bytes: free * 1024,
+                color: Color::Green,
+            },
+        ];
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn refresh(&mut self) {
+        self.refresh_from_snapshot();
+    }
+
+    /// 캐시/버퍼 정보가 없을 때 sysinfo의 사용 가능 메모리만으로 표시
+    fn refresh_from_snapshot(&mut self) {
+        let snapshot = self.metrics.current();
+        self.total_bytes = snapshot.total_memory;
+        self.segments = vec![
+            MemorySegment {
+                label: "Used",
+                bytes: snapshot
+                    .total_memory
+                    .saturating_sub(snapshot.available_memory),
+                color: Color::Red,
+            },
+            MemorySegment {
+                label: "Available",
+                bytes: snapshot.available_memory,
+                color: Color::Green,
+            },
+        ];
+    }
+
+    fn render_segment(&self, segment: &MemorySegment, frame: &mut Frame, area: Rect) {
+        let ratio = if self.total_bytes > 0 {
+            (segment.bytes as f64 / self.total_bytes as f64).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let gauge = Gauge::default()
+            .block(Block::default().title(segment.label).borders(Borders::ALL))
+            .gauge_style(Style::default().fg(segment.color))
+            .ratio(ratio)
+            .label(format!(
+                "{:.2} GiB ({:.1}%)",
+                segment.bytes as f64 / GIB,
+                ratio * 100.0
+            ));
+        frame.render_widget(gauge, area);
+    }
+}
+
+impl Default for MemoryPressureView {
+    fn default() -> Self {
+        Self::new(SharedSnapshot::default())
+    }
+}
+
+impl super::ViewComponent for MemoryPressureView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(format!(
+                " Memory Pressure ({:.2} GiB total) ",
+                self.total_bytes as f64 / GIB
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::vertical(
+            self.segments
+                .iter()
+                .map(|_| Constraint::Length(3))
+                .chain(std::iter::once(Constraint::Min(0))),
+        )
+        .split(inner);
+        for (segment, row) in self.segments.iter().zip(rows.iter()) {
+            self.render_segment(segment, frame, *row);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Memory Pressure"
+    }
+
+    fn title(&self) -> &str {
+        "Pressure"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 16)
+    }
+}
+
+impl super::TickingComponent for MemoryPressureView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
@@ -1,25 +1,109 @@
-use ratatui::{crossterm::event::KeyCode, Frame};
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent, MouseEvent},
+    Frame,
+};
+
+use crate::config::Config;
 
 /// 뷰 컴포넌트를 위한 trait - 구현체에서 draw를 반드시 구현해야 함
 pub trait ViewComponent {
     fn draw_with_area(&self, frame: &mut Frame, area: ratatui::layout::Rect);
+
+    /// 뷰 이름 (터미널 타이틀 등에 표시)
+    fn name(&self) -> &str;
+
+    /// 탭 바에 표시할 짧은 제목
+    fn title(&self) -> &str {
+        "Unnamed"
+    }
+
+    /// 제대로 그리기 위해 필요한 최소 터미널 크기 (폭, 높이)
+    fn min_dimensions(&self) -> (u16, u16) {
+        (80, 24)
+    }
     
     /// 키 입력 처리 (Optional) - true 반환 시 이벤트 소비됨
     fn handle_key(&mut self, _key: KeyCode) -> bool {
         false
     }
+
+    /// 수정 키(Ctrl 등)가 필요한 경우 재정의 - 기본은 handle_key로 위임
+    fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        self.handle_key(key.code)
+    }
+
+    /// 뷰 전용 단축키와 설명 목록 (도움말 표시용)
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
+    /// 설정이 다시 로드되었을 때 호출 (Optional)
+    fn on_config_changed(&mut self, _config: &Config) {}
+
+    /// 터미널 크기가 바뀌었을 때 그리기 전에 호출 (Optional) - 크기에 맞춘 레이아웃 재계산용
+    fn on_resize(&mut self, _new_area: ratatui::layout::Rect) {}
+
+    /// 이 뷰로 전환되었을 때 호출 (Optional) - 다음 tick을 기다리지 않고 갱신할 때 사용
+    fn on_focus(&mut self) {}
+
+    /// 다른 뷰로 전환되기 직전에 호출 (Optional) - 백그라운드 작업 일시 중지용
+    fn on_blur(&mut self) {}
+
+    /// 마우스 입력 처리 (Optional) - true 반환 시 이벤트 소비됨
+    fn handle_mouse(&mut self, _event: MouseEvent) -> bool {
+        false
+    }
+}
+
+/// 화면에 보이지 않을 때의 tick 처리 방식
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BackgroundRefreshPolicy {
+    /// 화면에 보일 때만 tick (기본)
+    #[default]
+    OnlyWhenVisible,
+    /// 숨어 있어도 tick - 전환했을 때 바로 최신 데이터를 보여줘야 하는 뷰용
+    AlwaysRefresh,
 }
 
 /// Tick 기반 업데이트가 필요한 컴포넌트용 trait
 pub trait TickingComponent {
     fn on_tick(&mut self) {}
-}
 
-/// ViewComponent + TickingComponent를 둘 다 구현하는 뷰용 trait
-pub trait TickingView: ViewComponent + TickingComponent {}
-impl<T: ViewComponent + TickingComponent> TickingView for T {}
+    /// 원하는 tick 간격 (ms) - 이 간격이 지나야 on_tick이 호출됨
+    fn preferred_tick_ms(&self) -> u64 {
+        200
+    }
+
+    /// 화면에 보이지 않을 때도 tick을 받을지 (Optional)
+    fn background_refresh_policy(&self) -> BackgroundRefreshPolicy {
+        BackgroundRefreshPolicy::OnlyWhenVisible
+    }
+}
 
 pub mod status;
 pub mod system_monitor;
 pub mod cpu_cores;
-pub mod vrchat_page;
\ No newline at end of file
+pub mod grouped_cpu;
+pub mod cpu_freq;
+pub mod debug;
+pub mod histogram;
+pub mod vrchat_page;
+pub mod memory_breakdown;
+pub mod memory_detail;
+pub mod network;
+pub mod network_monitor;
+pub mod network_connections;
+pub mod network_latency;
+pub mod disk_monitor;
+pub mod disk_health;
+pub mod disk_tree;
+pub mod process_list;
+pub mod process_tree;
+pub mod syslog;
+pub mod irq;
+pub mod custom_metrics;
+pub mod docker;
+pub mod thermal;
+pub mod config_editor;
+pub mod session_stats;
+pub mod power;
\ No newline at end of file
@@ -0,0 +1,434 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Paragraph, Row, Table},
+    Frame,
+};
+
+#[cfg(target_os = "linux")]
+use crate::config::Config;
+use crate::integration::connections::{Connection, ConnectionTable, TcpState};
+use crate::integration::net_stats::{read_interface_counters, InterfaceCounters};
+use crate::ui::components::usage_gauge::ThroughputGraph;
+
+const CONNECTION_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+const STATS_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 인터페이스별 초당 변화량
+struct InterfaceRates {
+    name: String,
+    rx_packets: f64,
+    tx_packets: f64,
+    rx_errors: f64,
+    tx_errors: f64,
+    rx_drops: f64,
+    tx_drops: f64,
+}
+
+/// 연결 테이블 정렬 기준
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ConnectionSort {
+    Local,
+    Remote,
+    State,
+    Process,
+    Pid,
+}
+
+impl ConnectionSort {
+    fn label(&self) -> &'static str {
+        match self {
+            ConnectionSort::Local => "Local",
+            ConnectionSort::Remote => "Remote",
+            ConnectionSort::State => "State",
+            ConnectionSort::Process => "Process",
+            ConnectionSort::Pid => "PID",
+        }
+    }
+}
+
+/// 네트워크 뷰 - 전체 대역폭, 인터페이스 통계, 프로세스별 활성 연결 목록
+pub struct NetworkView {
+    #[cfg(target_os = "linux")]
+    connections: ConnectionTable,
+    sort: ConnectionSort,
+    sort_desc: bool,
+    scroll: usize,
+    last_refresh: Option<Instant>,
+    bandwidth_graph: ThroughputGraph,
+    /// 앱 시작 시점의 카운터 (누적 오류/드롭 계산용)
+    baseline_counters: HashMap<String, InterfaceCounters>,
+    previous_counters: HashMap<String, InterfaceCounters>,
+    interface_rates: Vec<InterfaceRates>,
+    /// 앱 시작 이후 누적 (오류, 드롭) 수
+    total_errors: u64,
+    total_drops: u64,
+    last_stats_refresh: Instant,
+}
+
+impl NetworkView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성
+    pub fn with_config(config: &Config) -> Self {
+        let mut bandwidth_graph = ThroughputGraph::new("Total Bandwidth", "RX", "TX")
+            .with_history_size(config.history_size);
+        bandwidth_graph.apply_theme(&config.theme);
+
+        let baseline_counters: HashMap<String, InterfaceCounters> = read_interface_counters()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| (c.name.clone(), c))
+            .collect();
+
+        Self {
+            #[cfg(target_os = "linux")]
+            connections: ConnectionTable::new(),
+            sort: ConnectionSort::Local,
+            sort_desc: false,
+            scroll: 0,
+            last_refresh: None,
+            bandwidth_graph,
+            previous_counters: baseline_counters.clone(),
+            baseline_counters,
+            interface_rates: Vec::new(),
+            total_errors: 0,
+            total_drops: 0,
+            last_stats_refresh: Instant::now(),
+        }
+    }
+
+    /// 인터페이스 통계 갱신 (1초 간격, 이전 값과의 차이를 초당 값으로 환산)
+    fn refresh_stats(&mut self) {
+        let elapsed = self.last_stats_refresh.elapsed();
+        if elapsed < STATS_REFRESH_INTERVAL {
+            return;
+        }
+        self.last_stats_refresh = Instant::now();
+        let Ok(counters) = read_interface_counters() else {
+            return;
+        };
+
+        let secs = elapsed.as_secs_f64();
+        let rate = |current: u64, previous: u64| current.saturating_sub(previous) as f64 / secs;
+
+        let mut rx_total = 0.0;
+        let mut tx_total = 0.0;
+        self.interface_rates.clear();
+        self.total_errors = 0;
+        self.total_drops = 0;
+
+        for current in &counters {
+            let previous = self.previous_counters.get(&current.name).unwrap_or(current);
+            // 루프백은 전체 대역폭에서 제외
+            if current.name != "lo" {
+                rx_total += rate(current.rx_bytes, previous.rx_bytes);
+                tx_total += rate(current.tx_bytes, previous.tx_bytes);
+            }
+            self.interface_rates.push(InterfaceRates {
+                name: current.name.clone(),
+                rx_packets: rate(current.rx_packets, previous.rx_packets),
+                tx_packets: rate(current.tx_packets, previous.tx_packets),
+                rx_errors: rate(current.rx_errors, previous.rx_errors),
+                tx_errors: rate(current.tx_errors, previous.tx_errors),
+                rx_drops: rate(current.rx_drops, previous.rx_drops),
+                tx_drops: rate(current.tx_drops, previous.tx_drops),
+            });
+
+            let baseline = self.baseline_counters.get(&current.name).unwrap_or(current);
+            self.total_errors += (current.rx_errors + current.tx_errors)
+                .saturating_sub(baseline.rx_errors + baseline.tx_errors);
+            self.total_drops += (current.rx_drops + current.tx_drops)
+                .saturating_sub(baseline.rx_drops + baseline.tx_drops);
+        }
+        self.interface_rates.sort_by(|a, b| a.name.cmp(&b.name));
+        self.bandwidth_graph.push(rx_total, tx_total);
+
+        self.previous_counters = counters.into_iter().map(|c| (c.name.clone(), c)).collect();
+    }
+
+    fn render_interface_stats(&self, frame: &mut Frame, area: Rect) {
+        // 오류/드롭 값은 0이 아니면 빨간색
+        let alert_cell = |value: f64| {
+            let color = if value > 0.0 { Color::Red } else { Color::White };
+            Span::styled(format!("{:.0}", value), Style::default().fg(color))
+        };
+
+        let mut rows: Vec<Row> = self
+            .interface_rates
+            .iter()
+            .map(|r| {
+                Row::new(vec![
+                    Span::styled(r.name.clone(), Style::default().fg(Color::Cyan)),
+                    Span::raw(format!("{:.0}", r.rx_packets)),
+                    Span::raw(format!("{:.0}", r.tx_packets)),
+                    alert_cell(r.rx_errors),
+                    alert_cell(r.tx_errors),
+                    alert_cell(r.rx_drops),
+                    alert_cell(r.tx_drops),
+                ])
+            })
+            .collect();
+
+        // 앱 시작 이후 누적 오류/드롭 (정상 동작 확인용)
+        rows.push(
+            Row::new(vec![
+                Span::raw("Since start"),
+                Span::raw(""),
+                Span::raw(""),
+                Span::raw(format!("errors: {}", self.total_errors)),
+                Span::raw(""),
+                Span::raw(format!("drops: {}", self.total_drops)),
+                Span::raw(""),
+            ])
+            .style(Style::default().fg(Color::Gray)),
+        );
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(10),
+            ],
+        )
+        .header(
+            Row::new(vec![
+                "Interface", "RX pkt/s", "TX pkt/s", "RX err/s", "TX err/s", "RX drop/s",
+                "TX drop/s",
+            ])
+            .style(Style::default().fg(Color::Cyan).bold()),
+        )
+        .block(
+            Block::default()
+                .title(" Interface Statistics ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, area);
+    }
+
+    fn refresh(&mut self) {
+        self.refresh_stats();
+
+        // /proc 전체를 훑으므로 일정 간격으로만 갱신
+        if self
+            .last_refresh
+            .is_some_and(|t| t.elapsed() < CONNECTION_REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.last_refresh = Some(Instant::now());
+
+        #[cfg(target_os = "linux")]
+        self.connections.refresh();
+    }
+
+    #[cfg(target_os = "linux")]
+    fn connection_count(&self) -> usize {
+        self.connections.connections().len()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn connection_count(&self) -> usize {
+        0
+    }
+
+    /// 같은 기준을 다시 누르면 정렬 방향 반전
+    fn set_sort(&mut self, sort: ConnectionSort) {
+        if self.sort == sort {
+            self.sort_desc = !self.sort_desc;
+        } else {
+            self.sort = sort;
+            self.sort_desc = false;
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn sorted_connections(&self) -> Vec<&Connection> {
+        let mut connections: Vec<&Connection> = self.connections.connections().iter().collect();
+        connections.sort_by(|a, b| {
+            let ordering = match self.sort {
+                ConnectionSort::Local => a.local.cmp(&b.local),
+                ConnectionSort::Remote => a.remote.cmp(&b.remote),
+                ConnectionSort::State => a.state.cmp(&b.state),
+                ConnectionSort::Process => a.process_name.cmp(&b.process_name),
+                ConnectionSort::Pid => a.pid.cmp(&b.pid),
+            };
+            if self.sort_desc {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        connections
+    }
+
+    #[cfg(target_os = "linux")]
+    fn state_color(state: TcpState) -> Color {
+        match state {
+            TcpState::Established => Color::Green,
+            TcpState::Listen => Color::Cyan,
+            TcpState::TimeWait | TcpState::CloseWait => Color::Yellow,
+            _ => Color::Gray,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn render_connections(&self, frame: &mut Frame, area: Rect) {
+        let connections = self.sorted_connections();
+
+        let capacity = area.height.saturating_sub(3) as usize;
+        let start = self.scroll.min(connections.len().saturating_sub(capacity));
+
+        let rows: Vec<Row> = connections
+            .iter()
+            .skip(start)
+            .take(capacity)
+            .map(|c| {
+                Row::new(vec![
+                    Span::raw(c.local.to_string()),
+                    Span::raw(c.remote.to_string()),
+                    Span::styled(
+                        c.state.as_str(),
+                        Style::default().fg(Self::state_color(c.state)),
+                    ),
+                    Span::raw(c.process_name.clone().unwrap_or_else(|| "-".to_string())),
+                    Span::raw(c.pid.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string())),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Fill(2),
+                Constraint::Fill(2),
+                Constraint::Length(12),
+                Constraint::Fill(1),
+                Constraint::Length(8),
+            ],
+        )
+        .header(
+            Row::new(vec!["Local:Port", "Remote:Port", "State", "Process", "PID"])
+                .style(Style::default().fg(Color::Cyan).bold()),
+        )
+        .block(
+            Block::default()
+                .title(format!(" Connections ({}) ", connections.len()))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, area);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn render_connections(&self, frame: &mut Frame, area: Rect) {
+        let message = Paragraph::new("Connection table is only supported on Linux")
+            .style(Style::default().fg(Color::Gray))
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(message, area);
+    }
+}
+
+impl Default for NetworkView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for NetworkView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let direction = if self.sort_desc { "↓" } else { "↑" };
+        let title = Paragraph::new(format!(
+            "Network [A/R/S/N/I: sort - {} {}] [↑↓: scroll] [Tab: switch view]",
+            self.sort.label(),
+            direction
+        ))
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, main_chunks[0]);
+
+        // 대역폭 그래프 + 인터페이스 통계 + 연결 목록
+        let interface_rows = self.interface_rates.len() as u16 + 1;
+        let body = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Length(interface_rows + 3),
+                Constraint::Min(0),
+            ])
+            .split(main_chunks[1]);
+
+        self.bandwidth_graph.render(frame, body[0]);
+        self.render_interface_stats(frame, body[1]);
+        self.render_connections(frame, body[2]);
+    }
+
+    fn name(&self) -> &str {
+        "Network"
+    }
+
+    fn title(&self) -> &str {
+        "Network"
+    }
+
+    /// 연결 목록 표 열이 많음
+    fn min_dimensions(&self) -> (u16, u16) {
+        (80, 20)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.bandwidth_graph.apply_theme(&config.theme);
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("A / R", "Sort by local address / remote"),
+            ("S", "Sort by state"),
+            ("N / I", "Sort by process / PID"),
+            ("↑ / ↓", "Scroll connections"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            // L은 전역 레이아웃 선택 키이므로 로컬 주소 정렬은 A
+            KeyCode::Char('a') | KeyCode::Char('A') => self.set_sort(ConnectionSort::Local),
+            KeyCode::Char('r') | KeyCode::Char('R') => self.set_sort(ConnectionSort::Remote),
+            KeyCode::Char('s') | KeyCode::Char('S') => self.set_sort(ConnectionSort::State),
+            KeyCode::Char('n') | KeyCode::Char('N') => self.set_sort(ConnectionSort::Process),
+            // P는 전역 일시정지 키이므로 PID 정렬은 I
+            KeyCode::Char('i') | KeyCode::Char('I') => self.set_sort(ConnectionSort::Pid),
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => {
+                self.scroll = (self.scroll + 1).min(self.connection_count().saturating_sub(1));
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl super::TickingComponent for NetworkView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
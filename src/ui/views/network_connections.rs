@@ -0,0 +1,266 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+#[cfg(target_os = "linux")]
+use std::net::SocketAddr;
+
+#[cfg(target_os = "linux")]
+use ratatui::{
+    text::Span,
+    widgets::{Row, Table},
+};
+
+#[cfg(target_os = "linux")]
+use crate::integration::connections::{Connection, ConnectionTable, TcpState};
+#[cfg(target_os = "linux")]
+use crate::integration::net_connections::HostnameCache;
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// F 키로 순환하는 상태 필터 (None은 전체)
+#[cfg(target_os = "linux")]
+const STATE_FILTERS: [Option<TcpState>; 6] = [
+    None,
+    Some(TcpState::Established),
+    Some(TcpState::TimeWait),
+    Some(TcpState::CloseWait),
+    Some(TcpState::SynSent),
+    Some(TcpState::Listen),
+];
+
+/// 활성 TCP 연결 목록 뷰 - 상대 주소는 호스트 이름으로 표시 (Linux 전용)
+pub struct NetworkConnectionsView {
+    #[cfg(target_os = "linux")]
+    connections: ConnectionTable,
+    #[cfg(target_os = "linux")]
+    hostnames: HostnameCache,
+    /// `STATE_FILTERS` 인덱스
+    filter_index: usize,
+    scroll: usize,
+    last_refresh: Option<Instant>,
+}
+
+impl NetworkConnectionsView {
+    pub fn new() -> Self {
+        Self {
+            #[cfg(target_os = "linux")]
+            connections: ConnectionTable::new(),
+            #[cfg(target_os = "linux")]
+            hostnames: HostnameCache::new(),
+            filter_index: 0,
+            scroll: 0,
+            last_refresh: None,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn refresh(&mut self) {
+        self.hostnames.poll();
+
+        // /proc 전체를 훑으므로 일정 간격으로만 갱신
+        if self
+            .last_refresh
+            .is_some_and(|t| t.elapsed() < REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.last_refresh = Some(Instant::now());
+
+        self.connections.refresh();
+        let remotes: Vec<_> = self
+            .visible_connections()
+            .iter()
+            .map(|c| c.remote.ip())
+            .collect();
+        for ip in remotes {
+            self.hostnames.request(ip);
+        }
+        self.scroll = self
+            .scroll
+            .min(self.visible_connections().len().saturating_sub(1));
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn refresh(&mut self) {}
+
+    #[cfg(target_os = "linux")]
+    fn state_filter(&self) -> Option<TcpState> {
+        STATE_FILTERS[self.filter_index]
+    }
+
+    #[cfg(target_os = "linux")]
+    fn filter_label(&self) -> &'static str {
+        self.state_filter().map_or("ALL", |state| state.as_str())
+    }
+
+    /// 상태 필터 적용 후 (상태, 원격 주소) 순으로 정렬한 목록
+    #[cfg(target_os = "linux")]
+    fn visible_connections(&self) -> Vec<&Connection> {
+        let filter = self.state_filter();
+        let mut connections: Vec<&Connection> = self
+            .connections
+            .connections()
+            .iter()
+            .filter(|c| filter.is_none_or(|state| c.state == state))
+            .collect();
+        connections.sort_by(|a, b| a.state.cmp(&b.state).then(a.remote.cmp(&b.remote)));
+        connections
+    }
+
+    /// 호스트 이름이 조회되었으면 "이름:포트", 아니면 주소 그대로
+    #[cfg(target_os = "linux")]
+    fn format_remote(&self, addr: &SocketAddr) -> String {
+        match self.hostnames.get(&addr.ip()) {
+            Some(host) => format!("{}:{}", host, addr.port()),
+            None => addr.to_string(),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn state_color(state: TcpState) -> Color {
+        match state {
+            TcpState::Established => Color::Green,
+            TcpState::Listen => Color::Cyan,
+            TcpState::TimeWait | TcpState::CloseWait => Color::Yellow,
+            _ => Color::Gray,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn render_table(&self, frame: &mut Frame, area: Rect) {
+        let connections = self.visible_connections();
+
+        let capacity = area.height.saturating_sub(3) as usize;
+        let start = self.scroll.min(connections.len().saturating_sub(capacity));
+
+        let rows: Vec<Row> = connections
+            .iter()
+            .skip(start)
+            .take(capacity)
+            .map(|c| {
+                let owner = match (c.pid, c.process_name.as_deref()) {
+                    (Some(pid), Some(name)) => format!("{}/{}", pid, name),
+                    (Some(pid), None) => pid.to_string(),
+                    _ => "-".to_string(),
+                };
+                Row::new(vec![
+                    Span::raw(c.local.to_string()),
+                    Span::raw(self.format_remote(&c.remote)),
+                    Span::styled(
+                        c.state.as_str(),
+                        Style::default().fg(Self::state_color(c.state)),
+                    ),
+                    Span::raw(owner),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Fill(2),
+                Constraint::Fill(3),
+                Constraint::Length(12),
+                Constraint::Fill(2),
+            ],
+        )
+        .header(
+            Row::new(vec!["Local:Port", "Remote:Port", "State", "PID/Process"])
+                .style(Style::default().fg(Color::Cyan).bold()),
+        )
+        .block(
+            Block::default()
+                .title(format!(
+                    " TCP Connections ({}) [{}] ",
+                    connections.len(),
+                    self.filter_label()
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, area);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn render_table(&self, frame: &mut Frame, area: Rect) {
+        let message = Paragraph::new("Unsupported platform: connection list reads /proc/net/tcp (Linux only)")
+            .style(Style::default().fg(Color::Gray))
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(message, area);
+    }
+}
+
+impl Default for NetworkConnectionsView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for NetworkConnectionsView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let [title_area, table_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let title = Paragraph::new(
+            "Network Connections [F: filter state] [↑↓: scroll] [Tab: switch view]",
+        )
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        self.render_table(frame, table_area);
+    }
+
+    fn name(&self) -> &str {
+        "Network Connections"
+    }
+
+    fn title(&self) -> &str {
+        "Connections"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (80, 16)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("F", "Cycle state filter"),
+            ("↑ / ↓", "Scroll connections"),
+        ]
+    }
+
+    #[cfg(target_os = "linux")]
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.filter_index = (self.filter_index + 1) % STATE_FILTERS.len();
+                self.scroll = 0;
+            }
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => {
+                let count = self.visible_connections().len();
+                self.scroll = (self.scroll + 1).min(count.saturating_sub(1));
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn handle_key(&mut self, _key: KeyCode) -> bool {
+        false
+    }
+}
+
+impl super::TickingComponent for NetworkConnectionsView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
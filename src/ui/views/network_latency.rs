@@ -0,0 +1,166 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::config::Config;
+use crate::integration::ping::{PingMonitor, PING_INTERVAL};
+use crate::ui::components::usage_gauge::UsageGraph;
+
+/// y축 최댓값 (ms)
+const MAX_RTT_MS: f64 = 500.0;
+
+/// 대상 하나의 ping 스레드와 RTT 그래프
+struct LatencyPanel {
+    monitor: PingMonitor,
+    graph: UsageGraph,
+    /// 마지막 응답 RTT (ms)
+    last_rtt: Option<f64>,
+}
+
+impl LatencyPanel {
+    fn new(target: &str, config: &Config) -> Self {
+        let mut graph = UsageGraph::new(format!("Ping {}", target))
+            .with_history_size(config.history_size)
+            .with_thresholds(50.0, 150.0);
+        graph.apply_theme(&config.theme);
+        graph.set_y_bounds(0.0, MAX_RTT_MS);
+        graph.set_y_labels("0", "250", "500 ms");
+        graph.set_unit(" ms");
+        graph.set_expected_interval(PING_INTERVAL);
+
+        Self {
+            monitor: PingMonitor::start(target),
+            graph,
+            last_rtt: None,
+        }
+    }
+
+    fn update(&mut self) {
+        let samples = self.monitor.poll();
+        if samples.is_empty() {
+            return;
+        }
+        for sample in samples {
+            match sample {
+                Some(rtt) => {
+                    let ms = rtt.as_secs_f64() * 1000.0;
+                    self.last_rtt = Some(ms);
+                    self.graph.push(ms);
+                }
+                None => self.graph.push_gap(),
+            }
+        }
+
+        let rtt = match self.last_rtt {
+            Some(ms) => format!("{:.1} ms", ms),
+            None => "-- ms".to_string(),
+        };
+        self.graph.set_label(Some(format!(
+            "{} (loss {:.1}%)",
+            rtt,
+            self.monitor.loss_percent()
+        )));
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        if let Some(error) = self.monitor.error() {
+            let message = Paragraph::new(error.to_string())
+                .style(Style::default().fg(Color::Gray))
+                .wrap(Wrap { trim: true })
+                .block(
+                    Block::default()
+                        .title(format!("Ping {}", self.monitor.target()))
+                        .borders(Borders::ALL),
+                );
+            frame.render_widget(message, area);
+            return;
+        }
+        self.graph.render(frame, area);
+    }
+}
+
+/// 설정된 대상(`ping_targets`)별 ping 지연 그래프 뷰
+pub struct NetworkLatencyView {
+    targets: Vec<String>,
+    panels: Vec<LatencyPanel>,
+}
+
+impl NetworkLatencyView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성 (대상마다 ping 스레드 시작)
+    pub fn with_config(config: &Config) -> Self {
+        Self {
+            targets: config.ping_targets.clone(),
+            panels: config
+                .ping_targets
+                .iter()
+                .map(|target| LatencyPanel::new(target, config))
+                .collect(),
+        }
+    }
+}
+
+impl Default for NetworkLatencyView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for NetworkLatencyView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        if self.panels.is_empty() {
+            let message = Paragraph::new("No ping targets configured (set ping_targets in config)")
+                .style(Style::default().fg(Color::Gray))
+                .centered()
+                .block(
+                    Block::default()
+                        .title(" Network Latency ")
+                        .borders(Borders::ALL),
+                );
+            frame.render_widget(message, area);
+            return;
+        }
+
+        let areas = Layout::vertical(vec![Constraint::Fill(1); self.panels.len()]).split(area);
+        for (panel, panel_area) in self.panels.iter().zip(areas.iter()) {
+            panel.render(frame, *panel_area);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Network Latency"
+    }
+
+    fn title(&self) -> &str {
+        "Latency"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (50, 12)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        // 대상 목록이 바뀐 경우에만 스레드를 다시 시작
+        if self.targets != config.ping_targets {
+            *self = Self::with_config(config);
+            return;
+        }
+        for panel in &mut self.panels {
+            panel.graph.apply_theme(&config.theme);
+        }
+    }
+}
+
+impl super::TickingComponent for NetworkLatencyView {
+    fn on_tick(&mut self) {
+        for panel in &mut self.panels {
+            panel.update();
+        }
+    }
+}
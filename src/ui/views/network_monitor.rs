@@ -0,0 +1,279 @@
+use std::{collections::BTreeSet, path::PathBuf};
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::config::{Config, Theme};
+use crate::controllers::interface_filter;
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader};
+use crate::integration::net_speed::LinkSpeed;
+use crate::ui::components::interface_selector::InterfaceSelectorOverlay;
+use crate::ui::components::usage_gauge::{format_rate, ThroughputGraph, UsageGauge};
+
+/// 링크 속도 대비 RX/TX 게이지 높이 (테두리 포함)
+const GAUGE_HEIGHT: u16 = 3;
+
+/// 인터페이스 하나의 링크 속도 대비 게이지와 대역폭 그래프
+struct InterfacePanel {
+    graph: ThroughputGraph,
+    rx_gauge: UsageGauge,
+    tx_gauge: UsageGauge,
+}
+
+impl InterfacePanel {
+    fn new(name: &str, history_size: usize, theme: &Theme) -> Self {
+        let mut panel = Self {
+            graph: ThroughputGraph::new(name.to_string(), "RX", "TX")
+                .with_history_size(history_size),
+            rx_gauge: UsageGauge::new("RX"),
+            tx_gauge: UsageGauge::new("TX"),
+        };
+        panel.apply_theme(theme);
+        panel
+    }
+
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.graph.apply_theme(theme);
+        self.rx_gauge.apply_theme(theme);
+        self.tx_gauge.apply_theme(theme);
+    }
+
+    /// 새 대역폭 반영 (링크 속도는 재협상될 수 있어 매번 다시 읽음)
+    fn update(&mut self, name: &str, rx: f64, tx: f64) {
+        self.graph.push(rx, tx);
+        let (rx, tx) = (self.graph.current_in(), self.graph.current_out());
+        self.graph
+            .set_title(format!("{} ↓{} ↑{}", name, format_rate(rx), format_rate(tx)));
+
+        let link = LinkSpeed::detect(name);
+        // 링크 속도를 모르면 1000 Mbps로 가정했다는 표시
+        let speed = format!(
+            "{} Mbps{}",
+            link.mbps,
+            if link.assumed { " (?)" } else { "" }
+        );
+        for (gauge, direction, rate) in [
+            (&mut self.rx_gauge, "RX ↓", rx),
+            (&mut self.tx_gauge, "TX ↑", tx),
+        ] {
+            let percent = link.percent_of(rate);
+            gauge.set_usage(percent);
+            gauge.set_title(format!("{} / {}", direction, speed));
+            gauge.set_label(Some(format!("{} ({:.1}%)", format_rate(rate), percent)));
+        }
+    }
+
+    /// 위쪽에 RX/TX 게이지를 나란히, 남은 영역에 그래프
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let [gauge_area, graph_area] =
+            Layout::vertical([Constraint::Length(GAUGE_HEIGHT), Constraint::Min(0)]).areas(area);
+        let [rx_area, tx_area] =
+            Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+                .areas(gauge_area);
+        self.rx_gauge.render(frame, rx_area);
+        self.tx_gauge.render(frame, tx_area);
+        if !graph_area.is_empty() {
+            self.graph.render(frame, graph_area);
+        }
+    }
+}
+
+/// 인터페이스별 링크 속도 대비 송수신 게이지와 대역폭 그래프 뷰
+pub struct NetworkMonitorView {
+    metrics: SnapshotReader,
+    /// (인터페이스 이름, 패널) - 이름순 정렬
+    panels: Vec<(String, InterfacePanel)>,
+    /// 새 인터페이스 패널 생성 시 적용할 테마와 히스토리 길이
+    theme: Theme,
+    history_size: usize,
+    /// 그래프를 숨길 인터페이스 (숨겨도 히스토리는 계속 갱신)
+    hidden: BTreeSet<String>,
+    /// 숨김 목록 저장 경로
+    filter_path: Option<PathBuf>,
+    /// 숨김 목록 저장 실패 사유
+    filter_error: Option<String>,
+    /// 인터페이스 선택 모달 (F 키로 열기)
+    selector: InterfaceSelectorOverlay,
+}
+
+impl NetworkMonitorView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default(), SharedSnapshot::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성
+    pub fn with_config(config: &Config, metrics: SharedSnapshot) -> Self {
+        let filter_path = interface_filter::default_filter_path();
+        let hidden = filter_path
+            .as_deref()
+            .map(interface_filter::load_hidden_interfaces)
+            .unwrap_or_default();
+
+        Self {
+            metrics: SnapshotReader::new(metrics),
+            panels: Vec::new(),
+            theme: config.theme.clone(),
+            history_size: config.history_size,
+            hidden,
+            filter_path,
+            filter_error: None,
+            selector: InterfaceSelectorOverlay::new(),
+        }
+    }
+
+    /// 선택한 숨김 목록을 적용하고 파일에 저장
+    fn apply_filter(&mut self, hidden: BTreeSet<String>) {
+        self.hidden = hidden;
+        self.filter_error = self.filter_path.as_deref().and_then(|path| {
+            interface_filter::save_hidden_interfaces(path, &self.hidden)
+                .err()
+                .map(|e| format!("Cannot save filter: {}", e))
+        });
+    }
+
+    /// 새 스냅샷의 인터페이스별 대역폭 반영
+    fn refresh(&mut self) {
+        let Some(snapshot) = self.metrics.poll() else {
+            return;
+        };
+
+        // 사라진 인터페이스 제거
+        self.panels
+            .retain(|(name, _)| snapshot.network(name).is_some());
+
+        for network in &snapshot.networks {
+            let name = &network.name;
+            let index = match self.panels.binary_search_by(|(n, _)| n.cmp(name)) {
+                Ok(index) => index,
+                Err(index) => {
+                    let panel = InterfacePanel::new(name, self.history_size, &self.theme);
+                    self.panels.insert(index, (name.clone(), panel));
+                    index
+                }
+            };
+
+            let (_, panel) = &mut self.panels[index];
+            panel.update(name, network.rx_bytes_per_sec, network.tx_bytes_per_sec);
+        }
+    }
+}
+
+impl Default for NetworkMonitorView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for NetworkMonitorView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let title = match self.filter_error {
+            Some(ref error) => format!(" Network Monitor - {} ", error),
+            None if !self.hidden.is_empty() => {
+                format!(" Network Monitor ({} hidden) ", self.hidden.len())
+            }
+            None => " Network Monitor ".to_string(),
+        };
+        let block = Block::default()
+            .title(title)
+            .title_bottom(" F: select interfaces ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let visible: Vec<&InterfacePanel> = self
+            .panels
+            .iter()
+            .filter(|(name, _)| !self.hidden.contains(name))
+            .map(|(_, panel)| panel)
+            .collect();
+
+        if visible.is_empty() {
+            let text = if self.panels.is_empty() {
+                "No network interfaces"
+            } else {
+                "All interfaces hidden (F: select interfaces)"
+            };
+            let message = Paragraph::new(text).style(Style::default().fg(Color::Gray));
+            frame.render_widget(message, inner);
+        } else {
+            // 인터페이스가 하나면 전체 영역 사용, 아니면 2열 배치
+            let columns = if visible.len() == 1 { 1 } else { 2 };
+            let row_count = visible.len().div_ceil(columns);
+            let rows = Layout::vertical(vec![Constraint::Ratio(1, row_count as u32); row_count])
+                .split(inner);
+
+            for (row_area, chunk) in rows.iter().zip(visible.chunks(columns)) {
+                let cells =
+                    Layout::horizontal(vec![Constraint::Ratio(1, columns as u32); columns])
+                        .split(*row_area);
+                for (cell, panel) in cells.iter().zip(chunk) {
+                    panel.render(frame, *cell);
+                }
+            }
+        }
+
+        if self.selector.is_open() {
+            self.selector.render(frame, area);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Network Monitor"
+    }
+
+    fn title(&self) -> &str {
+        "Net Monitor"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 16)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("F", "Select interfaces"),
+            ("↑ / ↓", "Move selection (selector)"),
+            ("Space", "Toggle interface (selector)"),
+            ("Enter", "Apply filter (selector)"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        // 모달이 열려 있으면 모든 키를 모달이 처리
+        if self.selector.is_open() {
+            if let Some(hidden) = self.selector.handle_key(key) {
+                self.apply_filter(hidden);
+            }
+            return true;
+        }
+
+        match key {
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                let names = self.panels.iter().map(|(name, _)| name.as_str());
+                self.selector.open(names, &self.hidden);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.theme = config.theme.clone();
+        for (_, panel) in &mut self.panels {
+            panel.apply_theme(&self.theme);
+        }
+    }
+}
+
+impl super::TickingComponent for NetworkMonitorView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
@@ -0,0 +1,246 @@
+use std::time::{Duration, Instant};
+
+use battery::units::{power::watt, ratio::percent, time::second};
+use battery::{Battery, Manager, State};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::config::{Config, Theme};
+use crate::ui::components::usage_gauge::{UsageGauge, UsageGraph};
+
+/// 배터리 정보 갱신 간격 (그래프 샘플 간격)
+const BATTERY_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 초 단위 시간을 "1h 23m" 형식으로 변환
+fn format_hours(secs: f32) -> String {
+    let minutes = (secs / 60.0) as u64;
+    format!("{}h {}m", minutes / 60, minutes % 60)
+}
+
+/// 잔량에 따른 색상 (사용량과 반대로 낮을수록 빨간색)
+fn charge_color(charge: f64, theme: &Theme) -> Color {
+    if charge <= 20.0 {
+        theme.high
+    } else if charge <= 50.0 {
+        theme.mid
+    } else {
+        theme.low
+    }
+}
+
+/// 배터리 하나의 상태와 잔량 그래프
+struct BatteryPanel {
+    battery: Battery,
+    theme: Theme,
+    gauge: UsageGauge,
+    graph: UsageGraph,
+}
+
+impl BatteryPanel {
+    fn new(index: usize, battery: Battery, config: &Config) -> Self {
+        let name = match battery.model() {
+            Some(model) => format!("Battery {} ({})", index, model.trim()),
+            None => format!("Battery {}", index),
+        };
+        // 잔량 그래프는 색상 경고 없이 항상 초록색
+        let mut graph = UsageGraph::new(format!("{} - Charge", name))
+            .with_history_size(config.history_size)
+            .with_thresholds(100.0, 100.0);
+        graph.apply_theme(&config.theme);
+        graph.set_expected_interval(BATTERY_REFRESH_INTERVAL);
+
+        let mut gauge = UsageGauge::new(name);
+        gauge.apply_theme(&config.theme);
+
+        let mut panel = Self {
+            battery,
+            theme: config.theme.clone(),
+            gauge,
+            graph,
+        };
+        panel.update();
+        panel
+    }
+
+    fn charge_percent(&self) -> f64 {
+        self.battery.state_of_charge().get::<percent>() as f64
+    }
+
+    fn update(&mut self) {
+        let charge = self.charge_percent();
+        self.gauge.set_usage(charge);
+        self.gauge.set_color(charge_color(charge, &self.theme));
+        self.graph.push(charge);
+    }
+
+    /// 상태, 충/방전 속도, 남은 시간, 사이클 수, 수명
+    fn details(&self) -> Line<'static> {
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+        let battery = &self.battery;
+
+        let remaining = match battery.state() {
+            State::Charging => battery
+                .time_to_full()
+                .map(|t| format!("{} to full", format_hours(t.get::<second>()))),
+            State::Discharging => battery
+                .time_to_empty()
+                .map(|t| format!("{} to empty", format_hours(t.get::<second>()))),
+            _ => None,
+        }
+        .unwrap_or_else(|| "-".to_string());
+        let cycles = battery
+            .cycle_count()
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "N/A".to_string());
+
+        Line::from(vec![
+            label("State "),
+            Span::raw(format!("{}  ", battery.state())),
+            label("Rate "),
+            Span::raw(format!("{:.1}W  ", battery.energy_rate().get::<watt>())),
+            label("Remaining "),
+            Span::raw(format!("{}  ", remaining)),
+            label("Cycles "),
+            Span::raw(format!("{}  ", cycles)),
+            label("Health "),
+            Span::raw(format!(
+                "{:.0}%",
+                battery.state_of_health().get::<percent>()
+            )),
+        ])
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let [details_area, gauge_area, graph_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .areas(area);
+
+        frame.render_widget(Paragraph::new(self.details()), details_area);
+        self.gauge.render(frame, gauge_area);
+        self.graph.render(frame, graph_area);
+    }
+}
+
+/// 배터리/전원 상태 뷰 (노트북용)
+pub struct PowerView {
+    manager: Option<Manager>,
+    batteries: Vec<BatteryPanel>,
+    last_refresh: Instant,
+}
+
+impl PowerView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성
+    pub fn with_config(config: &Config) -> Self {
+        let manager = Manager::new().ok();
+        let batteries = manager
+            .as_ref()
+            .and_then(|manager| manager.batteries().ok())
+            .map(|batteries| {
+                batteries
+                    .flatten()
+                    .enumerate()
+                    .map(|(index, battery)| BatteryPanel::new(index, battery, config))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            manager,
+            batteries,
+            last_refresh: Instant::now(),
+        }
+    }
+
+    fn refresh(&mut self) {
+        if self.last_refresh.elapsed() < BATTERY_REFRESH_INTERVAL {
+            return;
+        }
+        self.last_refresh = Instant::now();
+
+        let Some(ref manager) = self.manager else {
+            return;
+        };
+        for panel in &mut self.batteries {
+            if manager.refresh(&mut panel.battery).is_ok() {
+                panel.update();
+            }
+        }
+    }
+}
+
+impl Default for PowerView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for PowerView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(" Power ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Green));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.batteries.is_empty() {
+            let [_, message_area, _] = Layout::vertical([
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+            ])
+            .areas(inner);
+            let message = Paragraph::new("No battery detected (desktop system)")
+                .style(Style::default().fg(Color::Gray))
+                .centered();
+            frame.render_widget(message, message_area);
+            return;
+        }
+
+        let count = self.batteries.len() as u32;
+        let areas = Layout::vertical(vec![Constraint::Ratio(1, count); count as usize]).split(inner);
+        for (panel, panel_area) in self.batteries.iter().zip(areas.iter()) {
+            panel.render(frame, *panel_area);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Power"
+    }
+
+    fn title(&self) -> &str {
+        "Power"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 16)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        for panel in &mut self.batteries {
+            panel.theme = config.theme.clone();
+            panel.gauge.apply_theme(&config.theme);
+            panel.gauge.set_color(charge_color(panel.charge_percent(), &panel.theme));
+            panel.graph.apply_theme(&config.theme);
+        }
+    }
+}
+
+impl super::TickingComponent for PowerView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
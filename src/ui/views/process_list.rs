@@ -0,0 +1,623 @@
+use std::{cell::Cell, cmp::Ordering, collections::HashMap, sync::Arc};
+
+use ratatui::{
+    crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind},
+    layout::{Constraint, Direction, Layout, Margin, Position, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, Signal, System};
+
+use crate::controllers::metrics_collector::{ProcessSnapshot, SharedSnapshot, SnapshotReader};
+use crate::ui::components::{FilterBar, HorizontalScroller};
+
+/// 고정 행 영역 높이 (위 테두리 + 헤더 + VRChat 행)
+const PINNED_HEIGHT: u16 = 3;
+/// 이 값을 넘는 GPU 메모리를 쓰는 프로세스는 노란색으로 강조 (MB)
+const GPU_MEM_HIGHLIGHT_MB: u64 = 1024;
+
+/// 바이트 크기를 MB/GB 문자열로 변환
+fn format_memory(bytes: u64) -> String {
+    let mb = bytes as f64 / 1024.0 / 1024.0;
+    if mb >= 1024.0 {
+        format!("{:.1} GB", mb / 1024.0)
+    } else {
+        format!("{:.1} MB", mb)
+    }
+}
+
+/// 프로세스 한 행
+#[derive(Clone, Debug)]
+pub struct ProcessRow {
+    pub pid: Pid,
+    pub name: String,
+    pub cpu_percent: f32,
+    pub mem_bytes: u64,
+    pub user: String,
+    /// GPU 메모리 사용량 (MB, NVML을 쓸 수 없으면 None)
+    pub gpu_mem_mb: Option<u64>,
+}
+
+/// 프로세스 정렬 열 (F1~F6로 선택, S 키로 순환)
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    Pid,
+    Name,
+    CpuPct,
+    MemBytes,
+    User,
+    /// NVML을 쓸 수 있을 때만 표시
+    GpuMem,
+}
+
+impl SortColumn {
+    /// 표의 열 순서
+    const ALL: [SortColumn; 6] = [
+        SortColumn::Pid,
+        SortColumn::Name,
+        SortColumn::CpuPct,
+        SortColumn::MemBytes,
+        SortColumn::User,
+        SortColumn::GpuMem,
+    ];
+
+    fn next(self) -> Self {
+        match self {
+            SortColumn::Pid => SortColumn::Name,
+            SortColumn::Name => SortColumn::CpuPct,
+            SortColumn::CpuPct => SortColumn::MemBytes,
+            SortColumn::MemBytes => SortColumn::User,
+            SortColumn::User => SortColumn::GpuMem,
+            SortColumn::GpuMem => SortColumn::Pid,
+        }
+    }
+
+    /// F1~F6 키에 해당하는 열
+    fn from_function_key(n: u8) -> Option<Self> {
+        Self::ALL.get((n as usize).checked_sub(1)?).copied()
+    }
+
+    fn header(&self) -> &'static str {
+        match self {
+            SortColumn::Pid => "PID",
+            SortColumn::Name => "Name",
+            SortColumn::CpuPct => "CPU%",
+            SortColumn::MemBytes => "Memory",
+            SortColumn::User => "User",
+            SortColumn::GpuMem => "GPU Mem",
+        }
+    }
+
+    fn width(&self) -> Constraint {
+        match self {
+            SortColumn::Pid | SortColumn::CpuPct => Constraint::Length(8),
+            SortColumn::Name => Constraint::Fill(2),
+            SortColumn::MemBytes | SortColumn::GpuMem => Constraint::Length(10),
+            SortColumn::User => Constraint::Fill(1),
+        }
+    }
+
+    /// 처음 선택했을 때의 방향 (사용량은 큰 값부터)
+    fn default_descending(&self) -> bool {
+        matches!(
+            self,
+            SortColumn::CpuPct | SortColumn::MemBytes | SortColumn::GpuMem
+        )
+    }
+
+    /// 오름차순 기준 비교
+    fn compare(&self, a: &ProcessRow, b: &ProcessRow) -> Ordering {
+        match self {
+            SortColumn::Pid => a.pid.cmp(&b.pid),
+            SortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            SortColumn::CpuPct => a.cpu_percent.total_cmp(&b.cpu_percent),
+            SortColumn::MemBytes => a.mem_bytes.cmp(&b.mem_bytes),
+            SortColumn::User => a.user.cmp(&b.user),
+            SortColumn::GpuMem => a.gpu_mem_mb.cmp(&b.gpu_mem_mb),
+        }
+    }
+}
+
+/// 프로세스 목록 뷰 - 정렬, 이름 필터, 종료(SIGTERM)
+pub struct ProcessListView {
+    metrics: SnapshotReader,
+    /// 마지막으로 행을 만든 프로세스 목록 (같은 목록이면 다시 만들지 않음)
+    processes: Arc<[ProcessSnapshot]>,
+    /// 종료 신호 전송용 (대상 PID만 갱신)
+    system: System,
+    rows: Vec<ProcessRow>,
+    /// 표 맨 위에 고정 표시할 VRChat 프로세스 (실행 중이 아니면 None)
+    vrchat: Option<ProcessRow>,
+    sort: SortColumn,
+    /// 내림차순 여부 (같은 열을 다시 고르면 반전)
+    descending: bool,
+    /// 프로세스 이름 필터 (`/`로 입력, 대소문자 무시)
+    filter_bar: FilterBar,
+    selected: usize,
+    /// 종료 확인 대기 중인 프로세스 (PID, 이름)
+    pending_kill: Option<(Pid, String)>,
+    /// 마지막 종료 요청 결과 메시지
+    status_message: Option<String>,
+    /// 마지막으로 그려진 표 영역과 스크롤 위치 (마우스 클릭 → 행 변환용)
+    table_area: Cell<Rect>,
+    table_offset: Cell<usize>,
+    /// 이름 열 가로 스크롤 (←/→)
+    scroller: HorizontalScroller,
+    /// 수집기가 프로세스별 GPU 메모리를 제공하는지 (NVML을 쓸 수 없으면 GPU Mem 열 생략)
+    show_gpu_mem: bool,
+}
+
+impl ProcessListView {
+    pub fn new(metrics: SharedSnapshot) -> Self {
+        let metrics = SnapshotReader::new(metrics);
+        let show_gpu_mem = metrics.current().gpu_processes.is_some();
+        Self {
+            metrics,
+            processes: Arc::default(),
+            system: System::new(),
+            rows: Vec::new(),
+            vrchat: None,
+            sort: SortColumn::CpuPct,
+            descending: true,
+            filter_bar: FilterBar::new(),
+            selected: 0,
+            pending_kill: None,
+            status_message: None,
+            table_area: Cell::new(Rect::default()),
+            table_offset: Cell::new(0),
+            scroller: HorizontalScroller::new(),
+            show_gpu_mem,
+        }
+    }
+
+    /// 표시할 열 (GPU 메모리 정보가 없으면 GPU Mem 제외)
+    fn columns(&self) -> &'static [SortColumn] {
+        if self.show_gpu_mem {
+            &SortColumn::ALL
+        } else {
+            &SortColumn::ALL[..SortColumn::ALL.len() - 1]
+        }
+    }
+
+    /// 한 행의 열 값 (`columns` 순서)
+    fn cells(&self, row: &ProcessRow) -> Vec<String> {
+        self.columns()
+            .iter()
+            .map(|column| match column {
+                SortColumn::Pid => row.pid.to_string(),
+                SortColumn::Name => self.scroller.shift(&row.name).to_string(),
+                SortColumn::CpuPct => format!("{:.1}", row.cpu_percent),
+                SortColumn::MemBytes => format_memory(row.mem_bytes),
+                SortColumn::User => row.user.clone(),
+                SortColumn::GpuMem => match row.gpu_mem_mb {
+                    Some(mb) if mb > 0 => format_memory(mb * 1024 * 1024),
+                    _ => "-".to_string(),
+                },
+            })
+            .collect()
+    }
+
+    /// 수집기가 프로세스 목록을 새로 읽었을 때만 행을 다시 만듦
+    fn refresh(&mut self) {
+        let Some(snapshot) = self.metrics.poll() else {
+            return;
+        };
+        if Arc::ptr_eq(&snapshot.processes, &self.processes) {
+            return;
+        }
+        self.processes = snapshot.processes;
+
+        self.show_gpu_mem = snapshot.gpu_processes.is_some();
+        let gpu_mem: Option<HashMap<u32, u64>> = snapshot.gpu_processes.map(|processes| {
+            processes
+                .iter()
+                .map(|process| (process.pid, process.gpu_mem_mb))
+                .collect()
+        });
+
+        // Linux에서는 스레드도 목록에 포함되므로 프로세스만 행으로 만듦
+        self.rows = self
+            .processes
+            .iter()
+            .filter(|process| process.thread_kind.is_none())
+            .map(|process| ProcessRow {
+                pid: Pid::from_u32(process.pid),
+                name: process.name.clone(),
+                cpu_percent: process.cpu_usage,
+                mem_bytes: process.memory,
+                user: process.user.clone(),
+                gpu_mem_mb: gpu_mem
+                    .as_ref()
+                    .map(|gpu_mem| gpu_mem.get(&process.pid).copied().unwrap_or(0)),
+            })
+            .collect();
+
+        // 이름에 VRChat이 들어간 프로세스 중 메모리를 가장 많이 쓰는 것 (보조 프로세스 제외)
+        self.vrchat = self
+            .rows
+            .iter()
+            .filter(|row| row.name.to_lowercase().contains("vrchat"))
+            .max_by_key(|row| row.mem_bytes)
+            .cloned();
+        self.sort_rows();
+    }
+
+    /// 고정 VRChat 행 (실행 중이 아니면 회색 안내 행)
+    fn pinned_row(&self) -> Row<'_> {
+        match self.vrchat {
+            Some(ref row) => Row::new(self.cells(row)).style(
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            None => {
+                let mut cells = vec![String::new(); self.columns().len()];
+                cells[0] = "-".to_string();
+                cells[1] = "VRChat: not running".to_string();
+                Row::new(cells).style(Style::default().fg(Color::DarkGray))
+            }
+        }
+    }
+
+    /// 선택한 열로 정렬 (값이 같으면 PID 오름차순)
+    fn sort_rows(&mut self) {
+        let (column, descending) = (self.sort, self.descending);
+        self.rows.sort_by(|a, b| {
+            let order = column.compare(a, b);
+            let order = if descending { order.reverse() } else { order };
+            order.then(a.pid.cmp(&b.pid))
+        });
+    }
+
+    /// 정렬 열 선택 - 이미 선택된 열이면 방향만 반전
+    fn select_sort(&mut self, column: SortColumn) {
+        if self.sort == column {
+            self.descending = !self.descending;
+        } else {
+            self.sort = column;
+            self.descending = column.default_descending();
+        }
+        self.sort_rows();
+    }
+
+    /// 필터가 적용된 행 목록
+    fn visible_rows(&self) -> Vec<&ProcessRow> {
+        self.rows
+            .iter()
+            .filter(|row| self.filter_bar.matches(&row.name))
+            .collect()
+    }
+
+    fn clamp_selection(&mut self) {
+        let count = self.visible_rows().len();
+        self.selected = self.selected.min(count.saturating_sub(1));
+    }
+
+    /// 선택된 프로세스에 대한 종료 확인 시작
+    fn request_kill(&mut self) {
+        let target = self
+            .visible_rows()
+            .get(self.selected)
+            .map(|row| (row.pid, row.name.clone()));
+        self.pending_kill = target;
+    }
+
+    fn confirm_kill(&mut self) {
+        let Some((pid, name)) = self.pending_kill.take() else {
+            return;
+        };
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            true,
+            ProcessRefreshKind::nothing(),
+        );
+        let Some(process) = self.system.process(pid) else {
+            self.status_message = Some(format!("{} ({}) is no longer running", name, pid));
+            return;
+        };
+        self.status_message = Some(match process.kill_with(Signal::Term) {
+            Some(true) => format!("Sent SIGTERM to {} ({})", name, pid),
+            Some(false) => format!("Failed to signal {} ({})", name, pid),
+            None => "SIGTERM is not supported on this platform".to_string(),
+        });
+    }
+
+    fn render_table(&self, frame: &mut Frame, area: Rect) {
+        let widths: Vec<Constraint> = self.columns().iter().map(SortColumn::width).collect();
+        let visible = self.visible_rows();
+
+        // 헤더와 VRChat 행은 스크롤되지 않도록 별도 표로 그림
+        let [pinned_area, area] =
+            Layout::vertical([Constraint::Length(PINNED_HEIGHT), Constraint::Min(0)]).areas(area);
+
+        // 표와 같은 방식으로 열 폭을 나눠 이름 열이 잘리는지 계산
+        let name_width = Layout::horizontal(widths.clone())
+            .spacing(1)
+            .split(area.inner(Margin::new(1, 1)))[1]
+            .width;
+        let longest_name = visible
+            .iter()
+            .chain(self.vrchat.as_ref().iter())
+            .map(|row| row.name.chars().count())
+            .max()
+            .unwrap_or(0);
+        self.scroller.update_bounds(longest_name, name_width);
+
+        let rows: Vec<Row> = visible
+            .iter()
+            .map(|row| {
+                let cells = Row::new(self.cells(row));
+                if row.gpu_mem_mb.is_some_and(|mb| mb > GPU_MEM_HIGHLIGHT_MB) {
+                    cells.style(Style::default().fg(Color::Yellow))
+                } else {
+                    cells
+                }
+            })
+            .collect();
+
+        // 정렬 중인 열 제목에 방향 화살표 표시
+        let header: Vec<String> = self
+            .columns()
+            .iter()
+            .map(|column| {
+                if *column != self.sort {
+                    column.header().to_string()
+                } else if self.descending {
+                    format!("{} ▼", column.header())
+                } else {
+                    format!("{} ▲", column.header())
+                }
+            })
+            .collect();
+
+        let pinned = Table::new([self.pinned_row()], widths.clone())
+            .header(
+                Row::new(header)
+                    .style(Style::default().fg(Color::Cyan).bold()),
+            )
+            .block(
+                Block::default()
+                    .title(format!(" Processes ({}) ", visible.len()))
+                    .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+        frame.render_widget(pinned, pinned_area);
+
+        let table = Table::new(rows, widths)
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM | Borders::LEFT | Borders::RIGHT)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+
+        let mut state = TableState::default()
+            .with_offset(self.table_offset.get())
+            .with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, area, &mut state);
+        self.table_area.set(area);
+        self.table_offset.set(state.offset());
+    }
+
+    /// 클릭 위치에 해당하는 표 행 선택 (아래 테두리 제외, 헤더는 고정 영역에 있음)
+    fn select_at(&mut self, cursor: Position) -> bool {
+        let area = self.table_area.get();
+        let first_row_y = area.y;
+        if !area.contains(cursor) || cursor.y < first_row_y || cursor.y >= area.bottom() - 1 {
+            return false;
+        }
+        let index = self.table_offset.get() + (cursor.y - first_row_y) as usize;
+        if index >= self.visible_rows().len() {
+            return false;
+        }
+        self.selected = index;
+        true
+    }
+
+    /// 맨 아래 줄 - 필터 입력/적용 중이면 필터 바, 아니면 상태 메시지
+    fn render_status_line(&self, frame: &mut Frame, area: Rect) {
+        if self.filter_bar.is_focused() {
+            self.filter_bar.render(frame, area);
+        } else if let Some(ref message) = self.status_message {
+            frame.render_widget(
+                Paragraph::new(message.as_str()).style(Style::default().fg(Color::Gray)),
+                area,
+            );
+        } else if !self.filter_bar.is_empty() {
+            self.filter_bar.render(frame, area);
+        }
+    }
+
+    fn render_kill_prompt(&self, frame: &mut Frame, area: Rect) {
+        let Some((pid, ref name)) = self.pending_kill else {
+            return;
+        };
+        let width = 44.min(area.width);
+        let height = 3.min(area.height);
+        let prompt_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        let prompt = Paragraph::new(format!("Terminate {} ({})? [y/N]", name, pid)).block(
+            Block::default()
+                .title(" Confirm ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red)),
+        );
+        frame.render_widget(Clear, prompt_area);
+        frame.render_widget(prompt, prompt_area);
+    }
+}
+
+impl Default for ProcessListView {
+    fn default() -> Self {
+        Self::new(SharedSnapshot::default())
+    }
+}
+
+impl super::ViewComponent for ProcessListView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(area);
+
+        let title = Paragraph::new(format!(
+            "Processes [F1-F{}/S: sort] [/: filter] [K: terminate] [↑↓: select] [Tab: switch view]",
+            self.columns().len()
+        ))
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, main_chunks[0]);
+
+        self.render_table(frame, main_chunks[1]);
+        self.render_status_line(frame, main_chunks[2]);
+        self.render_kill_prompt(frame, area);
+    }
+
+    fn name(&self) -> &str {
+        "Processes"
+    }
+
+    fn title(&self) -> &str {
+        "Processes"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 12)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑ / ↓", "Select process"),
+            ("F1 - F5", "Sort by column (again: reverse)"),
+            ("F6", "Sort by GPU memory (NVIDIA)"),
+            ("S", "Cycle sort column"),
+            ("/", "Filter by name"),
+            ("K", "Terminate process"),
+            ("← / →", "Scroll long names (when truncated)"),
+            ("Click", "Select row"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        // 종료 확인 중에는 y 외의 키는 모두 취소
+        if self.pending_kill.is_some() {
+            if matches!(key, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.confirm_kill();
+            } else {
+                self.pending_kill = None;
+            }
+            return true;
+        }
+
+        if self.filter_bar.is_focused() {
+            self.filter_bar.handle_key(key);
+            self.selected = 0;
+            return true;
+        }
+
+        // 이름이 잘려 있으면 ←/→는 가로 스크롤, 끝에 닿으면 뷰 전환으로 전달
+        if self.scroller.handle_key(key) {
+            return true;
+        }
+
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected += 1;
+                self.clamp_selection();
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                // 표시하지 않는 열은 건너뜀
+                let mut column = self.sort.next();
+                while !self.columns().contains(&column) {
+                    column = column.next();
+                }
+                self.select_sort(column);
+            }
+            KeyCode::F(n) => match SortColumn::from_function_key(n) {
+                Some(column) if self.columns().contains(&column) => self.select_sort(column),
+                _ => return false,
+            },
+            KeyCode::Char('/') => {
+                self.filter_bar.focus();
+                self.status_message = None;
+            }
+            KeyCode::Char('k') | KeyCode::Char('K') => self.request_kill(),
+            // 필터가 있을 때만 Esc를 소비 (없으면 전역 종료 키로 전달)
+            KeyCode::Esc if !self.filter_bar.is_empty() => {
+                self.filter_bar.clear();
+                self.selected = 0;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> bool {
+        if self.pending_kill.is_some() || event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return false;
+        }
+        self.select_at(Position::new(event.column, event.row))
+    }
+}
+
+impl super::TickingComponent for ProcessListView {
+    fn on_tick(&mut self) {
+        self.refresh();
+        self.filter_bar.tick();
+        self.clamp_selection();
+    }
+
+    fn background_refresh_policy(&self) -> super::BackgroundRefreshPolicy {
+        // 전환 직후에도 CPU% 계산에 필요한 이전 샘플이 있도록 계속 갱신
+        super::BackgroundRefreshPolicy::AlwaysRefresh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::views::TickingComponent;
+
+    fn process(pid: u32, thread_kind: Option<sysinfo::ThreadKind>) -> ProcessSnapshot {
+        ProcessSnapshot {
+            pid,
+            parent: None,
+            name: format!("proc{}", pid),
+            cpu_usage: 0.0,
+            memory: 0,
+            user: "-".to_string(),
+            task_count: 1,
+            thread_kind,
+        }
+    }
+
+    #[test]
+    fn thread_entries_are_not_listed_as_processes() {
+        let metrics = SharedSnapshot::default();
+        {
+            let mut snapshot = metrics.lock().unwrap();
+            snapshot.sequence = 1;
+            snapshot.processes = Arc::from([
+                process(1, None),
+                process(2, Some(sysinfo::ThreadKind::Userland)),
+                process(3, None),
+            ]);
+        }
+        let mut view = ProcessListView::new(metrics);
+        view.on_tick();
+
+        let pids: Vec<u32> = view.rows.iter().map(|row| row.pid.as_u32()).collect();
+        assert_eq!(pids.len(), 2);
+        assert!(!pids.contains(&2));
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use sysinfo::ThreadKind;
+
+use crate::controllers::metrics_collector::{ProcessSnapshot, SharedSnapshot, SnapshotReader};
+
+/// PageUp/PageDown 한 번에 이동할 행 수
+const PAGE_STEP: usize = 10;
+
+/// 트리의 노드 (부모가 목록에 없는 프로세스는 가상 `[orphans]` 노드 아래에 모음)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TreeNodeId {
+    Process(u32),
+    Orphans,
+}
+
+/// 화면에 그릴 트리 한 줄
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeLine {
+    pub id: TreeNodeId,
+    /// `├── `, `└── `, `│   `로 이루어진 들여쓰기
+    pub prefix: String,
+    pub label: String,
+    /// 숨겨진 자식 수 (접혀 있지 않으면 0)
+    pub hidden_children: usize,
+}
+
+/// 부모 → 자식 관계로 묶은 프로세스 목록
+#[derive(Default)]
+pub struct ProcessTree {
+    names: HashMap<u32, String>,
+    children: HashMap<u32, Vec<u32>>,
+    /// 부모가 없는 프로세스 (PID 1 등)
+    roots: Vec<u32>,
+    /// 부모가 목록에 없는 프로세스
+    orphans: Vec<u32>,
+}
+
+impl ProcessTree {
+    /// (PID, 부모 PID, 이름) 목록으로 트리 구성 (형제는 PID 순)
+    pub fn build(processes: impl IntoIterator<Item = (u32, Option<u32>, String)>) -> Self {
+        let processes: Vec<(u32, Option<u32>, String)> = processes.into_iter().collect();
+        let mut tree = Self::default();
+        for (pid, _, name) in &processes {
+            tree.names.insert(*pid, name.clone());
+        }
+        for (pid, parent, _) in &processes {
+            match parent {
+                Some(parent) if tree.names.contains_key(parent) && parent != pid => {
+                    tree.children.entry(*parent).or_default().push(*pid);
+                }
+                Some(_) => tree.orphans.push(*pid),
+                None => tree.roots.push(*pid),
+            }
+        }
+        tree.roots.sort_unstable();
+        tree.orphans.sort_unstable();
+        for children in tree.children.values_mut() {
+            children.sort_unstable();
+        }
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    fn children_of(&self, id: TreeNodeId) -> &[u32] {
+        match id {
+            TreeNodeId::Process(pid) => self.children.get(&pid).map_or(&[], Vec::as_slice),
+            TreeNodeId::Orphans => &self.orphans,
+        }
+    }
+
+    /// 접힌 노드의 하위 트리를 빼고 위에서부터 펼친 줄 목록
+    pub fn lines(&self, collapsed: &HashSet<TreeNodeId>) -> Vec<TreeLine> {
+        let mut top: Vec<TreeNodeId> = self.roots.iter().map(|&pid| TreeNodeId::Process(pid)).collect();
+        if !self.orphans.is_empty() {
+            top.push(TreeNodeId::Orphans);
+        }
+
+        let mut lines = Vec::new();
+        for id in top {
+            self.push_lines(id, String::new(), String::new(), collapsed, &mut lines);
+        }
+        lines
+    }
+
+    /// `prefix`는 이 줄의 들여쓰기, `child_prefix`는 자식 줄이 이어받을 들여쓰기
+    fn push_lines(
+        &self,
+        id: TreeNodeId,
+        prefix: String,
+        child_prefix: String,
+        collapsed: &HashSet<TreeNodeId>,
+        lines: &mut Vec<TreeLine>,
+    ) {
+        let label = match id {
+            TreeNodeId::Process(pid) => {
+                format!("{} {}", pid, self.names.get(&pid).map_or("", String::as_str))
+            }
+            TreeNodeId::Orphans => "[orphans]".to_string(),
+        };
+        let children = self.children_of(id);
+        let is_collapsed = collapsed.contains(&id);
+        lines.push(TreeLine {
+            id,
+            prefix,
+            label,
+            hidden_children: if is_collapsed { children.len() } else { 0 },
+        });
+        if is_collapsed {
+            return;
+        }
+
+        for (i, &child) in children.iter().enumerate() {
+            let last = i + 1 == children.len();
+            let (branch, continuation) = if last { ("└── ", "    ") } else { ("├── ", "│   ") };
+            self.push_lines(
+                TreeNodeId::Process(child),
+                format!("{}{}", child_prefix, branch),
+                format!("{}{}", child_prefix, continuation),
+                collapsed,
+                lines,
+            );
+        }
+    }
+}
+
+/// 프로세스 부모-자식 관계를 트리로 보여주는 뷰 (Enter로 하위 트리 접기)
+pub struct ProcessTreeView {
+    metrics: SnapshotReader,
+    /// 마지막으로 트리를 만든 프로세스 목록 (같은 목록이면 다시 만들지 않음)
+    processes: Arc<[ProcessSnapshot]>,
+    tree: ProcessTree,
+    collapsed: HashSet<TreeNodeId>,
+    /// 현재 펼쳐진 줄 목록 (갱신하거나 접을 때 다시 계산)
+    lines: Vec<TreeLine>,
+    selected: usize,
+}
+
+impl ProcessTreeView {
+    pub fn new(metrics: SharedSnapshot) -> Self {
+        let mut view = Self {
+            metrics: SnapshotReader::new(metrics),
+            processes: Arc::default(),
+            tree: ProcessTree::default(),
+            collapsed: HashSet::new(),
+            lines: Vec::new(),
+            selected: 0,
+        };
+        view.refresh();
+        view
+    }
+
+    /// 수집기가 프로세스 목록을 새로 읽었을 때만 트리를 다시 만듦
+    fn refresh(&mut self) {
+        let Some(snapshot) = self.metrics.poll() else {
+            return;
+        };
+        if Arc::ptr_eq(&snapshot.processes, &self.processes) {
+            return;
+        }
+        self.processes = snapshot.processes;
+
+        // 사용자 스레드는 프로세스와 같은 트리에 넣지 않음
+        self.tree = ProcessTree::build(
+            self.processes
+                .iter()
+                .filter(|process| process.thread_kind != Some(ThreadKind::Userland))
+                .map(|process| (process.pid, process.parent, process.name.clone())),
+        );
+        self.rebuild_lines();
+    }
+
+    /// 줄 목록을 다시 계산하고, 선택했던 노드가 남아 있으면 계속 선택
+    fn rebuild_lines(&mut self) {
+        let selected_id = self.lines.get(self.selected).map(|line| line.id);
+        self.lines = self.tree.lines(&self.collapsed);
+        self.selected = selected_id
+            .and_then(|id| self.lines.iter().position(|line| line.id == id))
+            .unwrap_or(self.selected)
+            .min(self.lines.len().saturating_sub(1));
+    }
+
+    /// 선택한 노드 접기/펼치기 (자식이 없으면 무시)
+    fn toggle_selected(&mut self) {
+        let Some(line) = self.lines.get(self.selected) else {
+            return;
+        };
+        let id = line.id;
+        if !self.collapsed.remove(&id) && !self.tree.children_of(id).is_empty() {
+            self.collapsed.insert(id);
+        }
+        self.rebuild_lines();
+    }
+}
+
+impl Default for ProcessTreeView {
+    fn default() -> Self {
+        Self::new(SharedSnapshot::default())
+    }
+}
+
+impl super::ViewComponent for ProcessTreeView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let mut spans = vec![
+                    Span::styled(line.prefix.clone(), Style::default().fg(Color::DarkGray)),
+                    Span::raw(line.label.clone()),
+                ];
+                if line.hidden_children > 0 {
+                    spans.push(Span::styled(
+                        format!(" [+{}]", line.hidden_children),
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(format!(" Process Tree ({} processes) ", self.tree.len()))
+                    .title_bottom(" ↑↓: select  Enter: collapse/expand ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            )
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn name(&self) -> &str {
+        "Process Tree"
+    }
+
+    fn title(&self) -> &str {
+        "Proc Tree"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 10)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("↑ / ↓", "Select process"),
+            ("PgUp / PgDn", "Move by page"),
+            ("Enter", "Collapse / expand subtree"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        let last = self.lines.len().saturating_sub(1);
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(last),
+            KeyCode::PageUp => self.selected = self.selected.saturating_sub(PAGE_STEP),
+            KeyCode::PageDown => self.selected = (self.selected + PAGE_STEP).min(last),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = last,
+            KeyCode::Enter => self.toggle_selected(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl super::TickingComponent for ProcessTreeView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn process_tree_nests_children_and_groups_orphans() {
+        let tree = ProcessTree::build([
+            (1, None, "init".to_string()),
+            (10, Some(1), "VRChat".to_string()),
+            (11, Some(10), "uploader".to_string()),
+            (12, Some(1), "sshd".to_string()),
+            (50, Some(999), "lost".to_string()),
+        ]);
+        let rendered: Vec<String> = tree
+            .lines(&HashSet::new())
+            .iter()
+            .map(|line| format!("{}{}", line.prefix, line.label))
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "1 init",
+                "├── 10 VRChat",
+                "│   └── 11 uploader",
+                "└── 12 sshd",
+                "[orphans]",
+                "└── 50 lost",
+            ]
+        );
+
+        let collapsed = HashSet::from([TreeNodeId::Process(10)]);
+        let lines = tree.lines(&collapsed);
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[1].hidden_children, 1);
+    }
+}
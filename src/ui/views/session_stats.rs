@@ -0,0 +1,235 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Row, Table},
+    Frame,
+};
+
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader};
+
+/// 뷰별로 보관하는 draw 시간 샘플 수
+const DRAW_SAMPLE_SIZE: usize = 100;
+/// 이 값을 넘는 P95는 끊김 원인으로 간주 (ns)
+const JANK_THRESHOLD_NS: u32 = 5_000_000;
+
+/// 한 뷰의 최근 draw 시간 (ns, 메모리 절약을 위해 u32)
+#[derive(Default)]
+pub struct DrawTimes {
+    samples: VecDeque<u32>,
+}
+
+impl DrawTimes {
+    pub fn push(&mut self, elapsed: Duration) {
+        if self.samples.len() >= DRAW_SAMPLE_SIZE {
+            self.samples.pop_front();
+        }
+        self.samples
+            .push_back(elapsed.as_nanos().min(u32::MAX as u128) as u32);
+    }
+
+    pub fn average_ns(&self) -> u32 {
+        if self.samples.is_empty() {
+            return 0;
+        }
+        let total: u64 = self.samples.iter().map(|&s| s as u64).sum();
+        (total / self.samples.len() as u64) as u32
+    }
+
+    pub fn p95_ns(&self) -> u32 {
+        let mut sorted: Vec<u32> = self.samples.iter().copied().collect();
+        sorted.sort_unstable();
+        let index = (sorted.len() * 95 / 100).min(sorted.len().saturating_sub(1));
+        sorted.get(index).copied().unwrap_or(0)
+    }
+
+    pub fn max_ns(&self) -> u32 {
+        self.samples.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// 뷰 이름별 draw 시간 기록 (App과 SessionStatsView가 공유)
+#[derive(Default)]
+pub struct DrawProfiler {
+    /// 등록 순서 유지를 위한 뷰 이름 목록
+    order: Vec<String>,
+    times: HashMap<String, DrawTimes>,
+}
+
+impl DrawProfiler {
+    pub fn register(&mut self, name: &str) {
+        if !self.times.contains_key(name) {
+            self.order.push(name.to_string());
+            self.times.insert(name.to_string(), DrawTimes::default());
+        }
+    }
+
+    pub fn record(&mut self, name: &str, elapsed: Duration) {
+        self.register(name);
+        if let Some(times) = self.times.get_mut(name) {
+            times.push(elapsed);
+        }
+    }
+
+    /// 등록 순서대로 (이름, 기록) 반환
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &DrawTimes)> {
+        self.order
+            .iter()
+            .filter_map(|name| self.times.get(name).map(|t| (name.as_str(), t)))
+    }
+}
+
+pub type SharedDrawProfiler = Rc<RefCell<DrawProfiler>>;
+
+/// 세션 동안의 메트릭 평균과 뷰별 draw 시간 통계
+pub struct SessionStatsView {
+    metrics: SnapshotReader,
+    started_at: Instant,
+    samples: u64,
+    cpu_sum: f64,
+    memory_sum: f64,
+    profiler: SharedDrawProfiler,
+}
+
+impl SessionStatsView {
+    pub fn new(profiler: SharedDrawProfiler, metrics: SharedSnapshot) -> Self {
+        Self {
+            metrics: SnapshotReader::new(metrics),
+            started_at: Instant::now(),
+            samples: 0,
+            cpu_sum: 0.0,
+            memory_sum: 0.0,
+            profiler,
+        }
+    }
+
+    fn refresh(&mut self) {
+        let Some(snapshot) = self.metrics.poll() else {
+            return;
+        };
+        self.cpu_sum += snapshot.cpu_usage;
+        self.memory_sum += snapshot.memory_percent();
+        self.samples += 1;
+    }
+
+    fn average(&self, sum: f64) -> String {
+        if self.samples == 0 {
+            return "-".to_string();
+        }
+        format!("{:.1}%", sum / self.samples as f64)
+    }
+
+    fn render_metrics(&self, frame: &mut Frame, area: Rect) {
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+        let rows = vec![
+            Row::new(vec![
+                label("Uptime"),
+                Span::raw(format!("{}s", self.started_at.elapsed().as_secs())),
+            ]),
+            Row::new(vec![label("Avg CPU"), Span::raw(self.average(self.cpu_sum))]),
+            Row::new(vec![
+                label("Avg Memory"),
+                Span::raw(self.average(self.memory_sum)),
+            ]),
+        ];
+
+        let table = Table::new(rows, [Constraint::Length(16), Constraint::Fill(1)]).block(
+            Block::default()
+                .title(" Metric Averages ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, area);
+    }
+
+    fn render_draw_times(&self, frame: &mut Frame, area: Rect) {
+        let micros = |ns: u32| format!("{:.1}", ns as f64 / 1000.0);
+
+        let profiler = self.profiler.borrow();
+        let rows: Vec<Row> = profiler
+            .iter()
+            .map(|(name, times)| {
+                let row = Row::new(vec![
+                    name.to_string(),
+                    micros(times.average_ns()),
+                    micros(times.p95_ns()),
+                    micros(times.max_ns()),
+                ]);
+                // P95가 5ms를 넘으면 끊김 원인 후보로 노란색 표시
+                if times.p95_ns() > JANK_THRESHOLD_NS {
+                    row.style(Style::default().fg(Color::Yellow))
+                } else {
+                    row
+                }
+            })
+            .collect();
+
+        let header = Row::new(vec!["View", "Avg Draw (μs)", "P95 Draw (μs)", "Max Draw (μs)"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(14),
+                Constraint::Length(14),
+                Constraint::Length(14),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .title(" Draw Times ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, area);
+    }
+}
+
+impl Default for SessionStatsView {
+    fn default() -> Self {
+        Self::new(SharedDrawProfiler::default(), SharedSnapshot::default())
+    }
+}
+
+impl super::ViewComponent for SessionStatsView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(" Session Stats ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::vertical([Constraint::Length(5), Constraint::Min(0)]).split(inner);
+        self.render_metrics(frame, chunks[0]);
+        self.render_draw_times(frame, chunks[1]);
+    }
+
+    fn name(&self) -> &str {
+        "Session Stats"
+    }
+
+    fn title(&self) -> &str {
+        "Session"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 16)
+    }
+}
+
+impl super::TickingComponent for SessionStatsView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
@@ -1,11 +1,34 @@
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::KeyCode;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::style::{Color, Style, Stylize};
+use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Row, Table};
-use sysinfo::System;
+use sysinfo::{CpuRefreshKind, LoadAvg, MemoryRefreshKind, RefreshKind, System};
+
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader};
+use crate::ui::components::HorizontalScroller;
+
+/// 업타임/부하 갱신 간격
+const RUNTIME_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+/// 표의 레이블 열 폭
+const LABEL_WIDTH: u16 = 20;
+
+/// 초 단위 시간을 "1d 2h 3m 4s" 형식으로 변환
+fn format_uptime(secs: u64) -> String {
+    format!(
+        "{}d {}h {}m {}s",
+        secs / 86_400,
+        secs % 86_400 / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
 
 pub struct StatusView {
-    system: System,
+    /// 프로세스/스레드 수는 수집기의 프로세스 목록에서 셈
+    metrics: SnapshotReader,
     os_name: String,
     os_version: String,
     kernel_version: String,
@@ -13,12 +36,23 @@ pub struct StatusView {
     cpu_name: String,
     cpu_cores: usize,
     total_memory_gb: f64,
+    uptime_secs: u64,
+    load_average: LoadAvg,
+    process_count: usize,
+    thread_count: usize,
+    last_refresh: Option<Instant>,
+    /// OS/하드웨어 값 열 가로 스크롤 (←/→)
+    scroller: HorizontalScroller,
 }
 
 impl StatusView {
-    pub fn new() -> Self {
-        let mut system = System::new_all();
-        system.refresh_all();
+    pub fn new(metrics: SharedSnapshot) -> Self {
+        // CPU 이름/개수와 전체 메모리만 한 번 읽음 (프로세스 목록은 읽지 않음)
+        let system = System::new_with_specifics(
+            RefreshKind::nothing()
+                .with_cpu(CpuRefreshKind::nothing())
+                .with_memory(MemoryRefreshKind::nothing().with_ram()),
+        );
 
         let os_name = System::name().unwrap_or_else(|| "Unknown".to_string());
         let os_version = System::os_version().unwrap_or_else(|| "Unknown".to_string());
@@ -33,8 +67,8 @@ impl StatusView {
         let cpu_cores = system.cpus().len();
         let total_memory_gb = system.total_memory() as f64 / 1024.0 / 1024.0 / 1024.0;
 
-        StatusView {
-            system,
+        let mut view = StatusView {
+            metrics: SnapshotReader::new(metrics),
             os_name,
             os_version,
             kernel_version,
@@ -42,16 +76,77 @@ impl StatusView {
             cpu_name,
             cpu_cores,
             total_memory_gb,
+            uptime_secs: 0,
+            load_average: LoadAvg::default(),
+            process_count: 0,
+            thread_count: 0,
+            last_refresh: None,
+            scroller: HorizontalScroller::new(),
+        };
+        view.refresh_runtime();
+        view
+    }
+
+    /// 업타임, 부하 평균, 프로세스/스레드 수 갱신
+    fn refresh_runtime(&mut self) {
+        if self
+            .last_refresh
+            .is_some_and(|t| t.elapsed() < RUNTIME_REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.last_refresh = Some(Instant::now());
+
+        self.uptime_secs = System::uptime();
+        self.load_average = System::load_average();
+
+        let snapshot = self.metrics.current();
+        // Linux에서는 스레드도 목록에 포함되므로 제외하고 센다
+        let processes: Vec<_> = snapshot
+            .processes
+            .iter()
+            .filter(|process| process.thread_kind.is_none())
+            .collect();
+        self.process_count = processes.len();
+        self.thread_count = processes.iter().map(|process| process.task_count).sum();
+    }
+
+    /// CPU 수 대비 부하 색상 (1배 미만 초록, 2배 이하 노랑, 초과 빨강)
+    fn load_color(&self, load: f64) -> Color {
+        let cores = self.cpu_cores.max(1) as f64;
+        if load < cores {
+            Color::Green
+        } else if load <= cores * 2.0 {
+            Color::Yellow
+        } else {
+            Color::Red
         }
     }
 }
 
 impl super::ViewComponent for StatusView {
     fn draw_with_area(&self, frame: &mut ratatui::Frame, area: Rect) {
-        let block = Block::default()
+        // 값 열 폭 = 안쪽 폭 - 표 테두리 - 레이블 열 - 열 간격
+        let value_width = area.width.saturating_sub(2 + 2 + LABEL_WIDTH + 1);
+        let longest_value = [
+            &self.os_name,
+            &self.os_version,
+            &self.kernel_version,
+            &self.cpu_name,
+        ]
+        .iter()
+        .map(|value| value.chars().count())
+        .max()
+        .unwrap_or(0);
+        self.scroller.update_bounds(longest_value, value_width);
+
+        let mut block = Block::default()
             .title(" System Status ")
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan));
+        if self.scroller.can_scroll() {
+            block = block.title_bottom(" ←/→: scroll ");
+        }
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
@@ -60,7 +155,7 @@ impl super::ViewComponent for StatusView {
             Constraint::Length(3),  // Title
             Constraint::Length(8),  // OS Info Table
             Constraint::Length(5),  // Hardware Info Table
-            Constraint::Min(1),     // 나머지 공간
+            Constraint::Length(6),  // Runtime Table
         ])
         .split(inner);
 
@@ -76,21 +171,30 @@ impl super::ViewComponent for StatusView {
         let os_rows = vec![
             Row::new(vec![
                 Span::styled("Operating System", Style::default().fg(Color::Gray)),
-                Span::styled(&self.os_name, Style::default().fg(Color::Green)),
+                Span::styled(
+                    self.scroller.shift(&self.os_name),
+                    Style::default().fg(Color::Green),
+                ),
             ]),
             Row::new(vec![
                 Span::styled("OS Version", Style::default().fg(Color::Gray)),
-                Span::styled(&self.os_version, Style::default().fg(Color::White)),
+                Span::styled(
+                    self.scroller.shift(&self.os_version),
+                    Style::default().fg(Color::White),
+                ),
             ]),
             Row::new(vec![
                 Span::styled("Kernel Version", Style::default().fg(Color::Gray)),
-                Span::styled(&self.kernel_version, Style::default().fg(Color::White)),
+                Span::styled(
+                    self.scroller.shift(&self.kernel_version),
+                    Style::default().fg(Color::White),
+                ),
             ]),
         ];
 
         let os_table = Table::new(
             os_rows,
-            [Constraint::Length(20), Constraint::Fill(1)],
+            [Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)],
         )
         .block(
             Block::default()
@@ -104,7 +208,10 @@ impl super::ViewComponent for StatusView {
         let hw_rows = vec![
             Row::new(vec![
                 Span::styled("CPU", Style::default().fg(Color::Gray)),
-                Span::styled(&self.cpu_name, Style::default().fg(Color::Cyan)),
+                Span::styled(
+                    self.scroller.shift(&self.cpu_name),
+                    Style::default().fg(Color::Cyan),
+                ),
             ]),
             Row::new(vec![
                 Span::styled("CPU Cores", Style::default().fg(Color::Gray)),
@@ -124,7 +231,7 @@ impl super::ViewComponent for StatusView {
 
         let hw_table = Table::new(
             hw_rows,
-            [Constraint::Length(20), Constraint::Fill(1)],
+            [Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)],
         )
         .block(
             Block::default()
@@ -133,11 +240,78 @@ impl super::ViewComponent for StatusView {
                 .border_style(Style::default().fg(Color::Yellow)),
         );
         frame.render_widget(hw_table, chunks[2]);
+
+        // Runtime Table
+        let load = &self.load_average;
+        let load_span = |value: f64| {
+            Span::styled(format!("{:.2}", value), Style::default().fg(self.load_color(value)))
+        };
+        let runtime_rows = vec![
+            Row::new(vec![
+                Span::styled("Uptime", Style::default().fg(Color::Gray)),
+                Span::styled(format_uptime(self.uptime_secs), Style::default().fg(Color::White)),
+            ]),
+            Row::new(vec![
+                Line::from(Span::styled("Load Average", Style::default().fg(Color::Gray))),
+                Line::from(vec![
+                    load_span(load.one),
+                    Span::raw("  "),
+                    load_span(load.five),
+                    Span::raw("  "),
+                    load_span(load.fifteen),
+                    Span::styled("  (1m / 5m / 15m)", Style::default().fg(Color::DarkGray)),
+                ]),
+            ]),
+            Row::new(vec![
+                Span::styled("Processes", Style::default().fg(Color::Gray)),
+                Span::styled(self.process_count.to_string(), Style::default().fg(Color::White)),
+            ]),
+            Row::new(vec![
+                Span::styled("Threads", Style::default().fg(Color::Gray)),
+                Span::styled(self.thread_count.to_string(), Style::default().fg(Color::White)),
+            ]),
+        ];
+
+        let runtime_table = Table::new(
+            runtime_rows,
+            [Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)],
+        )
+        .block(
+            Block::default()
+                .title(" ⏱️  Runtime ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green)),
+        );
+        frame.render_widget(runtime_table, chunks[3]);
+    }
+
+    fn name(&self) -> &str {
+        "Status"
+    }
+
+    fn title(&self) -> &str {
+        "Status"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (50, 20)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[("← / →", "Scroll long values (when truncated)")]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        self.scroller.handle_key(key)
     }
 }
 
 impl super::TickingComponent for StatusView {
     fn on_tick(&mut self) {
-        // 정적 정보만 표시하므로 tick 불필요
+        self.refresh_runtime();
+    }
+
+    fn preferred_tick_ms(&self) -> u64 {
+        1000
     }
 }
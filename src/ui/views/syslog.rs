@@ -0,0 +1,214 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::integration::syslog::{LogEntry, LogSeverity, SyslogTail};
+use crate::ui::components::FilterBar;
+
+/// 보관할 로그 항목 최대 개수
+const MAX_ENTRIES: usize = 500;
+/// PageUp/PageDown 한 번에 이동할 줄 수
+const PAGE_STEP: usize = 10;
+
+fn severity_color(severity: LogSeverity) -> Color {
+    match severity {
+        LogSeverity::Error => Color::Red,
+        LogSeverity::Warning => Color::Yellow,
+        LogSeverity::Info => Color::White,
+    }
+}
+
+/// 시스템 로그(`/var/log/syslog` 또는 journalctl)를 따라 읽는 뷰 (Linux 전용)
+///
+/// OOM kill, 디스크/GPU 오류처럼 VRChat에 영향을 주는 시스템 이벤트 확인용
+pub struct SyslogView {
+    /// 첫 tick에 시작 (뷰를 열지 않으면 파일/프로세스를 건드리지 않음)
+    tail: Option<SyslogTail>,
+    entries: VecDeque<LogEntry>,
+    filter_bar: FilterBar,
+    /// 최신 줄로부터의 스크롤 위치 (0이면 새 줄을 따라감)
+    scroll: usize,
+}
+
+impl SyslogView {
+    pub fn new() -> Self {
+        Self {
+            tail: None,
+            entries: VecDeque::with_capacity(MAX_ENTRIES),
+            filter_bar: FilterBar::new().with_label("Search"),
+            scroll: 0,
+        }
+    }
+
+    fn visible_entries(&self) -> Vec<&LogEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                self.filter_bar.matches(&entry.message) || self.filter_bar.matches(&entry.process)
+            })
+            .collect()
+    }
+
+    fn render_list(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue));
+
+        if !cfg!(target_os = "linux") {
+            let message = Paragraph::new("Unsupported platform: system log view is Linux only")
+                .style(Style::default().fg(Color::Gray))
+                .block(block);
+            frame.render_widget(message, area);
+            return;
+        }
+        if let Some(error) = self.tail.as_ref().and_then(|tail| tail.error()) {
+            let message = Paragraph::new(format!("Cannot read system log ({})", error))
+                .style(Style::default().fg(Color::Gray))
+                .block(block);
+            frame.render_widget(message, area);
+            return;
+        }
+
+        let entries = self.visible_entries();
+        let capacity = area.height.saturating_sub(2) as usize;
+        let end = entries.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(capacity);
+
+        let items: Vec<ListItem> = entries[start..end]
+            .iter()
+            .map(|entry| {
+                let color = severity_color(entry.severity);
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{} ", entry.timestamp),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(format!("{}: ", entry.process), Style::default().fg(Color::Cyan)),
+                    Span::styled(entry.message.clone(), Style::default().fg(color)),
+                ]))
+            })
+            .collect();
+
+        let source = self
+            .tail
+            .as_ref()
+            .and_then(|tail| tail.source())
+            .map_or("-", |source| source.label());
+        let follow = if self.scroll == 0 { "" } else { " [scrolled]" };
+        let list = List::new(items).block(block.title(format!(
+            " {} ({}/{}){} ",
+            source,
+            entries.len(),
+            self.entries.len(),
+            follow
+        )));
+        frame.render_widget(list, area);
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.visible_entries().len().saturating_sub(1)
+    }
+}
+
+impl Default for SyslogView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for SyslogView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let [title_area, list_area, status_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        let title = Paragraph::new(
+            "System Log [/: search] [↑↓/PgUp/PgDn: scroll] [End: follow] [Tab: switch view]",
+        )
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(title, title_area);
+
+        self.render_list(frame, list_area);
+        if self.filter_bar.is_focused() || !self.filter_bar.is_empty() {
+            self.filter_bar.render(frame, status_area);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "System Log"
+    }
+
+    fn title(&self) -> &str {
+        "Syslog"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 12)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("/", "Search messages"),
+            ("↑ / ↓", "Scroll log"),
+            ("PgUp / PgDn", "Scroll log by page"),
+            ("End", "Follow new lines"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        if self.filter_bar.is_focused() {
+            self.filter_bar.handle_key(key);
+            self.scroll = 0;
+            return true;
+        }
+
+        match key {
+            KeyCode::Up => self.scroll = (self.scroll + 1).min(self.max_scroll()),
+            KeyCode::Down => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::PageUp => self.scroll = (self.scroll + PAGE_STEP).min(self.max_scroll()),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_sub(PAGE_STEP),
+            KeyCode::End => self.scroll = 0,
+            KeyCode::Char('/') => self.filter_bar.focus(),
+            // 검색어가 있을 때만 Esc를 소비 (없으면 전역 종료 키로 전달)
+            KeyCode::Esc if !self.filter_bar.is_empty() => {
+                self.filter_bar.clear();
+                self.scroll = 0;
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl super::TickingComponent for SyslogView {
+    fn on_tick(&mut self) {
+        self.filter_bar.tick();
+        if !cfg!(target_os = "linux") {
+            return;
+        }
+
+        let tail = self.tail.get_or_insert_with(SyslogTail::start);
+        for entry in tail.poll() {
+            if self.entries.len() == MAX_ENTRIES {
+                self.entries.pop_front();
+            }
+            // 스크롤 중이면 보던 줄이 밀리지 않도록 위치 유지
+            let visible =
+                self.filter_bar.matches(&entry.message) || self.filter_bar.matches(&entry.process);
+            if self.scroll > 0 && visible {
+                self.scroll += 1;
+            }
+            self.entries.push_back(entry);
+        }
+    }
+}
@@ -1,65 +1,527 @@
+use std::path::PathBuf;
+
+use crossbeam_channel::Sender;
 use ratatui::{
+    crossterm::event::KeyCode,
     layout::{Constraint, Direction, Layout, Rect},
-    widgets::{Block, Borders, Paragraph},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Row, Table},
     Frame,
 };
-use sysinfo::System;
 
-use crate::ui::components::usage_gauge::{CpuGraph, GpuGraph, MemoryGraph};
+use crate::config::{self, Config};
+use crate::controllers::metrics_collector::{SharedSnapshot, SnapshotReader, SystemSnapshot};
+use crate::ui::components::usage_gauge::{
+    CpuGraph, GpuClockGraph, GpuGraph, GpuTempGraph, MemoryGraph, PowerUsageGraph, RateGraph,
+    SmoothingMode, SwapGraph, TemperatureGraph, ThroughputGraph,
+};
+use crate::ui::components::time_range::MAX_TIME_RANGE_SAMPLES;
+use crate::ui::components::{SharedTimeRange, TimeRangeSelector};
+use crate::ui::viewer::ViewerCommand;
+use crate::util::statistics::{self, Statistics};
+
+/// 통계 오버레이 크기 (값 4행)
+const STATS_OVERLAY_WIDTH: u16 = 16;
+const STATS_OVERLAY_HEIGHT: u16 = 4;
+/// 한 페이지에 표시할 GPU 수 (초과 시 [ / ]로 페이지 전환)
+const GPUS_PER_PAGE: usize = 2;
+/// CPU/GPU 사용률 그래프의 노이즈 완화용 평활화
+const DEFAULT_SMOOTHING: SmoothingMode = SmoothingMode::Ema(0.3);
+
+/// 시스템 모니터 화면에 배치되는 패널 종류
+#[derive(Clone, Copy)]
+enum Panel {
+    Cpu,
+    Memory,
+    Swap,
+    Network,
+    Gpu(usize),
+    Vram(usize),
+    GpuTemp(usize),
+    GpuClock(usize),
+    CpuTemp,
+    CpuPower,
+    ContextSwitches,
+    Interrupts,
+}
 
 /// 시스템 모니터 뷰 - CPU, GPU, Memory 사용량 그래프 표시
 pub struct SystemMonitorView {
-    system: System,
+    metrics: SnapshotReader,
     cpu_graph: CpuGraph,
-    gpu_graph: GpuGraph,
+    /// GPU 장치별 그래프 (GPU가 없으면 빈 그래프 하나)
+    gpu_graphs: Vec<GpuGraph>,
+    /// GPU 장치별 온도/클럭 그래프 (`gpu_graphs`와 같은 순서)
+    gpu_temp_graphs: Vec<GpuTempGraph>,
+    gpu_clock_graphs: Vec<GpuClockGraph>,
     memory_graph: MemoryGraph,
-    nvml: Option<nvml_wrapper::Nvml>,
+    swap_graph: SwapGraph,
+    network_graph: ThroughputGraph,
+    temp_graph: TemperatureGraph,
+    power_graph: PowerUsageGraph,
+    /// 수집기가 RAPL 전력을 읽을 수 있는지 (Linux에서만, 없으면 패널 생략)
+    has_cpu_power: bool,
+    context_switch_graph: RateGraph,
+    interrupt_graph: RateGraph,
+    /// 수집기가 `/proc/stat`을 읽을 수 있는지 (Linux에서만)
+    has_scheduler_stats: bool,
+    /// 컨텍스트 스위치/인터럽트 패널 표시 여부 (C 키로 토글)
+    show_scheduler: bool,
+    /// CPU 온도 패널 표시 여부
+    show_cpu_temp: bool,
+    /// 대역폭을 측정할 네트워크 인터페이스 이름
+    network_interface: Option<String>,
+    /// 마지막으로 적용한 설정의 `network_interface` (바뀌었을 때만 인터페이스를 다시 고름)
+    configured_interface: Option<String>,
+    /// 네트워크 패널 표시 여부 (W 키로 토글, 설정 파일에 저장)
+    show_network: bool,
+    /// 패널 표시 여부를 저장할 설정 파일 경로
+    config_path: Option<PathBuf>,
+    /// 그래프별 통계 오버레이 표시 여부 (I 키로 토글)
+    show_stats: bool,
+    /// 메모리 패널을 캐시까지 쌓은 영역 차트로 표시 (M 키로 토글)
+    stacked_memory: bool,
+    /// 그래프에 표시할 시간 범위 (↑/↓로 변경)
+    time_range: TimeRangeSelector,
+    /// 현재 GPU 페이지 ([ / ] 키로 전환)
+    gpu_page: usize,
+    /// 메모리 누수 의심 시 알림을 보낼 곳
+    commands: Option<Sender<ViewerCommand>>,
+    /// 누수 경고에 쓰는 연속 증가 샘플 수
+    memory_leak_window: usize,
+    /// 이번 증가 구간에 이미 알림을 보냈는지
+    memory_leak_alerted: bool,
 }
 
 impl SystemMonitorView {
     pub fn new() -> Self {
-        let mut system = System::new_all();
-        system.refresh_all();
+        Self::with_config(&Config::default(), SharedSnapshot::default())
+    }
+
+    pub fn with_config(config: &Config, metrics: SharedSnapshot) -> Self {
+        let metrics = SnapshotReader::new(metrics);
+
+        // GPU 장치는 수집기가 열거 (NVIDIA → AMD → Intel 순)
+        let initial = metrics.current();
+        let gpus = &initial.gpus;
+
+        // 설정값이 없으면 누적 트래픽이 가장 많은 인터페이스를 사용
+        let network_interface = config
+            .network_interface
+            .clone()
+            .or_else(|| Self::detect_busiest_interface(&initial));
+
+        // 가장 긴 시간 범위까지 보관 (표시는 선택한 범위만)
+        let history_size = config.history_size.max(MAX_TIME_RANGE_SAMPLES);
+
+        let mut network_graph =
+            ThroughputGraph::new("Network", "RX", "TX").with_history_size(history_size);
+        if let Some(ref name) = network_interface {
+            network_graph.set_title(format!("Network ({})", name));
+        }
+        network_graph.apply_theme(&config.theme);
+
+        let (low, high) = (config.low_threshold as f64, config.high_threshold as f64);
+        let mut cpu_graph = CpuGraph::new()
+            .with_history_size(history_size)
+            .with_smoothing(DEFAULT_SMOOTHING);
+        cpu_graph.apply_theme(&config.theme);
+        cpu_graph.set_thresholds(low, high);
+        let mut gpu_graphs = Vec::new();
+        let mut gpu_temp_graphs = Vec::new();
+        let mut gpu_clock_graphs = Vec::new();
+        for index in 0..gpus.len().max(1) {
+            let mut graph = GpuGraph::new()
+                .with_history_size(history_size)
+                .with_smoothing(DEFAULT_SMOOTHING);
+            let mut temp_graph = GpuTempGraph::new().with_history_size(history_size);
+            let mut clock_graph = GpuClockGraph::new().with_history_size(history_size);
+            if let Some(gpu) = gpus.get(index) {
+                let name = format!("{}: {}", index, gpu.name);
+                graph.set_device_name(&name);
+                temp_graph.set_device_name(&name);
+                clock_graph.set_device_name(&name);
+                if let Some(max_mhz) = gpu.max_graphics_clock_mhz {
+                    clock_graph.set_max_clock(max_mhz);
+                }
+            }
+            graph.apply_theme(&config.theme);
+            graph.set_thresholds(low, high);
+            temp_graph.apply_theme(&config.theme);
+            clock_graph.apply_theme(&config.theme);
+            gpu_graphs.push(graph);
+            gpu_temp_graphs.push(temp_graph);
+            gpu_clock_graphs.push(clock_graph);
+        }
+        let mut memory_graph = MemoryGraph::new().with_history_size(history_size);
+        memory_graph.apply_theme(&config.theme);
+        memory_graph.set_thresholds(low, high);
+        memory_graph.set_leak_window(config.memory_leak_window);
+        let mut swap_graph = SwapGraph::new().with_history_size(history_size);
+        swap_graph.apply_theme(&config.theme);
+        swap_graph.set_thresholds(low, high);
+        let mut temp_graph = TemperatureGraph::new().with_history_size(history_size);
+        temp_graph.apply_theme(&config.theme);
+        let mut power_graph =
+            PowerUsageGraph::new(config.cpu_tdp_watts).with_history_size(history_size);
+        power_graph.apply_theme(&config.theme);
+        let mut context_switch_graph =
+            RateGraph::new("Context Switches").with_history_size(history_size);
+        context_switch_graph.apply_theme(&config.theme);
+        let mut interrupt_graph =
+            RateGraph::new("Interrupts").with_history_size(history_size);
+        interrupt_graph.apply_theme(&config.theme);
+
+        let mut view = Self {
+            metrics,
+            cpu_graph,
+            gpu_graphs,
+            gpu_temp_graphs,
+            gpu_clock_graphs,
+            memory_graph,
+            swap_graph,
+            network_graph,
+            temp_graph,
+            power_graph,
+            has_cpu_power: initial.cpu_power.is_some(),
+            context_switch_graph,
+            interrupt_graph,
+            has_scheduler_stats: initial.scheduler.is_some(),
+            show_scheduler: false,
+            show_cpu_temp: config.show_cpu_temp,
+            network_interface,
+            configured_interface: config.network_interface.clone(),
+            show_network: config.show_network,
+            config_path: config.path.clone(),
+            show_stats: false,
+            stacked_memory: false,
+            time_range: TimeRangeSelector::new(),
+            gpu_page: 0,
+            commands: None,
+            memory_leak_window: config.memory_leak_window,
+            memory_leak_alerted: false,
+        };
+        view.apply_time_range();
+        view
+    }
+
+    /// 메모리 누수 의심 알림을 보낼 명령 송신자 지정
+    pub fn with_commands(mut self, commands: Sender<ViewerCommand>) -> Self {
+        self.commands = Some(commands);
+        self
+    }
+
+    /// 메모리가 계속 늘고 있으면 증가 구간마다 한 번 알림
+    fn check_memory_leak(&mut self) {
+        if !self.memory_graph.check_monotonic_growth(self.memory_leak_window) {
+            self.memory_leak_alerted = false;
+            return;
+        }
+        if self.memory_leak_alerted {
+            return;
+        }
+        self.memory_leak_alerted = true;
+        if let Some(ref commands) = self.commands {
+            let _ = commands.send(ViewerCommand::Alert(format!(
+                "Potential memory leak: RAM usage kept growing for {} samples",
+                self.memory_leak_window
+            )));
+        }
+    }
+
+    /// 다른 뷰가 따라갈 시간 범위 공유 핸들
+    pub fn time_range(&self) -> SharedTimeRange {
+        self.time_range.shared()
+    }
+
+    /// 선택된 시간 범위를 모든 그래프에 반영
+    fn apply_time_range(&mut self) {
+        let samples = self.time_range.samples();
+        self.cpu_graph.set_visible_samples(samples);
+        for graph in &mut self.gpu_graphs {
+            graph.set_visible_samples(samples);
+        }
+        for graph in &mut self.gpu_temp_graphs {
+            graph.set_visible_samples(samples);
+        }
+        for graph in &mut self.gpu_clock_graphs {
+            graph.set_visible_samples(samples);
+        }
+        self.memory_graph.set_visible_samples(samples);
+        self.swap_graph.set_visible_samples(samples);
+        self.temp_graph.set_visible_samples(samples);
+        self.power_graph.set_visible_samples(samples);
+        self.context_switch_graph.set_visible_samples(samples);
+        self.interrupt_graph.set_visible_samples(samples);
+        self.network_graph.set_visible_samples(samples);
+    }
+
+    /// 모든 그래프의 최고값 표시 초기화
+    fn reset_peaks(&mut self) {
+        self.cpu_graph.reset_peak();
+        for graph in &mut self.gpu_graphs {
+            graph.reset_peak();
+        }
+        for graph in &mut self.gpu_temp_graphs {
+            graph.reset_peak();
+        }
+        for graph in &mut self.gpu_clock_graphs {
+            graph.reset_peak();
+        }
+        self.memory_graph.reset_peak();
+        self.swap_graph.reset_peak();
+        self.temp_graph.reset_peak();
+        self.power_graph.reset_peak();
+        self.context_switch_graph.reset_peak();
+        self.interrupt_graph.reset_peak();
+    }
 
-        // NVML 초기화 (NVIDIA GPU용)
-        let nvml = nvml_wrapper::Nvml::init().ok();
+    /// 총 송수신 바이트가 가장 큰 인터페이스 이름 반환
+    fn detect_busiest_interface(snapshot: &SystemSnapshot) -> Option<String> {
+        snapshot
+            .networks
+            .iter()
+            .max_by_key(|network| network.total_received + network.total_transmitted)
+            .map(|network| network.name.clone())
+    }
 
-        Self {
-            system,
-            cpu_graph: CpuGraph::new(),
-            gpu_graph: GpuGraph::new(),
-            memory_graph: MemoryGraph::new(),
-            nvml,
+    /// 네트워크 패널 토글 후 다음 실행에도 유지되도록 설정 파일에 저장
+    pub fn toggle_network(&mut self) {
+        self.show_network = !self.show_network;
+        let Some(ref path) = self.config_path else {
+            return;
+        };
+        if let Err(e) = config::save_value(path, "show_network", self.show_network)
+            && let Some(ref commands) = self.commands
+        {
+            let _ = commands.send(ViewerCommand::Alert(format!(
+                "Cannot save network panel state: {}",
+                e
+            )));
         }
     }
 
+    pub fn toggle_scheduler(&mut self) {
+        self.show_scheduler = !self.show_scheduler;
+    }
+
+    pub fn toggle_stats(&mut self) {
+        self.show_stats = !self.show_stats;
+    }
+
+    pub fn toggle_memory_mode(&mut self) {
+        self.stacked_memory = !self.stacked_memory;
+    }
+
+    fn gpu_page_count(&self) -> usize {
+        self.gpu_graphs.len().div_ceil(GPUS_PER_PAGE)
+    }
+
+    pub fn next_gpu_page(&mut self) {
+        self.gpu_page = (self.gpu_page + 1) % self.gpu_page_count();
+    }
+
+    pub fn prev_gpu_page(&mut self) {
+        let count = self.gpu_page_count();
+        self.gpu_page = (self.gpu_page + count - 1) % count;
+    }
+
     /// 시스템 정보 갱신
     fn refresh(&mut self) {
-        self.system.refresh_all();
+        // 새로 수집된 스냅샷이 있을 때만 그래프 갱신
+        let Some(snapshot) = self.metrics.poll() else {
+            return;
+        };
 
         // CPU 사용량 업데이트
-        let cpu_usage = self.system.global_cpu_usage() as f64;
-        self.cpu_graph.push(cpu_usage);
+        self.cpu_graph.push(snapshot.cpu_usage);
 
         // 메모리 사용량 업데이트
-        let used_memory = self.system.used_memory();
-        let total_memory = self.system.total_memory();
-        self.memory_graph.push(used_memory, total_memory);
-
-        // GPU 사용량 업데이트 (NVIDIA)
-        if let Some(ref nvml) = self.nvml {
-            if let Ok(device) = nvml.device_by_index(0) {
-                if let Ok(utilization) = device.utilization_rates() {
-                    self.gpu_graph.push(utilization.gpu as f64);
-                }
-                if let Ok(memory_info) = device.memory_info() {
-                    let vram_percent =
-                        (memory_info.used as f64 / memory_info.total as f64) * 100.0;
-                    self.gpu_graph.push_vram(vram_percent);
-                }
+        self.memory_graph
+            .push(snapshot.used_memory, snapshot.total_memory);
+        self.memory_graph
+            .push_swap(snapshot.used_swap, snapshot.total_swap);
+        if let Some(cache_bytes) = snapshot.cache_memory {
+            self.memory_graph
+                .push_cache(cache_bytes, snapshot.total_memory);
+        }
+        #[cfg(target_os = "windows")]
+        if let Some(ref commit) = snapshot.commit {
+            self.memory_graph.push_commit(commit);
+        }
+        self.check_memory_leak();
+        self.swap_graph.push(snapshot.used_swap, snapshot.total_swap);
+
+        // 네트워크 대역폭 업데이트 (수집 전에 생성된 경우 여기서 인터페이스 감지)
+        if self.network_interface.is_none()
+            && let Some(name) = Self::detect_busiest_interface(&snapshot)
+        {
+            self.network_graph.set_title(format!("Network ({})", name));
+            self.network_interface = Some(name);
+        }
+        if let Some(network) = self
+            .network_interface
+            .as_ref()
+            .and_then(|name| snapshot.network(name))
+        {
+            self.network_graph
+                .push(network.rx_bytes_per_sec, network.tx_bytes_per_sec);
+        }
+
+        // CPU 온도 업데이트
+        if self.show_cpu_temp {
+            self.temp_graph.push(snapshot.cpu_temperature);
+        }
+
+        // CPU 소비 전력 업데이트
+        self.has_cpu_power = snapshot.cpu_power.is_some();
+        if let Some(power) = snapshot.cpu_power {
+            self.power_graph.push(power.package_watts, power.core_watts);
+        }
+
+        // 초당 컨텍스트 스위치/인터럽트 업데이트 (숨겨져 있어도 히스토리 유지)
+        self.has_scheduler_stats = snapshot.scheduler.is_some();
+        if let Some(scheduler) = snapshot.scheduler {
+            self.context_switch_graph
+                .push(scheduler.context_switches_per_sec);
+            self.interrupt_graph.push(scheduler.interrupts_per_sec);
+        }
+
+        // GPU 사용량 업데이트 (보이지 않는 페이지의 GPU도 히스토리 유지)
+        for (index, gpu) in snapshot.gpus.iter().enumerate().take(self.gpu_graphs.len()) {
+            if let Some(utilization) = gpu.utilization {
+                self.gpu_graphs[index].push(utilization);
+            }
+            if let Some(vram_percent) = gpu.vram_percent {
+                self.gpu_graphs[index].push_vram(vram_percent);
+            }
+            if let Some(celsius) = gpu.temperature {
+                self.gpu_temp_graphs[index].push(celsius);
+            }
+            if let Some(mhz) = gpu.graphics_clock_mhz {
+                self.gpu_clock_graphs[index].push(mhz);
             }
         }
     }
+
+    /// 세 번째 행에 표시할 패널 목록 (없으면 행 생략)
+    fn extra_panels(&self) -> Vec<Panel> {
+        let mut extra = Vec::new();
+        if self.has_cpu_power {
+            extra.push(Panel::CpuPower);
+        }
+        if self.show_scheduler && self.has_scheduler_stats {
+            extra.extend([Panel::ContextSwitches, Panel::Interrupts]);
+        }
+        extra
+    }
+
+    /// 현재 표시할 패널 목록 (위 행, GPU 사용률 행, GPU 온도/클럭 행)
+    fn panels(&self) -> (Vec<Panel>, Vec<Panel>, Vec<Panel>) {
+        let mut top = vec![Panel::Cpu, Panel::Memory, Panel::Swap];
+        if self.show_network {
+            top.push(Panel::Network);
+        }
+        let first = self.gpu_page * GPUS_PER_PAGE;
+        let last = (first + GPUS_PER_PAGE).min(self.gpu_graphs.len());
+        let middle: Vec<Panel> = (first..last)
+            .flat_map(|index| [Panel::Gpu(index), Panel::Vram(index)])
+            .collect();
+        let mut bottom: Vec<Panel> = (first..last)
+            .flat_map(|index| [Panel::GpuTemp(index), Panel::GpuClock(index)])
+            .collect();
+        if self.show_cpu_temp {
+            bottom.push(Panel::CpuTemp);
+        }
+        (top, middle, bottom)
+    }
+
+    fn render_panel(&self, panel: Panel, frame: &mut Frame, area: Rect) {
+        match panel {
+            Panel::Cpu => self.cpu_graph.render(frame, area),
+            Panel::Memory if self.stacked_memory => self.memory_graph.render_stacked(frame, area),
+            Panel::Memory => self.memory_graph.render(frame, area),
+            Panel::Swap => self.swap_graph.render(frame, area),
+            Panel::Network => self.network_graph.render(frame, area),
+            Panel::Gpu(index) => self.gpu_graphs[index].render(frame, area),
+            Panel::Vram(index) => self.gpu_graphs[index].render_vram(frame, area),
+            Panel::GpuTemp(index) => self.gpu_temp_graphs[index].render(frame, area),
+            Panel::GpuClock(index) => self.gpu_clock_graphs[index].render(frame, area),
+            Panel::CpuTemp => self.temp_graph.render(frame, area),
+            Panel::CpuPower => self.power_graph.render(frame, area),
+            Panel::ContextSwitches => self.context_switch_graph.render(frame, area),
+            Panel::Interrupts => self.interrupt_graph.render(frame, area),
+        }
+        if self.show_stats
+            && let Some((stats, unit)) = self.panel_statistics(panel)
+        {
+            Self::render_stats_overlay(stats, unit, frame, area);
+        }
+    }
+
+    /// 표시 중인 구간의 요약 통계와 단위 (네트워크 패널은 제외)
+    fn panel_statistics(&self, panel: Panel) -> Option<(Statistics, &'static str)> {
+        let (history, unit) = match panel {
+            Panel::Cpu => (self.cpu_graph.recorded_history(), "%"),
+            Panel::Memory => (self.memory_graph.recorded_history(), "%"),
+            Panel::Swap => (self.swap_graph.recorded_history(), "%"),
+            Panel::Gpu(index) => (self.gpu_graphs[index].recorded_history(), "%"),
+            Panel::Vram(index) => (self.gpu_graphs[index].vram_recorded_history(), "%"),
+            Panel::GpuTemp(index) => (self.gpu_temp_graphs[index].recorded_history(), "°C"),
+            Panel::GpuClock(index) => (self.gpu_clock_graphs[index].recorded_history(), "MHz"),
+            Panel::CpuTemp => (self.temp_graph.recorded_history(), "°C"),
+            Panel::CpuPower => (self.power_graph.recorded_history(), "W"),
+            Panel::ContextSwitches => (self.context_switch_graph.recorded_history(), "/s"),
+            Panel::Interrupts => (self.interrupt_graph.recorded_history(), "/s"),
+            Panel::Network => return None,
+        };
+        // 공백 표시값(NaN)은 통계에서 제외
+        let data: Vec<f64> = history.iter().copied().filter(|v| !v.is_nan()).collect();
+        Some((statistics::compute(&data), unit))
+    }
+
+    /// 그래프 오른쪽 위에 배경을 지우지 않고 통계 표를 겹쳐 그림
+    fn render_stats_overlay(stats: Statistics, unit: &str, frame: &mut Frame, area: Rect) {
+        if area.width < STATS_OVERLAY_WIDTH + 2 || area.height < STATS_OVERLAY_HEIGHT + 2 {
+            return;
+        }
+        let overlay = Rect::new(
+            area.right() - STATS_OVERLAY_WIDTH - 1,
+            area.y + 1,
+            STATS_OVERLAY_WIDTH,
+            STATS_OVERLAY_HEIGHT,
+        );
+
+        let rows = [
+            ("Min", stats.min),
+            ("Max", stats.max),
+            ("Avg", stats.avg),
+            ("P95", stats.p95),
+        ]
+        .into_iter()
+        .map(|(label, value)| Row::new(vec![label.to_string(), format!("{:.1}{}", value, unit)]));
+
+        let table = Table::new(rows, [Constraint::Length(4), Constraint::Fill(1)])
+            .style(Style::default().fg(Color::Gray));
+        frame.render_widget(table, overlay);
+    }
+
+    /// 패널들을 한 행에 균등 분할하여 렌더링
+    fn render_row(&self, panels: &[Panel], frame: &mut Frame, area: Rect) {
+        let constraints: Vec<Constraint> = panels
+            .iter()
+            .map(|_| Constraint::Ratio(1, panels.len() as u32))
+            .collect();
+
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(area);
+
+        for (panel, chunk) in panels.iter().zip(chunks.iter()) {
+            self.render_panel(*panel, frame, *chunk);
+        }
+    }
 }
 
 impl Default for SystemMonitorView {
@@ -76,32 +538,167 @@ impl super::ViewComponent for SystemMonitorView {
             .constraints([Constraint::Length(3), Constraint::Min(0)])
             .split(area);
 
-        // 타이틀
-        let title = Paragraph::new("System Monitor (Tab to switch view)")
-            .block(Block::default().borders(Borders::ALL));
-        frame.render_widget(title, main_chunks[0]);
+        // 타이틀 (GPU 페이지가 여러 개면 현재 페이지 표시)
+        let mut title_text =
+            "System Monitor [W: toggle network] [C: toggle ctx/intr] [I: toggle stats] [M: memory mode] [R: reset peaks] [Tab: switch view]".to_string();
+        if self.gpu_page_count() > 1 {
+            title_text.push_str(&format!(
+                " [[/]: GPU page {}/{}]",
+                self.gpu_page + 1,
+                self.gpu_page_count()
+            ));
+        }
+        let title_block = Block::default().borders(Borders::ALL);
+        let title_inner = title_block.inner(main_chunks[0]);
+        frame.render_widget(title_block, main_chunks[0]);
+        let [title_area, range_area] = Layout::horizontal([
+            Constraint::Min(0),
+            Constraint::Length(self.time_range.width()),
+        ])
+        .areas(title_inner);
+        frame.render_widget(Paragraph::new(title_text), title_area);
+        self.time_range.render(frame, range_area);
 
-        // 그래프들 레이아웃 (2x2 그리드)
-        let rows = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(main_chunks[1]);
+        // 그래프들 레이아웃 (3행 그리드, 전력/스케줄러 패널이 있으면 아래에 낮은 행 추가)
+        let extra = self.extra_panels();
+        let rows = if !extra.is_empty() {
+            Layout::vertical([
+                Constraint::Fill(2),
+                Constraint::Fill(2),
+                Constraint::Fill(2),
+                Constraint::Fill(1),
+            ])
+            .split(main_chunks[1])
+        } else {
+            Layout::vertical([Constraint::Ratio(1, 3); 3]).split(main_chunks[1])
+        };
 
-        let top_row = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(rows[0]);
+        let (top, middle, bottom) = self.panels();
+        self.render_row(&top, frame, rows[0]);
+        self.render_row(&middle, frame, rows[1]);
+        self.render_row(&bottom, frame, rows[2]);
+        if !extra.is_empty() {
+            self.render_row(&extra, frame, rows[3]);
+        }
+    }
 
-        let bottom_row = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(rows[1]);
+    fn name(&self) -> &str {
+        "System Monitor"
+    }
+
+    fn title(&self) -> &str {
+        "System"
+    }
+
+    /// 3행 그래프 격자
+    fn min_dimensions(&self) -> (u16, u16) {
+        (80, 24)
+    }
 
-        // 각 그래프 렌더링
-        self.cpu_graph.render(frame, top_row[0]);
-        self.memory_graph.render(frame, top_row[1]);
-        self.gpu_graph.render(frame, bottom_row[0]);
-        self.gpu_graph.render_vram(frame, bottom_row[1]);
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("W", "Toggle network panel"),
+            ("C", "Toggle context switch / interrupt panels"),
+            ("I", "Toggle statistics overlay"),
+            ("M", "Toggle stacked memory chart"),
+            ("R", "Reset graph peaks"),
+            ("↑ / ↓", "Change graph time range"),
+            ("[ / ]", "Previous / next GPU page"),
+        ]
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        if self.time_range.handle_key(key) {
+            self.apply_time_range();
+            return true;
+        }
+
+        match key {
+            KeyCode::Char('w') | KeyCode::Char('W') => {
+                self.toggle_network();
+                true // 이벤트 소비됨
+            }
+            // P는 전역 일시정지 키이므로 GPU 페이지는 [ / ]
+            KeyCode::Char(']') if self.gpu_page_count() > 1 => {
+                self.next_gpu_page();
+                true
+            }
+            KeyCode::Char('[') if self.gpu_page_count() > 1 => {
+                self.prev_gpu_page();
+                true
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.toggle_scheduler();
+                true
+            }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                self.toggle_stats();
+                true
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                self.toggle_memory_mode();
+                true
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.reset_peaks();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.show_cpu_temp = config.show_cpu_temp;
+        // 인터페이스 설정이 바뀐 경우에만 다시 고름 (지워졌으면 생성할 때처럼 자동 감지)
+        if config.network_interface != self.configured_interface {
+            self.configured_interface = config.network_interface.clone();
+            let network_interface = config
+                .network_interface
+                .clone()
+                .or_else(|| Self::detect_busiest_interface(&self.metrics.current()));
+            // 다른 인터페이스의 히스토리는 의미가 없으므로 인터페이스가 바뀔 때만 새 그래프
+            if network_interface != self.network_interface {
+                self.network_graph = ThroughputGraph::new("Network", "RX", "TX")
+                    .with_history_size(config.history_size.max(MAX_TIME_RANGE_SAMPLES));
+                self.network_graph
+                    .set_visible_samples(self.time_range.samples());
+                if let Some(ref name) = network_interface {
+                    self.network_graph.set_title(format!("Network ({})", name));
+                }
+                self.network_interface = network_interface;
+            }
+        }
+
+        let (low, high) = (config.low_threshold as f64, config.high_threshold as f64);
+        self.cpu_graph.apply_theme(&config.theme);
+        self.cpu_graph.set_thresholds(low, high);
+        for graph in &mut self.gpu_graphs {
+            graph.apply_theme(&config.theme);
+            graph.set_thresholds(low, high);
+        }
+        for graph in &mut self.gpu_temp_graphs {
+            graph.apply_theme(&config.theme);
+        }
+        for graph in &mut self.gpu_clock_graphs {
+            graph.apply_theme(&config.theme);
+        }
+        self.memory_graph.apply_theme(&config.theme);
+        self.memory_graph.set_thresholds(low, high);
+        self.memory_graph.set_leak_window(config.memory_leak_window);
+        self.memory_leak_window = config.memory_leak_window;
+        self.swap_graph.apply_theme(&config.theme);
+        self.swap_graph.set_thresholds(low, high);
+        self.network_graph.apply_theme(&config.theme);
+        self.temp_graph.apply_theme(&config.theme);
+        self.power_graph.apply_theme(&config.theme);
+        self.power_graph.set_tdp(config.cpu_tdp_watts);
+        self.context_switch_graph.apply_theme(&config.theme);
+        self.interrupt_graph.apply_theme(&config.theme);
+    }
+
+    fn on_focus(&mut self) {
+        // 전환 직후 바로 최신 스냅샷 표시
+        self.refresh();
     }
 }
 
@@ -109,4 +706,85 @@ impl super::TickingComponent for SystemMonitorView {
     fn on_tick(&mut self) {
         self.refresh();
     }
+
+    fn background_refresh_policy(&self) -> super::BackgroundRefreshPolicy {
+        // 그래프 히스토리가 끊기지 않도록 숨어 있어도 계속 수집
+        super::BackgroundRefreshPolicy::AlwaysRefresh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controllers::metrics_collector::NetworkSnapshot;
+    use crate::ui::views::ViewComponent;
+
+    #[test]
+    fn clearing_network_interface_redetects_busiest_interface() {
+        let metrics = SharedSnapshot::default();
+        metrics.lock().unwrap().networks = vec![
+            NetworkSnapshot {
+                name: "eth0".to_string(),
+                total_received: 100,
+                ..Default::default()
+            },
+            NetworkSnapshot {
+                name: "wlan0".to_string(),
+                total_received: 5_000,
+                ..Default::default()
+            },
+        ];
+        let mut config = Config {
+            network_interface: Some("eth0".to_string()),
+            ..Config::default()
+        };
+        let mut view = SystemMonitorView::with_config(&config, metrics);
+        assert_eq!(view.network_interface.as_deref(), Some("eth0"));
+
+        config.network_interface = None;
+        view.on_config_changed(&config);
+        assert_eq!(view.network_interface.as_deref(), Some("wlan0"));
+    }
+
+    #[test]
+    fn unrelated_config_change_keeps_network_history() {
+        let config = Config {
+            network_interface: Some("eth0".to_string()),
+            ..Config::default()
+        };
+        let mut view = SystemMonitorView::with_config(&config, SharedSnapshot::default());
+        view.network_graph.push(2048.0, 512.0);
+
+        view.on_config_changed(&Config {
+            high_threshold: 90,
+            ..config.clone()
+        });
+        assert_eq!(view.network_graph.current_in(), 2048.0);
+        assert_eq!(view.network_graph.current_out(), 512.0);
+    }
+
+    #[test]
+    fn network_panel_toggle_is_saved_to_config_file() {
+        let path = std::env::temp_dir().join(format!(
+            "l_vrc_console_show_network_{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "# 내 설정\nhistory_size = 90\n").unwrap();
+        let mut config = Config::load(&path).unwrap();
+        config.path = Some(path.clone());
+
+        let mut view = SystemMonitorView::with_config(&config, SharedSnapshot::default());
+        assert!(view.show_network);
+        assert!(view.handle_key(KeyCode::Char('w')));
+
+        let saved = Config::load(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(!saved.show_network);
+        assert_eq!(saved.history_size, 90);
+        assert!(content.starts_with("# 내 설정"));
+
+        let reopened = SystemMonitorView::with_config(&saved, SharedSnapshot::default());
+        assert!(!reopened.show_network);
+    }
 }
@@ -0,0 +1,153 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use sysinfo::Components;
+
+use crate::config::{Config, Theme};
+use crate::ui::components::usage_gauge::{TemperatureGauge, TemperatureGraph};
+
+/// 센서 갱신 간격 (그래프 샘플 간격)
+const SENSOR_REFRESH_INTERVAL: Duration = Duration::from_millis(200);
+/// 한 행에 배치할 게이지 수
+const GAUGES_PER_ROW: usize = 3;
+
+/// 온도 센서 뷰 - 센서별 게이지 + 가장 뜨거운 센서의 온도 그래프
+pub struct ThermalView {
+    components: Components,
+    gauges: Vec<TemperatureGauge>,
+    hottest_graph: TemperatureGraph,
+    theme: Theme,
+    last_refresh: Option<Instant>,
+}
+
+impl ThermalView {
+    pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    /// 설정을 반영하여 뷰 생성
+    pub fn with_config(config: &Config) -> Self {
+        let mut hottest_graph = TemperatureGraph::new().with_history_size(config.history_size);
+        hottest_graph.set_title("Hottest Sensor");
+        hottest_graph.apply_theme(&config.theme);
+
+        Self {
+            components: Components::new_with_refreshed_list(),
+            gauges: Vec::new(),
+            hottest_graph,
+            theme: config.theme.clone(),
+            last_refresh: None,
+        }
+    }
+
+    fn refresh(&mut self) {
+        if self
+            .last_refresh
+            .is_some_and(|t| t.elapsed() < SENSOR_REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.last_refresh = Some(Instant::now());
+
+        // 센서가 추가/제거될 수 있으므로 목록까지 갱신
+        self.components.refresh(true);
+
+        self.gauges = self
+            .components
+            .list()
+            .iter()
+            .map(|component| {
+                let mut gauge = TemperatureGauge::new(component.label());
+                gauge.apply_theme(&self.theme);
+                gauge.set_temperature(component.temperature().map(|t| t as f64));
+                gauge
+            })
+            .collect();
+
+        let hottest = self
+            .gauges
+            .iter()
+            .filter_map(|gauge| gauge.temperature())
+            .reduce(f64::max);
+        self.hottest_graph.push(hottest);
+    }
+
+    fn render_gauges(&self, frame: &mut Frame, area: Rect) {
+        let rows = self.gauges.len().div_ceil(GAUGES_PER_ROW);
+        let row_areas = Layout::vertical(vec![Constraint::Length(3); rows]).split(area);
+
+        for (row_area, chunk) in row_areas.iter().zip(self.gauges.chunks(GAUGES_PER_ROW)) {
+            let cells = Layout::horizontal(vec![
+                Constraint::Ratio(1, GAUGES_PER_ROW as u32);
+                GAUGES_PER_ROW
+            ])
+            .split(*row_area);
+            for (cell, gauge) in cells.iter().zip(chunk) {
+                gauge.render(frame, *cell);
+            }
+        }
+    }
+}
+
+impl Default for ThermalView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::ViewComponent for ThermalView {
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(" Thermal ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red));
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.gauges.is_empty() {
+            let message = Paragraph::new("No temperature sensors found")
+                .style(Style::default().fg(Color::Gray));
+            frame.render_widget(message, inner);
+            return;
+        }
+
+        let gauge_rows = self.gauges.len().div_ceil(GAUGES_PER_ROW) as u16;
+        let [gauge_area, graph_area] =
+            Layout::vertical([Constraint::Max(gauge_rows * 3), Constraint::Min(8)]).areas(inner);
+
+        self.render_gauges(frame, gauge_area);
+        self.hottest_graph.render(frame, graph_area);
+    }
+
+    fn name(&self) -> &str {
+        "Thermal"
+    }
+
+    fn title(&self) -> &str {
+        "Thermal"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (60, 16)
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.theme = config.theme.clone();
+        self.hottest_graph.apply_theme(&config.theme);
+        for gauge in &mut self.gauges {
+            gauge.apply_theme(&config.theme);
+        }
+    }
+}
+
+impl super::TickingComponent for ThermalView {
+    fn on_tick(&mut self) {
+        self.refresh();
+    }
+}
@@ -1,31 +1,668 @@
+use std::time::{Duration, Instant, SystemTime};
 
+use ratatui::{
+    crossterm::event::KeyCode,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::config::{Config, OscBinding, OscBindingValue};
+use crate::controllers::log_exporter::SharedSessionEvents;
+use crate::integration::vrc_log::{
+    PerfRank, VrcLogEventKind, VrchatEvent, VrchatLogWatcher, WorldEventType,
+};
+use crate::integration::vrc_osc::{
+    OscEvent, OscValue, SharedOscState, VrchatOscSender, AVATAR_PARAMETER_PREFIX,
+};
+use crate::ui::components::osc_debug_log::OscDebugLog;
+use crate::ui::components::usage_gauge::FpsGraph;
+
+const NOTIFICATION_DURATION: Duration = Duration::from_secs(10);
+/// 파라미터 표에 한 번에 보여줄 행 수
+const PARAMETER_PAGE_SIZE: usize = 20;
+/// PageUp/PageDown 한 번에 이동할 로그 이벤트 수
+const LOG_SCROLL_STEP: usize = 5;
+/// [ / ] 한 번에 이동할 월드 기록 수
+const WORLD_SCROLL_STEP: usize = 1;
+/// 아바타 변경 시 VRChat이 보내는 OSC 주소 (값은 아바타 ID)
+const AVATAR_CHANGE_ADDRESS: &str = "/avatar/change";
+
+/// 로그 이벤트 종류별 색상
+fn log_event_color(kind: VrcLogEventKind) -> Color {
+    match kind {
+        VrcLogEventKind::WorldJoin => Color::Cyan,
+        VrcLogEventKind::PlayerJoin => Color::Green,
+        VrcLogEventKind::PlayerLeave => Color::Yellow,
+        VrcLogEventKind::AvatarChange => Color::Magenta,
+        VrcLogEventKind::Error => Color::Red,
+    }
+}
+
+/// 아바타 성능 등급별 색상
+fn perf_rank_color(rank: PerfRank) -> Color {
+    match rank {
+        PerfRank::Excellent => Color::Green,
+        PerfRank::Good => Color::Cyan,
+        PerfRank::Medium => Color::Yellow,
+        PerfRank::Poor => Color::Red,
+        PerfRank::VeryPoor => Color::Magenta,
+    }
+}
+
+/// 1st, 2nd, 3rd, 4th ... 형식의 서수
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// 경과 시간을 "1h 2m 3s" 형식으로 변환
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (h, m, s) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if h > 0 {
+        format!("{}h {}m {}s", h, m, s)
+    } else if m > 0 {
+        format!("{}m {}s", m, s)
+    } else {
+        format!("{}s", s)
+    }
+}
+
+/// 이번 실행 동안의 VRChat 세션 통계
+#[derive(Default)]
+struct SessionStats {
+    crash_count: u32,
+    last_crash: Option<Instant>,
+    /// 현재 VRChat 세션 시작 시각
+    session_started: Option<Instant>,
+    /// 크래시로 끝난 세션들의 길이
+    crashed_sessions: Vec<Duration>,
+}
+
+impl SessionStats {
+    /// 크래시 간 평균 세션 길이
+    fn mean_session_length(&self) -> Option<Duration> {
+        if self.crashed_sessions.is_empty() {
+            return None;
+        }
+        let total: Duration = self.crashed_sessions.iter().sum();
+        Some(total / self.crashed_sessions.len() as u32)
+    }
+}
 
 pub struct VrchatPageView {
     // VRChat 관련 데이터 및 상태를 여기에 추가
+    osc_log: OscDebugLog,
+    /// OSC 디버그 로그 표시 여부 (O 키로 토글)
+    show_osc_log: bool,
+    log_watcher: VrchatLogWatcher,
+    stats: SessionStats,
+    /// 중요 알림 메시지와 표시 시작 시각
+    notification: Option<(String, Instant)>,
+    osc_state: SharedOscState,
+    /// 이름순으로 정렬된 아바타 파라미터 (tick마다 복사)
+    parameters: Vec<(String, OscValue)>,
+    parameter_scroll: usize,
+    /// OSC 수신 불가 사유
+    osc_error: Option<String>,
+    /// 최신 로그 이벤트로부터의 스크롤 위치
+    log_scroll: usize,
+    /// 최신 월드 기록으로부터의 스크롤 위치
+    world_scroll: usize,
+    /// 로그에서 읽은 VRChat 프레임레이트
+    fps_graph: FpsGraph,
+    /// 종료 시 세션 요약에 남길 이벤트
+    session_events: SharedSessionEvents,
+    /// VRChat으로 OSC를 보내는 송신기 (소켓을 못 열면 None)
+    osc_sender: Option<VrchatOscSender>,
+    /// 송신 대상 (설정이 바뀌었는지 비교용)
+    osc_send_target: (String, u16),
+    /// 키를 누르면 보낼 OSC 메시지
+    osc_bindings: Vec<OscBinding>,
+    /// 마지막 송신 결과 또는 송신기 생성 실패 사유
+    osc_send_status: Option<String>,
+}
+
+/// 송신기 생성 (실패하면 사유 문구)
+fn open_osc_sender(host: &str, port: u16) -> Result<VrchatOscSender, String> {
+    VrchatOscSender::new(host, port)
+        .map_err(|e| format!("OSC send to {}:{} unavailable: {}", host, port, e))
 }
 
 impl VrchatPageView {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(osc_state: SharedOscState) -> Self {
+        Self::with_config(&Config::default(), osc_state)
+    }
+
+    /// 설정을 반영하여 뷰 생성
+    pub fn with_config(config: &Config, osc_state: SharedOscState) -> Self {
+        let mut fps_graph = FpsGraph::new().with_history_size(config.history_size);
+        fps_graph.apply_theme(&config.theme);
+        let (osc_sender, osc_send_status) =
+            match open_osc_sender(&config.osc_send_host, config.osc_send_port) {
+                Ok(sender) => (Some(sender), None),
+                Err(error) => (None, Some(error)),
+            };
+
+        Self {
+            osc_log: OscDebugLog::new(),
+            show_osc_log: false,
+            log_watcher: VrchatLogWatcher::new(),
+            stats: SessionStats::default(),
+            notification: None,
+            osc_state,
+            parameters: Vec::new(),
+            parameter_scroll: 0,
+            osc_error: None,
+            log_scroll: 0,
+            world_scroll: 0,
+            fps_graph,
+            session_events: SharedSessionEvents::default(),
+            osc_sender,
+            osc_send_target: (config.osc_send_host.clone(), config.osc_send_port),
+            osc_bindings: config.osc_bindings.clone(),
+            osc_send_status,
+        }
+    }
+
+    /// 세션 요약용 이벤트 기록 대상 지정
+    pub fn with_session_events(mut self, session_events: SharedSessionEvents) -> Self {
+        self.session_events = session_events;
+        self
+    }
+
+    /// 수신 스레드가 모은 OSC 이벤트와 파라미터 값 반영
+    fn sync_osc_state(&mut self) {
+        let Ok(mut state) = self.osc_state.lock() else {
+            return;
+        };
+        let events = state.drain_events();
+        self.osc_error = state.error.clone();
+        self.parameters = state
+            .parameters
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        drop(state);
+
+        self.parameters.sort_by(|a, b| a.0.cmp(&b.0));
+        self.parameter_scroll = self
+            .parameter_scroll
+            .min(self.parameters.len().saturating_sub(PARAMETER_PAGE_SIZE));
+        for event in events {
+            if event.address == AVATAR_CHANGE_ADDRESS
+                && let OscValue::String(ref id) = event.value
+            {
+                self.log_watcher.avatar_mut().set_avatar_id(id.clone());
+            }
+            self.push_osc_event(event);
+        }
+    }
+
+    /// 바인딩된 OSC 메시지 송신 (토글이면 마지막으로 받은 Bool 값의 반대)
+    fn send_binding(&mut self, binding: &OscBinding) {
+        let Some(ref sender) = self.osc_sender else {
+            return;
+        };
+        let value = match binding.value {
+            OscBindingValue::Bool(value) if binding.toggle => {
+                let name = binding
+                    .address
+                    .strip_prefix(AVATAR_PARAMETER_PREFIX)
+                    .unwrap_or(&binding.address);
+                let current = self
+                    .parameters
+                    .iter()
+                    .find(|(parameter, _)| parameter == name)
+                    .and_then(|(_, value)| match value {
+                        OscValue::Bool(current) => Some(*current),
+                        _ => None,
+                    })
+                    .unwrap_or(!value);
+                OscBindingValue::Bool(!current)
+            }
+            value => value,
+        };
+        let result = match value {
+            OscBindingValue::Bool(v) => sender.send_bool(&binding.address, v),
+            OscBindingValue::Int(v) => sender.send_int(&binding.address, v),
+            OscBindingValue::Float(v) => sender.send_float(&binding.address, v),
+        };
+        let shown = match value {
+            OscBindingValue::Bool(v) => v.to_string(),
+            OscBindingValue::Int(v) => v.to_string(),
+            OscBindingValue::Float(v) => format!("{:.2}", v),
+        };
+        self.osc_send_status = Some(match result {
+            Ok(()) => format!("sent {} = {}", binding.address, shown),
+            Err(e) => format!("send failed: {}", e),
+        });
+    }
+
+    fn render_parameters(&self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row> = self
+            .parameters
+            .iter()
+            .skip(self.parameter_scroll)
+            .take(PARAMETER_PAGE_SIZE)
+            .map(|(name, value)| {
+                Row::new(vec![
+                    Span::raw(name.clone()),
+                    Span::styled(value.type_name(), Style::default().fg(Color::Gray)),
+                    Span::raw(value.to_string()),
+                ])
+            })
+            .collect();
+
+        let title = match (&self.osc_error, &self.osc_send_status) {
+            (Some(error), _) => format!(" Avatar Parameters - {} ", error),
+            (None, Some(status)) => {
+                format!(" Avatar Parameters ({}) - {} ", self.parameters.len(), status)
+            }
+            (None, None) => format!(" Avatar Parameters ({}) ", self.parameters.len()),
+        };
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Fill(2),
+                Constraint::Length(8),
+                Constraint::Fill(1),
+            ],
+        )
+        .header(
+            Row::new(vec!["Name", "Type", "Value"])
+                .style(Style::default().fg(Color::Cyan).bold()),
+        )
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, area);
+    }
+
+    /// 로그 이벤트를 최신순으로 표시
+    fn render_log_events(&self, frame: &mut Frame, area: Rect) {
+        let events = self.log_watcher.log_events();
+        let items: Vec<ListItem> = events
+            .iter()
+            .rev()
+            .skip(self.log_scroll)
+            .map(|event| {
+                let color = log_event_color(event.kind);
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{} ", event.time),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("{:<7}", event.kind.label()),
+                        Style::default().fg(color).bold(),
+                    ),
+                    Span::styled(event.detail.clone(), Style::default().fg(color)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title(format!(" Log Events ({}) ", events.len()))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(list, area);
+    }
+
+    /// 월드 입장/퇴장 기록을 최신순 타임라인으로 표시 (가장 최근 기록 강조)
+    fn render_world_history(&self, frame: &mut Frame, area: Rect) {
+        let events = self.log_watcher.worlds().events();
+        let now = SystemTime::now();
+        let items: Vec<ListItem> = events
+            .iter()
+            .rev()
+            .enumerate()
+            .skip(self.world_scroll)
+            .map(|(i, event)| {
+                let ago = now
+                    .duration_since(event.timestamp)
+                    .map(|elapsed| format!("{} ago", format_duration(elapsed)))
+                    .unwrap_or_else(|_| "now".to_string());
+                let (icon, color) = match event.event_type {
+                    WorldEventType::Join => ("→", Color::Green),
+                    WorldEventType::Leave => ("←", Color::Yellow),
+                };
+                let name = event.world_name.clone().unwrap_or_else(|| event.world_id.clone());
+                let item = ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:>12} ", ago), Style::default().fg(Color::DarkGray)),
+                    Span::styled(format!("{} ", icon), Style::default().fg(color).bold()),
+                    Span::raw(name),
+                ]));
+                if i == 0 {
+                    item.style(Style::default().bg(Color::DarkGray).bold())
+                } else {
+                    item
+                }
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title(format!(" World History ({}) ", events.len()))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(list, area);
+    }
+
+    /// 수신된 OSC 이벤트를 디버그 로그에 기록
+    pub fn push_osc_event(&mut self, event: OscEvent) {
+        self.osc_log.push(event);
+    }
+
+    pub fn toggle_osc_log(&mut self) {
+        self.show_osc_log = !self.show_osc_log;
+    }
+
+    fn handle_vrchat_event(&mut self, event: VrchatEvent) {
+        match event {
+            VrchatEvent::Started => {
+                self.stats.session_started = Some(Instant::now());
+            }
+            VrchatEvent::Exited { .. } => {
+                self.stats.session_started = None;
+            }
+            VrchatEvent::Crash { .. } => {
+                if let Some(started) = self.stats.session_started.take() {
+                    self.stats.crashed_sessions.push(started.elapsed());
+                }
+                self.stats.crash_count += 1;
+                self.stats.last_crash = Some(Instant::now());
+                self.notification = Some((
+                    format!(
+                        "VRChat crashed ({} time this session)",
+                        ordinal(self.stats.crash_count)
+                    ),
+                    Instant::now(),
+                ));
+            }
+        }
+    }
+
+    /// 현재 아바타 이름, ID, 성능 등급, 폴리곤 수
+    fn render_avatar(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(" Avatar ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue));
+
+        let Some(avatar) = self.log_watcher.avatar().current() else {
+            let message = Paragraph::new("No avatar change seen yet")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block);
+            frame.render_widget(message, area);
+            return;
+        };
+
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+        let unknown = || Span::styled("-", Style::default().fg(Color::DarkGray));
+        let id = if avatar.id.is_empty() {
+            unknown()
+        } else {
+            Span::styled(avatar.id.clone(), Style::default().fg(Color::DarkGray))
+        };
+        let rank = avatar.perf_rank.map_or_else(unknown, |rank| {
+            Span::styled(rank.label(), Style::default().fg(perf_rank_color(rank)).bold())
+        });
+        let polys = avatar
+            .poly_count
+            .map_or_else(unknown, |count| Span::raw(count.to_string()));
+
+        let rows = vec![
+            Row::new(vec![label("Name"), Span::styled(avatar.name.clone(), Style::default().bold())]),
+            Row::new(vec![label("ID"), id]),
+            Row::new(vec![label("Performance"), rank]),
+            Row::new(vec![label("Polygons"), polys]),
+        ];
+        let table = Table::new(rows, [Constraint::Length(12), Constraint::Fill(1)]).block(block);
+        frame.render_widget(table, area);
+    }
+
+    fn render_session_stats(&self, frame: &mut Frame, area: Rect) {
+        let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+
+        let status = match self.log_watcher.process().pid() {
+            Some(pid) => Span::styled(format!("Running (PID {})", pid), Style::default().fg(Color::Green)),
+            None => Span::styled("Not running", Style::default().fg(Color::DarkGray)),
+        };
+        let crash_color = if self.stats.crash_count > 0 {
+            Color::Red
+        } else {
+            Color::White
+        };
+        let last_crash = self
+            .stats
+            .last_crash
+            .map(|t| format!("{} ago", format_duration(t.elapsed())))
+            .unwrap_or_else(|| "-".to_string());
+        let mean_session = self
+            .stats
+            .mean_session_length()
+            .map(format_duration)
+            .unwrap_or_else(|| "-".to_string());
+
+        let rows = vec![
+            Row::new(vec![label("VRChat"), status]),
+            Row::new(vec![
+                label("Crashes"),
+                Span::styled(
+                    self.stats.crash_count.to_string(),
+                    Style::default().fg(crash_color),
+                ),
+            ]),
+            Row::new(vec![label("Last Crash"), Span::raw(last_crash)]),
+            Row::new(vec![label("Mean Session"), Span::raw(mean_session)]),
+        ];
+
+        let table = Table::new(rows, [Constraint::Length(16), Constraint::Fill(1)]).block(
+            Block::default()
+                .title(" Session Statistics ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        frame.render_widget(table, area);
+    }
+}
+
+impl Default for VrchatPageView {
+    fn default() -> Self {
+        Self::new(SharedOscState::default())
     }
 }
 
 impl super::ViewComponent for VrchatPageView {
-    fn draw_with_area(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
-        let block = ratatui::widgets::Block::default()
+    fn draw_with_area(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
             .title(" VRChat Page ")
-            .borders(ratatui::widgets::Borders::ALL)
-            .border_style(ratatui::style::Style::default().fg(ratatui::style::Color::Magenta));
+            .title_bottom(
+                " O: OSC debug log  ↑↓: scroll parameters  PgUp/PgDn: scroll log  [/]: scroll worlds ",
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta));
 
+        let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        // VRChat 페이지의 추가 UI 요소를 여기에 그리기
+        let notification = self
+            .notification
+            .as_ref()
+            .filter(|(_, shown_at)| shown_at.elapsed() < NOTIFICATION_DURATION);
+
+        let chunks = Layout::vertical([
+            Constraint::Length(if notification.is_some() { 1 } else { 0 }),
+            Constraint::Length(8), // Session Statistics + Avatar + FPS
+            Constraint::Min(0),
+        ])
+        .split(inner);
+
+        // OSC 로그를 열면 파라미터 표와 나눠서 표시
+        let (content_area, log_area) = if self.show_osc_log {
+            let [top, bottom] =
+                Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .areas(chunks[2]);
+            (top, Some(bottom))
+        } else {
+            (chunks[2], None)
+        };
+        let [parameter_area, right_area] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(content_area);
+        let [world_area, events_area] =
+            Layout::vertical([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(right_area);
+
+        if let Some((message, _)) = notification {
+            let banner = Paragraph::new(format!("⚠ {}", message))
+                .style(Style::default().fg(Color::White).bg(Color::Red).bold());
+            frame.render_widget(banner, chunks[0]);
+        }
+
+        let [stats_area, avatar_area, fps_area] = Layout::horizontal([
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+        ])
+        .areas(chunks[1]);
+        self.render_session_stats(frame, stats_area);
+        self.render_avatar(frame, avatar_area);
+        self.fps_graph.render(frame, fps_area);
+
+        self.render_parameters(frame, parameter_area);
+        self.render_world_history(frame, world_area);
+        self.render_log_events(frame, events_area);
+        if let Some(log_area) = log_area {
+            self.osc_log.render(frame, log_area);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "VRChat"
+    }
+
+    fn title(&self) -> &str {
+        "VRChat"
+    }
+
+    fn min_dimensions(&self) -> (u16, u16) {
+        (80, 24)
+    }
+
+    fn key_bindings(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("O", "Toggle OSC debug log"),
+            ("↑ / ↓", "Scroll parameters"),
+            ("PgUp / PgDn", "Scroll log events"),
+            ("[ / ]", "Scroll world history"),
+            ("osc_bindings keys", "Send configured OSC message"),
+        ]
+    }
+
+    fn on_config_changed(&mut self, config: &Config) {
+        self.fps_graph.apply_theme(&config.theme);
+        self.osc_bindings = config.osc_bindings.clone();
+        let target = (config.osc_send_host.clone(), config.osc_send_port);
+        if target != self.osc_send_target {
+            match open_osc_sender(&target.0, target.1) {
+                Ok(sender) => {
+                    self.osc_sender = Some(sender);
+                    self.osc_send_status = None;
+                }
+                Err(error) => {
+                    self.osc_sender = None;
+                    self.osc_send_status = Some(error);
+                }
+            }
+            self.osc_send_target = target;
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        // 로그가 열려 있으면 로그가 먼저 키를 처리
+        if self.show_osc_log && self.osc_log.handle_key(key) {
+            return true;
+        }
+
+        match key {
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                self.toggle_osc_log();
+                true
+            }
+            KeyCode::Up => {
+                self.parameter_scroll = self.parameter_scroll.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                let max_scroll = self.parameters.len().saturating_sub(PARAMETER_PAGE_SIZE);
+                self.parameter_scroll = (self.parameter_scroll + 1).min(max_scroll);
+                true
+            }
+            KeyCode::PageUp => {
+                self.log_scroll = self.log_scroll.saturating_sub(LOG_SCROLL_STEP);
+                true
+            }
+            KeyCode::PageDown => {
+                let max_scroll = self.log_watcher.log_events().len().saturating_sub(1);
+                self.log_scroll = (self.log_scroll + LOG_SCROLL_STEP).min(max_scroll);
+                true
+            }
+            KeyCode::Char('[') => {
+                self.world_scroll = self.world_scroll.saturating_sub(WORLD_SCROLL_STEP);
+                true
+            }
+            KeyCode::Char(']') => {
+                let max_scroll = self.log_watcher.worlds().events().len().saturating_sub(1);
+                self.world_scroll = (self.world_scroll + WORLD_SCROLL_STEP).min(max_scroll);
+                true
+            }
+            KeyCode::Char(c) => {
+                let Some(binding) = self.osc_bindings.iter().find(|b| b.key == c).cloned() else {
+                    return false;
+                };
+                self.send_binding(&binding);
+                true
+            }
+            _ => false,
+        }
     }
 }
 
 impl super::TickingComponent for VrchatPageView {
     fn on_tick(&mut self) {
-        
+        self.sync_osc_state();
+        for event in self.log_watcher.poll() {
+            self.handle_vrchat_event(event);
+        }
+        for fps in self.log_watcher.fps_mut().drain_new_samples() {
+            self.fps_graph.push(fps);
+        }
+        let mut session_events = self.session_events.borrow_mut();
+        session_events.watcher_active = true;
+        for event in self.log_watcher.drain_new_log_events() {
+            session_events.record(event);
+        }
     }
-}
 
+    fn background_refresh_policy(&self) -> super::BackgroundRefreshPolicy {
+        // 다른 뷰를 보는 동안에도 크래시 감지와 세션 기록이 늦어지지 않도록 계속 감시
+        super::BackgroundRefreshPolicy::AlwaysRefresh
+    }
+}
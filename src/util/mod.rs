@@ -0,0 +1,5 @@
+pub mod ring_buffer;
+pub mod screenshot;
+pub mod statistics;
+
+pub use ring_buffer::RingBuffer;
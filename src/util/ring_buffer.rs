@@ -0,0 +1,97 @@
+/// 고정 크기 링 버퍼 - 가득 차면 가장 오래된 값을 덮어씀 (push O(1), 생성 후 할당 없음)
+///
+/// 모든 값을 `i`와 `i + capacity` 두 칸에 써 두어 저장된 구간이 항상 연속된 슬라이스가 됨
+#[derive(Clone, Debug)]
+pub struct RingBuffer<T> {
+    /// 용량의 두 배 크기 (뒤 절반은 앞 절반의 복사본)
+    data: Box<[T]>,
+    capacity: usize,
+    /// 가장 오래된 요소의 위치
+    head: usize,
+    len: usize,
+}
+
+/// `RingBuffer` 순회자 (삽입 순서)
+pub type Iter<'a, T> = std::slice::Iter<'a, T>;
+
+impl<T: Copy + Default> RingBuffer<T> {
+    /// 주어진 용량의 빈 버퍼 생성
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            data: vec![T::default(); capacity * 2].into_boxed_slice(),
+            capacity,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// 모든 칸을 같은 값으로 채운 버퍼 생성
+    pub fn filled(value: T, capacity: usize) -> Self {
+        Self {
+            data: vec![value; capacity * 2].into_boxed_slice(),
+            capacity,
+            head: 0,
+            len: capacity,
+        }
+    }
+
+    /// 모든 칸을 같은 값으로 덮어씀
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+        self.head = 0;
+        self.len = self.capacity;
+    }
+
+    pub fn push(&mut self, value: T) {
+        let capacity = self.capacity;
+        if capacity == 0 {
+            return;
+        }
+        let index = if self.len < capacity {
+            self.len += 1;
+            (self.head + self.len - 1) % capacity
+        } else {
+            let index = self.head;
+            self.head = (self.head + 1) % capacity;
+            index
+        };
+        self.data[index] = value;
+        self.data[index + capacity] = value;
+    }
+
+    /// 오래된 것 → 최신 순서의 연속 슬라이스
+    pub fn as_slice(&self) -> &[T] {
+        &self.data[self.head..self.head + self.len]
+    }
+
+    /// 가장 최근에 추가된 값
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 삽입 순서(오래된 것 → 최신)대로 순회
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T: Copy + Default> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
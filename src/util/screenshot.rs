@@ -0,0 +1,145 @@
+use std::{
+    fmt::Write as _,
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+use unicode_width::UnicodeWidthStr;
+
+/// SGR 코드가 있는 수정자
+const MODIFIER_CODES: [(Modifier, u8); 9] = [
+    (Modifier::BOLD, 1),
+    (Modifier::DIM, 2),
+    (Modifier::ITALIC, 3),
+    (Modifier::UNDERLINED, 4),
+    (Modifier::SLOW_BLINK, 5),
+    (Modifier::RAPID_BLINK, 6),
+    (Modifier::REVERSED, 7),
+    (Modifier::HIDDEN, 8),
+    (Modifier::CROSSED_OUT, 9),
+];
+
+/// 색상의 SGR 파라미터 (`base`는 전경 30 / 배경 40)
+fn color_code(color: Color, base: u8) -> String {
+    match color {
+        Color::Reset => (base + 9).to_string(),
+        Color::Black => base.to_string(),
+        Color::Red => (base + 1).to_string(),
+        Color::Green => (base + 2).to_string(),
+        Color::Yellow => (base + 3).to_string(),
+        Color::Blue => (base + 4).to_string(),
+        Color::Magenta => (base + 5).to_string(),
+        Color::Cyan => (base + 6).to_string(),
+        Color::Gray => (base + 7).to_string(),
+        Color::DarkGray => (base + 60).to_string(),
+        Color::LightRed => (base + 61).to_string(),
+        Color::LightGreen => (base + 62).to_string(),
+        Color::LightYellow => (base + 63).to_string(),
+        Color::LightBlue => (base + 64).to_string(),
+        Color::LightMagenta => (base + 65).to_string(),
+        Color::LightCyan => (base + 66).to_string(),
+        Color::White => (base + 67).to_string(),
+        Color::Indexed(i) => format!("{};5;{}", base + 8, i),
+        Color::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+    }
+}
+
+/// 렌더링된 버퍼를 ANSI 이스케이프 문자열로 변환 (줄마다 스타일 초기화)
+pub fn render_ansi(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+
+    for y in area.top()..area.bottom() {
+        let mut current = None;
+        // 넓은 문자 뒤에 가려지는 칸 수
+        let mut hidden = 0;
+        for x in area.left()..area.right() {
+            let cell = &buffer[(x, y)];
+            if hidden > 0 {
+                hidden -= 1;
+                continue;
+            }
+
+            let style = (cell.fg, cell.bg, cell.modifier);
+            if current != Some(style) {
+                out.push_str("\x1b[0");
+                for (modifier, code) in MODIFIER_CODES {
+                    if cell.modifier.contains(modifier) {
+                        let _ = write!(out, ";{}", code);
+                    }
+                }
+                let _ = write!(out, ";{};{}m", color_code(cell.fg, 30), color_code(cell.bg, 40));
+                current = Some(style);
+            }
+
+            let symbol = cell.symbol();
+            out.push_str(symbol);
+            hidden = symbol.width().saturating_sub(1);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+/// 스크린샷 저장 경로 (`~/.cache/l_vrc_console/screenshot_<unix>.ans`)
+pub fn default_screenshot_path() -> Option<PathBuf> {
+    let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))?;
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Some(
+        cache_dir
+            .join("l_vrc_console")
+            .join(format!("screenshot_{}.ans", secs)),
+    )
+}
+
+/// 버퍼를 기본 경로에 저장하고 그 경로 반환 (디렉터리가 없으면 생성)
+pub fn save_screenshot(buffer: &Buffer) -> io::Result<PathBuf> {
+    let path = default_screenshot_path()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No cache directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, render_ansi(buffer))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        style::{Color, Style},
+        widgets::Paragraph,
+        Terminal,
+    };
+
+    use super::*;
+
+    #[test]
+    fn screenshot_renders_styled_cells_as_ansi() {
+        let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(6, 2)).unwrap();
+        let completed = terminal
+            .draw(|frame| {
+                frame.render_widget(
+                    Paragraph::new("CPU").style(Style::default().fg(Color::Red)),
+                    frame.area(),
+                );
+            })
+            .unwrap();
+
+        let ansi = render_ansi(completed.buffer);
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("\x1b[0;31;49mCPU"));
+        assert!(lines[0].ends_with("\x1b[0m"));
+    }
+}
@@ -0,0 +1,30 @@
+/// 시계열 데이터 요약 통계
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Statistics {
+    pub min: f64,
+    pub max: f64,
+    pub avg: f64,
+    pub p95: f64,
+    /// 모표준편차
+    pub stddev: f64,
+}
+
+/// 최솟값/최댓값/평균/P95/표준편차 계산 (NaN 공백 표시값은 제외, 데이터가 없으면 모두 0)
+pub fn compute(data: &[f64]) -> Statistics {
+    let mut sorted: Vec<f64> = data.iter().copied().filter(|v| !v.is_nan()).collect();
+    if sorted.is_empty() {
+        return Statistics::default();
+    }
+    sorted.sort_unstable_by(f64::total_cmp);
+
+    let index = (sorted.len() * 95 / 100).min(sorted.len() - 1);
+    let avg = sorted.iter().sum::<f64>() / sorted.len() as f64;
+    let variance = sorted.iter().map(|v| (v - avg).powi(2)).sum::<f64>() / sorted.len() as f64;
+    Statistics {
+        min: sorted[0],
+        max: sorted[sorted.len() - 1],
+        avg,
+        p95: sorted[index],
+        stddev: variance.sqrt(),
+    }
+}